@@ -13,4 +13,59 @@ mod tests {
         let range: [f32; 2] = [0.0, 5.0];
     	b.iter(|| Matrix::<f32>::random(3, 3, &range));
     }
+
+    #[bench]
+    fn mul_small_bench(b: &mut Bencher) {
+        let range: [f64; 2] = [0.0, 5.0];
+        let m1 = Matrix::<f64>::random(4, 4, &range);
+        let m2 = Matrix::<f64>::random(4, 4, &range);
+        b.iter(|| &m1 * &m2);
+    }
+
+    #[bench]
+    fn mul_bench(b: &mut Bencher) {
+        let range: [f64; 2] = [0.0, 5.0];
+        let m1 = Matrix::<f64>::random(64, 64, &range);
+        let m2 = Matrix::<f64>::random(64, 64, &range);
+        b.iter(|| &m1 * &m2);
+    }
+
+    #[bench]
+    fn mul_large_bench(b: &mut Bencher) {
+        let range: [f64; 2] = [0.0, 5.0];
+        let m1 = Matrix::<f64>::random(256, 256, &range);
+        let m2 = Matrix::<f64>::random(256, 256, &range);
+        b.iter(|| &m1 * &m2);
+    }
+
+    #[bench]
+    fn gemm_bench(b: &mut Bencher) {
+        let range: [f64; 2] = [0.0, 5.0];
+        let m1 = Matrix::<f64>::random(256, 128, &range);
+        let m2 = Matrix::<f64>::random(128, 256, &range);
+        let mut c = Matrix::<f64>::random(256, 256, &range);
+        b.iter(|| Matrix::gemm(0.5, &m1, &m2, 1.0, &mut c));
+    }
+
+    #[bench]
+    fn transpose_bench(b: &mut Bencher) {
+        let range: [f64; 2] = [0.0, 5.0];
+        let mut m = Matrix::<f64>::random(64, 128, &range);
+        b.iter(|| m.transpose());
+    }
+
+    #[bench]
+    #[allow(deprecated)]
+    fn submatrix_bench(b: &mut Bencher) {
+        let range: [f64; 2] = [0.0, 5.0];
+        let m = Matrix::<f64>::random(64, 64, &range);
+        b.iter(|| m.submatrix(&[8, 55], &[8, 55]));
+    }
+
+    #[bench]
+    fn slice_bench(b: &mut Bencher) {
+        let range: [f64; 2] = [0.0, 5.0];
+        let m = Matrix::<f64>::random(64, 64, &range);
+        b.iter(|| m.slice(8..56, 8..56).to_matrix());
+    }
 }
@@ -5,6 +5,7 @@ extern crate rand;
 use rand::Rng;
 use r::algebra::matrix::*;
 use r::algebra::vector::*;
+use r::algebra::stats::RunningStats;
 
 /// Onemax is a basic problem of evolutionary algorithms. The aim of the problem is to find a individuals
 /// where its genes are all '1'. This is the first algorithm that I learned, when I was introduced to the
@@ -28,6 +29,8 @@ fn main() {
 
     let mut max_fitness_arr: Vec<u32> = Vec::new();
     let mut median_fitness_arr: Vec<f64> = Vec::new();
+    // statistics of the fitness of all the evaluated individuals
+    let mut all_fitness = RunningStats::new();
 
     let mut pop = Matrix::<u32>::random(pop_size, n_genes, &[0, 1]);
 
@@ -36,11 +39,10 @@ fn main() {
     // iterate for the generations
     for gen in 0..max_gens {
 
-        // get the fitness: number of '1'
-        let mut fitness = Vector::<u32>::new();
-        for gene in pop.row_iter() {
-            let sum = gene.iter().fold(0u32, |sum, &el| sum + el);
-            fitness.push(sum);
+        // get the fitness: number of '1' of every individual
+        let fitness = pop.sum_axis(Axis::Col);
+        for f in fitness.iter() {
+            all_fitness.push(*f);
         }
 
         // get the best indiv and its fitness
@@ -58,6 +60,9 @@ fn main() {
             println!("value found in {} generations", gen);
             println!("best fitness = {}", max);
             println!("median fitness = {}", median);
+            println!("mean fitness = {} (std dev = {})", fitness.mean(), fitness.std_dev());
+            println!("mean fitness of all generations = {} (std dev = {})",
+                     all_fitness.mean(), all_fitness.std_dev());
             println!("final pop:\n{}", pop);
             break;
         }
@@ -0,0 +1,165 @@
+use num::{Float, NumCast};
+use algebra::matrix::Matrix;
+use algebra::view::MatrixView;
+use algebra::vector::Vector;
+use error::Error;
+
+/// Cholesky decomposition of a symmetric positive-definite matrix, so that
+/// `A = L * L'`
+///
+/// # Remarks
+///
+/// * `L` is lower triangular with a positive diagonal.
+/// * This struct is created by `Matrix::cholesky` and is just available for float types.
+pub struct Cholesky<N: Copy> {
+    l: Vec<N>,
+    size: usize,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Inherent methods
+////////////////////////////////////////////////////////////////////////////////
+
+impl<N: Copy + Float> Matrix<N> {
+
+    /// Returns the Cholesky decomposition of the matrix
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::NotSquare` if the matrix is not square
+    /// * Returns `Error::NotSymmetric` if the matrix is not symmetric (up to
+    ///   the rounding error `n * epsilon * max(|a_ij|)`)
+    /// * Returns `Error::NotPositiveDefinite` if the matrix is not positive definite
+    #[inline]
+    pub fn cholesky(&self) -> Result<Cholesky<N>, Error> {
+        if self.nrows() != self.ncols() {
+            return Err(Error::NotSquare { nrows: self.nrows(), ncols: self.ncols() });
+        }
+
+        let n = self.nrows();
+        let a = self.as_slice();
+        let scale = a.iter().fold(N::zero(), |max, el| max.max(el.abs()));
+        if !self.is_symmetric(N::epsilon() * <N as NumCast>::from(n).unwrap() * scale) {
+            return Err(Error::NotSymmetric);
+        }
+
+        let mut l: Vec<N> = vec![N::zero(); n * n];
+        for i in 0..n {
+            for j in 0..(i + 1) {
+                let mut s = a[i * n + j];
+                for k in 0..j {
+                    s = s - l[i * n + k] * l[j * n + k];
+                }
+
+                if i == j {
+                    if s <= N::zero() || s.is_nan() {
+                        return Err(Error::NotPositiveDefinite);
+                    }
+                    l[i * n + i] = s.sqrt();
+                } else {
+                    l[i * n + j] = s / l[j * n + j];
+                }
+            }
+        }
+
+        Ok(Cholesky { l, size: n })
+    }
+}
+
+impl<'a, N: Copy + Float> MatrixView<'a, N> {
+
+    /// Returns the Cholesky decomposition of the view (see `Matrix::cholesky`)
+    #[inline]
+    pub fn cholesky(&self) -> Result<Cholesky<N>, Error> {
+        self.to_matrix().cholesky()
+    }
+}
+
+impl<N: Copy + Float> Cholesky<N> {
+
+    /// Returns the lower triangular factor `L`
+    #[inline]
+    pub fn l(&self) -> Matrix<N> {
+        Matrix::<N>::from_vec(self.size, self.size, self.l.clone())
+    }
+
+    /// Returns the determinant of the decomposed matrix
+    #[inline]
+    pub fn determinant(&self) -> N {
+        let n = self.size;
+        (0..n).fold(N::one(), |det, i| det * self.l[i * n + i] * self.l[i * n + i])
+    }
+
+    /// Returns the inverse of the decomposed matrix
+    #[inline]
+    pub fn inverse(&self) -> Matrix<N> {
+        self.solve_unchecked(Matrix::<N>::create_identity(self.size).into_vec(), self.size)
+    }
+
+    /// Solves the system `A * X = B` for one or many right-hand sides
+    ///
+    /// # Arguments
+    ///
+    /// * `b`: right-hand sides, one per column
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::DimensionMismatch` if `b` hasn't as many rows as `A`
+    #[inline]
+    pub fn solve(&self, b: &Matrix<N>) -> Result<Matrix<N>, Error> {
+        if b.nrows() != self.size {
+            return Err(Error::DimensionMismatch { expected: (self.size, b.ncols()),
+                                                  found: (b.nrows(), b.ncols()) });
+        }
+        Ok(self.solve_unchecked(b.as_slice().to_vec(), b.ncols()))
+    }
+
+    /// Solves the system `A * x = b` for a single right-hand side
+    ///
+    /// # Arguments
+    ///
+    /// * `b`: right-hand side
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::DimensionMismatch` if `b` hasn't as many elements as rows in `A`
+    #[inline]
+    pub fn solve_vector(&self, b: &Vector<N>) -> Result<Vector<N>, Error> {
+        let x = self.solve(&Matrix::<N>::from_vec(b.size(), 1, b.as_slice().to_vec()))?;
+        Ok(Vector::<N>::from_vec(x.into_vec()))
+    }
+
+    // internal use: solves L * L' * X = B for a row-major B with `m` columns
+    fn solve_unchecked(&self, mut x: Vec<N>, m: usize) -> Matrix<N> {
+        let n = self.size;
+
+        // forward substitution with L
+        for i in 0..n {
+            for k in 0..i {
+                let factor = self.l[i * n + k];
+                for j in 0..m {
+                    x[i * m + j] = x[i * m + j] - factor * x[k * m + j];
+                }
+            }
+            let pivot = self.l[i * n + i];
+            for j in 0..m {
+                x[i * m + j] = x[i * m + j] / pivot;
+            }
+        }
+        // back substitution with L'
+        for i in (0..n).rev() {
+            for k in (i + 1)..n {
+                let factor = self.l[k * n + i];
+                for j in 0..m {
+                    x[i * m + j] = x[i * m + j] - factor * x[k * m + j];
+                }
+            }
+            let pivot = self.l[i * n + i];
+            for j in 0..m {
+                x[i * m + j] = x[i * m + j] / pivot;
+            }
+        }
+
+        Matrix::<N>::from_vec(n, m, x)
+    }
+}
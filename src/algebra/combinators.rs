@@ -0,0 +1,334 @@
+use num::Num;
+use std::cmp::Ordering;
+use algebra::matrix::{Axis, Matrix};
+use algebra::vector::Vector;
+use error::Error;
+
+////////////////////////////////////////////////////////////////////////////////
+// Inherent methods
+////////////////////////////////////////////////////////////////////////////////
+
+impl<N: Copy> Matrix<N> {
+
+    /// Returns a new matrix applying a function to every element
+    ///
+    /// # Arguments
+    ///
+    /// * `f`: function applied to each element
+    #[inline]
+    pub fn map<M: Copy, F>(&self, f: F) -> Matrix<M> where F: FnMut(N) -> M {
+        let values: Vec<M> = self.as_slice().iter().cloned().map(f).collect();
+        Matrix::<M>::from_vec(self.nrows(), self.ncols(), values)
+    }
+
+    /// Applies a function to every element of the matrix, in place
+    ///
+    /// # Arguments
+    ///
+    /// * `f`: function applied to each element
+    #[inline]
+    pub fn map_inplace<F>(&mut self, mut f: F) where F: FnMut(N) -> N {
+        for el in self.as_mut_slice().iter_mut() {
+            *el = f(*el);
+        }
+    }
+
+    /// Returns a new matrix applying a function to the elements of two
+    /// matrices at the same coordinates
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: matrix with the second argument of the function
+    /// * `f`: function applied to each pair of elements
+    ///
+    /// # Remarks
+    ///
+    /// * Panics if the sizes are not equal (see `try_zip_map`)
+    #[inline]
+    pub fn zip_map<M: Copy, R: Copy, F>(&self, other: &Matrix<M>, f: F) -> Matrix<R>
+        where F: FnMut(N, M) -> R {
+        match self.try_zip_map(other, f) {
+            Ok(m) => m,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Returns a new matrix applying a function to the elements of two
+    /// matrices at the same coordinates
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: matrix with the second argument of the function
+    /// * `f`: function applied to each pair of elements
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::DimensionMismatch` if the sizes are not equal
+    #[inline]
+    pub fn try_zip_map<M: Copy, R: Copy, F>(&self, other: &Matrix<M>, mut f: F) -> Result<Matrix<R>, Error>
+        where F: FnMut(N, M) -> R {
+        self.check_same_size(other)?;
+
+        let values: Vec<R> = self.as_slice().iter()
+                                            .zip(other.as_slice().iter())
+                                            .map(|(a, b)| f(*a, *b))
+                                            .collect();
+        Ok(Matrix::<R>::from_vec(self.nrows(), self.ncols(), values))
+    }
+
+    /// Accumulates all the elements, row by row, into a single value
+    ///
+    /// # Arguments
+    ///
+    /// * `init`: initial value of the accumulator
+    /// * `f`: function combining the accumulator with an element
+    #[inline]
+    pub fn fold<A, F>(&self, init: A, f: F) -> A where F: FnMut(A, N) -> A {
+        self.as_slice().iter().cloned().fold(init, f)
+    }
+
+    /// Returns the sum of all the elements
+    ///
+    /// # Remarks
+    ///
+    /// * The sum of an empty matrix is zero
+    #[inline]
+    pub fn sum(&self) -> N where N: Num {
+        self.fold(N::zero(), |acc, el| acc + el)
+    }
+
+    /// Returns the product of all the elements
+    ///
+    /// # Remarks
+    ///
+    /// * The product of an empty matrix is one
+    #[inline]
+    pub fn product(&self) -> N where N: Num {
+        self.fold(N::one(), |acc, el| acc * el)
+    }
+
+    /// Returns the minimal element and its coordinates `(row, column)`
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `None` if the matrix is empty
+    /// * On ties the first element, row by row, is returned
+    /// * Elements that are not comparable with themselves (`NaN`) are
+    ///   skipped, unless all of them are
+    #[inline]
+    pub fn min(&self) -> Option<(N, (usize, usize))> where N: PartialOrd {
+        self.extreme(Ordering::Less)
+    }
+
+    /// Returns the maximal element and its coordinates `(row, column)`
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `None` if the matrix is empty
+    /// * On ties the first element, row by row, is returned
+    /// * Elements that are not comparable with themselves (`NaN`) are
+    ///   skipped, unless all of them are
+    #[inline]
+    pub fn max(&self) -> Option<(N, (usize, usize))> where N: PartialOrd {
+        self.extreme(Ordering::Greater)
+    }
+
+    /// Returns the coordinates `(row, column)` of the minimal element (see `min`)
+    #[inline]
+    pub fn argmin(&self) -> Option<(usize, usize)> where N: PartialOrd {
+        self.min().map(|(_, pos)| pos)
+    }
+
+    /// Returns the coordinates `(row, column)` of the maximal element (see `max`)
+    #[inline]
+    pub fn argmax(&self) -> Option<(usize, usize)> where N: PartialOrd {
+        self.max().map(|(_, pos)| pos)
+    }
+
+    /// Returns true if any element satisfies a predicate
+    ///
+    /// # Arguments
+    ///
+    /// * `f`: predicate to check
+    #[inline]
+    pub fn any<F>(&self, f: F) -> bool where F: FnMut(N) -> bool {
+        self.as_slice().iter().cloned().any(f)
+    }
+
+    /// Returns true if all the elements satisfy a predicate
+    ///
+    /// # Arguments
+    ///
+    /// * `f`: predicate to check
+    ///
+    /// # Remarks
+    ///
+    /// * Returns true for an empty matrix
+    #[inline]
+    pub fn all<F>(&self, f: F) -> bool where F: FnMut(N) -> bool {
+        self.as_slice().iter().cloned().all(f)
+    }
+
+    /// Accumulates the elements of every column or every row
+    ///
+    /// # Arguments
+    ///
+    /// * `axis`: `Axis::Row` folds the rows together, giving a value per
+    ///   column; `Axis::Col` folds the columns, giving a value per row
+    /// * `init`: initial value of each accumulator
+    /// * `f`: function combining an accumulator with an element
+    #[inline]
+    pub fn fold_axis<A: Copy, F>(&self, axis: Axis, init: A, mut f: F) -> Vector<A>
+        where F: FnMut(A, N) -> A {
+        let (nrows, ncols) = (self.nrows(), self.ncols());
+        let values = self.as_slice();
+        let acc: Vec<A> = match axis {
+            Axis::Row => {
+                let mut acc = vec![init; ncols];
+                for row in values.chunks(ncols.max(1)) {
+                    for (a, el) in acc.iter_mut().zip(row.iter()) {
+                        *a = f(*a, *el);
+                    }
+                }
+                acc
+            },
+            Axis::Col => {
+                let mut acc = vec![init; nrows];
+                for (a, row) in acc.iter_mut().zip(values.chunks(ncols.max(1))) {
+                    for el in row.iter() {
+                        *a = f(*a, *el);
+                    }
+                }
+                acc
+            },
+        };
+        Vector::<A>::from_vec(acc)
+    }
+
+    /// Returns the sum of every column (`Axis::Row`) or every row (`Axis::Col`)
+    ///
+    /// # Arguments
+    ///
+    /// * `axis`: direction of the sums (see `fold_axis`)
+    #[inline]
+    pub fn sum_axis(&self, axis: Axis) -> Vector<N> where N: Num {
+        self.fold_axis(axis, N::zero(), |acc, el| acc + el)
+    }
+
+    /// Returns the product of every column (`Axis::Row`) or every row (`Axis::Col`)
+    ///
+    /// # Arguments
+    ///
+    /// * `axis`: direction of the products (see `fold_axis`)
+    #[inline]
+    pub fn product_axis(&self, axis: Axis) -> Vector<N> where N: Num {
+        self.fold_axis(axis, N::one(), |acc, el| acc * el)
+    }
+
+    /// Returns the minimal element of every column (`Axis::Row`) or every
+    /// row (`Axis::Col`), with its position inside that column or row
+    ///
+    /// # Arguments
+    ///
+    /// * `axis`: direction of the search (see `fold_axis`)
+    ///
+    /// # Remarks
+    ///
+    /// * Ties and `NaN` values are handled like in `min`
+    /// * The result has one element per column or row, `None` for the empty
+    ///   ones (when the matrix has no rows or no columns)
+    #[inline]
+    pub fn min_axis(&self, axis: Axis) -> Vector<Option<(N, usize)>> where N: PartialOrd {
+        self.extreme_axis(axis, Ordering::Less)
+    }
+
+    /// Returns the maximal element of every column (`Axis::Row`) or every
+    /// row (`Axis::Col`), with its position inside that column or row
+    ///
+    /// # Arguments
+    ///
+    /// * `axis`: direction of the search (see `fold_axis`)
+    ///
+    /// # Remarks
+    ///
+    /// * Ties and `NaN` values are handled like in `max`
+    /// * The result has one element per column or row, `None` for the empty
+    ///   ones (when the matrix has no rows or no columns)
+    #[inline]
+    pub fn max_axis(&self, axis: Axis) -> Vector<Option<(N, usize)>> where N: PartialOrd {
+        self.extreme_axis(axis, Ordering::Greater)
+    }
+
+    /// Checks a predicate on every column (`Axis::Row`) or every row
+    /// (`Axis::Col`), true if any element satisfies it
+    ///
+    /// # Arguments
+    ///
+    /// * `axis`: direction of the check (see `fold_axis`)
+    /// * `f`: predicate to check
+    #[inline]
+    pub fn any_axis<F>(&self, axis: Axis, mut f: F) -> Vector<bool> where F: FnMut(N) -> bool {
+        self.fold_axis(axis, false, |acc, el| acc || f(el))
+    }
+
+    /// Checks a predicate on every column (`Axis::Row`) or every row
+    /// (`Axis::Col`), true if all the elements satisfy it
+    ///
+    /// # Arguments
+    ///
+    /// * `axis`: direction of the check (see `fold_axis`)
+    /// * `f`: predicate to check
+    #[inline]
+    pub fn all_axis<F>(&self, axis: Axis, mut f: F) -> Vector<bool> where F: FnMut(N) -> bool {
+        self.fold_axis(axis, true, |acc, el| acc && f(el))
+    }
+
+    // internal use: first element that is `target` than all the others
+    fn extreme(&self, target: Ordering) -> Option<(N, (usize, usize))> where N: PartialOrd {
+        let ncols = self.ncols();
+        let mut best: Option<(N, usize)> = None;
+        for (index, el) in self.as_slice().iter().enumerate() {
+            if replaces(*el, best.map(|(b, _)| b), target) {
+                best = Some((*el, index));
+            }
+        }
+        best.map(|(el, index)| (el, (index / ncols, index % ncols)))
+    }
+
+    // internal use: `extreme` for every column or row
+    fn extreme_axis(&self, axis: Axis, target: Ordering) -> Vector<Option<(N, usize)>> where N: PartialOrd {
+        let ncols = self.ncols();
+        let size = match axis {
+            Axis::Row => ncols,
+            Axis::Col => self.nrows(),
+        };
+
+        let mut best: Vec<Option<(N, usize)>> = vec![None; size];
+        for (index, el) in self.as_slice().iter().enumerate() {
+            let (i, j) = (index / ncols, index % ncols);
+            let (line, pos) = match axis {
+                Axis::Row => (j, i),
+                Axis::Col => (i, j),
+            };
+            if replaces(*el, best[line].map(|(b, _)| b), target) {
+                best[line] = Some((*el, pos));
+            }
+        }
+        Vector::<Option<(N, usize)>>::from_vec(best)
+    }
+}
+
+// internal use: checks if an element must replace the current extreme, the
+// unordered values (`NaN`) only stay while nothing better is found
+fn replaces<N: PartialOrd>(el: N, best: Option<N>, target: Ordering) -> bool {
+    match best {
+        None => true,
+        Some(best) => {
+            if best.partial_cmp(&best).is_none() {
+                el.partial_cmp(&el).is_some()
+            } else {
+                el.partial_cmp(&best) == Some(target)
+            }
+        },
+    }
+}
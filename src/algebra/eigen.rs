@@ -0,0 +1,739 @@
+use std::cmp::Ordering;
+use num::{Complex, Float, NumCast};
+use algebra::matrix::Matrix;
+use algebra::view::MatrixView;
+use algebra::vector::Vector;
+use error::Error;
+
+/// Eigenvalue decomposition of a symmetric matrix computed with the cyclic
+/// Jacobi method, so that `A = V * D * V'`
+///
+/// # Remarks
+///
+/// * `D` is the diagonal matrix of the eigenvalues, sorted in ascending order,
+///   and `V` the orthogonal matrix of the eigenvectors (one per column).
+/// * This struct is created by `Matrix::symmetric_eigen` and is just available for float types.
+pub struct SymmetricEigen<N: Copy> {
+    values: Vec<N>,
+    vectors: Vec<N>,
+    size: usize,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Inherent methods
+////////////////////////////////////////////////////////////////////////////////
+
+impl<N: Copy + Float> Matrix<N> {
+
+    /// Returns the eigenvalue decomposition of a symmetric matrix
+    ///
+    /// # Remarks
+    ///
+    /// * Uses a relative tolerance of `n * epsilon` and at most 50 sweeps,
+    ///   see `symmetric_eigen_with`
+    #[inline]
+    pub fn symmetric_eigen(&self) -> Result<SymmetricEigen<N>, Error> {
+        let tol = N::epsilon() * <N as NumCast>::from(self.nrows().max(1)).unwrap();
+        self.symmetric_eigen_with(tol, 50)
+    }
+
+    /// Returns the eigenvalue decomposition of a symmetric matrix
+    ///
+    /// # Arguments
+    ///
+    /// * `tol`: the iteration stops when the norm of the off-diagonal elements
+    ///   is not bigger than `tol` times the norm of the matrix
+    /// * `max_sweeps`: maximum number of sweeps over all the off-diagonal elements
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::NotSquare` if the matrix is not square
+    /// * Returns `Error::NotSymmetric` if the matrix is not symmetric (up to
+    ///   the rounding error `n * epsilon * max(|a_ij|)`)
+    /// * Returns `Error::NoConvergence` if the tolerance is not reached after `max_sweeps`
+    #[inline]
+    pub fn symmetric_eigen_with(&self, tol: N, max_sweeps: usize) -> Result<SymmetricEigen<N>, Error> {
+        if self.nrows() != self.ncols() {
+            return Err(Error::NotSquare { nrows: self.nrows(), ncols: self.ncols() });
+        }
+
+        let n = self.nrows();
+        let mut a: Vec<N> = self.as_slice().to_vec();
+        let scale = a.iter().fold(N::zero(), |max, el| max.max(el.abs()));
+        if !self.is_symmetric(N::epsilon() * <N as NumCast>::from(n).unwrap() * scale) {
+            return Err(Error::NotSymmetric);
+        }
+
+        let mut v: Vec<N> = Matrix::<N>::create_identity(n).into_vec();
+        let norm = a.iter().fold(N::zero(), |sum, el| sum + *el * *el).sqrt();
+        let off_norm = |a: &[N]| {
+            let mut sum = N::zero();
+            for i in 0..n {
+                for j in 0..n {
+                    if i != j {
+                        sum = sum + a[i * n + j] * a[i * n + j];
+                    }
+                }
+            }
+            sum.sqrt()
+        };
+
+        let mut sweeps = 0;
+        while off_norm(&a) > tol * norm {
+            if sweeps == max_sweeps {
+                return Err(Error::NoConvergence { iterations: max_sweeps });
+            }
+            sweeps += 1;
+
+            for p in 0..n {
+                for q in (p + 1)..n {
+                    let apq = a[p * n + q];
+                    if apq == N::zero() {
+                        continue;
+                    }
+
+                    // rotation that annihilates the element (p, q)
+                    let two = N::one() + N::one();
+                    let theta = (a[q * n + q] - a[p * n + p]) / (two * apq);
+                    let t = theta.signum() / (theta.abs() + (theta * theta + N::one()).sqrt());
+                    let c = N::one() / (t * t + N::one()).sqrt();
+                    let s = t * c;
+
+                    // A = J' * A * J
+                    for k in 0..n {
+                        let (akp, akq) = (a[k * n + p], a[k * n + q]);
+                        a[k * n + p] = c * akp - s * akq;
+                        a[k * n + q] = s * akp + c * akq;
+                    }
+                    for k in 0..n {
+                        let (apk, aqk) = (a[p * n + k], a[q * n + k]);
+                        a[p * n + k] = c * apk - s * aqk;
+                        a[q * n + k] = s * apk + c * aqk;
+                    }
+                    a[p * n + q] = N::zero();
+                    a[q * n + p] = N::zero();
+
+                    // V = V * J
+                    for k in 0..n {
+                        let (vkp, vkq) = (v[k * n + p], v[k * n + q]);
+                        v[k * n + p] = c * vkp - s * vkq;
+                        v[k * n + q] = s * vkp + c * vkq;
+                    }
+                }
+            }
+        }
+
+        // sort the eigenvalues in ascending order, together with their eigenvectors
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&i, &j| a[i * n + i].partial_cmp(&a[j * n + j]).unwrap_or(Ordering::Equal));
+        let values: Vec<N> = order.iter().map(|&i| a[i * n + i]).collect();
+        let mut vectors: Vec<N> = Vec::with_capacity(n * n);
+        for k in 0..n {
+            vectors.extend(order.iter().map(|&i| v[k * n + i]));
+        }
+
+        Ok(SymmetricEigen { values, vectors, size: n })
+    }
+}
+
+impl<'a, N: Copy + Float> MatrixView<'a, N> {
+
+    /// Returns the eigenvalue decomposition of a symmetric view (see `Matrix::symmetric_eigen`)
+    #[inline]
+    pub fn symmetric_eigen(&self) -> Result<SymmetricEigen<N>, Error> {
+        self.to_matrix().symmetric_eigen()
+    }
+
+    /// Returns the eigenvalue decomposition of a symmetric view (see `Matrix::symmetric_eigen_with`)
+    #[inline]
+    pub fn symmetric_eigen_with(&self, tol: N, max_sweeps: usize) -> Result<SymmetricEigen<N>, Error> {
+        self.to_matrix().symmetric_eigen_with(tol, max_sweeps)
+    }
+}
+
+impl<N: Copy + Float> SymmetricEigen<N> {
+
+    /// Returns the eigenvalues in ascending order
+    #[inline]
+    pub fn eigenvalues(&self) -> Vector<N> {
+        Vector::<N>::from_vec(self.values.clone())
+    }
+
+    /// Returns the eigenvectors as the columns of an orthogonal matrix
+    ///
+    /// # Remarks
+    ///
+    /// * The column `i` is the eigenvector of the eigenvalue `i`
+    #[inline]
+    pub fn eigenvectors(&self) -> Matrix<N> {
+        Matrix::<N>::from_vec(self.size, self.size, self.vectors.clone())
+    }
+}
+
+/// Eigenvalue decomposition of a general (non-symmetric) real matrix, computed
+/// with a reduction to Hessenberg form and the shifted QR algorithm
+///
+/// # Remarks
+///
+/// * The eigenvalues are complex and come in conjugate pairs, they aren't
+///   sorted in any particular order.
+/// * The eigenvectors are just available if the decomposition was created by
+///   `Matrix::eigen`, not by `Matrix::eigenvalues`.
+/// * This struct is just available for `f64` matrices.
+pub struct Eigen {
+    values: Vec<Complex<f64>>,
+    vectors: Option<Vec<Complex<f64>>>,
+    size: usize,
+}
+
+impl Matrix<f64> {
+
+    /// Returns the eigenvalues and the right eigenvectors of the matrix
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::NotSquare` if the matrix is not square
+    /// * Returns `Error::NoConvergence` if the QR algorithm doesn't converge
+    ///   after `30 * max(n, 10)` iterations
+    #[inline]
+    pub fn eigen(&self) -> Result<Eigen, Error> {
+        self.general_eigen(true)
+    }
+
+    /// Returns the eigenvalues of the matrix, without computing its eigenvectors
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::NotSquare` if the matrix is not square
+    /// * Returns `Error::NoConvergence` if the QR algorithm doesn't converge
+    ///   after `30 * max(n, 10)` iterations
+    #[inline]
+    pub fn eigenvalues(&self) -> Result<Vector<Complex<f64>>, Error> {
+        Ok(self.general_eigen(false)?.eigenvalues())
+    }
+
+    // internal use: port of the `orthes` and `hqr2` routines of JAMA, that are
+    // based on the EISPACK routines of the same name
+    fn general_eigen(&self, with_vectors: bool) -> Result<Eigen, Error> {
+        if self.nrows() != self.ncols() {
+            return Err(Error::NotSquare { nrows: self.nrows(), ncols: self.ncols() });
+        }
+
+        let nn = self.nrows();
+        let mut h: Vec<Vec<f64>> = self.as_slice().chunks(nn.max(1)).map(|row| row.to_vec()).collect();
+        let mut v: Vec<Vec<f64>> = vec![vec![0.0; nn]; nn];
+        let mut d: Vec<f64> = vec![0.0; nn];
+        let mut e: Vec<f64> = vec![0.0; nn];
+        if nn == 0 {
+            return Ok(Eigen { values: Vec::new(), vectors: Some(Vec::new()), size: 0 });
+        }
+
+        orthes(&mut h, &mut v);
+        hqr2(&mut h, &mut v, &mut d, &mut e, with_vectors)?;
+
+        let values: Vec<Complex<f64>> = d.iter().zip(e.iter()).map(|(re, im)| Complex::new(*re, *im)).collect();
+        let vectors = if with_vectors {
+            // complex pairs are stored as real and imaginary part in consecutive columns
+            let mut vectors: Vec<Complex<f64>> = vec![Complex::new(0.0, 0.0); nn * nn];
+            for j in 0..nn {
+                for i in 0..nn {
+                    vectors[i * nn + j] = if e[j] > 0.0 {
+                        Complex::new(v[i][j], v[i][j + 1])
+                    } else if e[j] < 0.0 {
+                        Complex::new(v[i][j - 1], -v[i][j])
+                    } else {
+                        Complex::new(v[i][j], 0.0)
+                    };
+                }
+                let norm = (0..nn).fold(0.0, |sum, i| sum + vectors[i * nn + j].norm_sqr()).sqrt();
+                if norm != 0.0 {
+                    for i in 0..nn {
+                        vectors[i * nn + j] /= norm;
+                    }
+                }
+            }
+            Some(vectors)
+        } else {
+            None
+        };
+
+        Ok(Eigen { values, vectors, size: nn })
+    }
+}
+
+impl<'a> MatrixView<'a, f64> {
+
+    /// Returns the eigenvalues and the right eigenvectors of the view (see `Matrix::eigen`)
+    #[inline]
+    pub fn eigen(&self) -> Result<Eigen, Error> {
+        self.to_matrix().eigen()
+    }
+
+    /// Returns the eigenvalues of the view (see `Matrix::eigenvalues`)
+    #[inline]
+    pub fn eigenvalues(&self) -> Result<Vector<Complex<f64>>, Error> {
+        self.to_matrix().eigenvalues()
+    }
+}
+
+impl Eigen {
+
+    /// Returns the eigenvalues
+    #[inline]
+    pub fn eigenvalues(&self) -> Vector<Complex<f64>> {
+        Vector::<Complex<f64>>::from_vec(self.values.clone())
+    }
+
+    /// Returns the right eigenvectors as the columns of a complex matrix, if computed
+    ///
+    /// # Remarks
+    ///
+    /// * The column `i` is the eigenvector of the eigenvalue `i`, normalized
+    ///   to unit length
+    #[inline]
+    pub fn eigenvectors(&self) -> Option<Matrix<Complex<f64>>> {
+        self.vectors.as_ref().map(|vectors| Matrix::<Complex<f64>>::from_vec(self.size, self.size, vectors.clone()))
+    }
+}
+
+// reduces `h` to upper Hessenberg form with Householder similarity transformations,
+// accumulating them in `v`
+#[allow(clippy::needless_range_loop)] // keeps the indexing of the original routines
+fn orthes(h: &mut [Vec<f64>], v: &mut [Vec<f64>]) {
+    let n = h.len();
+    let high = n - 1;
+    let mut ort: Vec<f64> = vec![0.0; n];
+
+    for m in 1..high {
+        let scale = (m..n).fold(0.0, |sum, i| sum + h[i][m - 1].abs());
+        if scale == 0.0 {
+            continue;
+        }
+
+        // Householder transformation
+        let mut hh = 0.0;
+        for i in (m..n).rev() {
+            ort[i] = h[i][m - 1] / scale;
+            hh += ort[i] * ort[i];
+        }
+        let mut g = hh.sqrt();
+        if ort[m] > 0.0 {
+            g = -g;
+        }
+        hh -= ort[m] * g;
+        ort[m] -= g;
+
+        // H = (I - u * u' / h) * H * (I - u * u' / h)
+        for j in m..n {
+            let f = (m..n).rev().fold(0.0, |sum, i| sum + ort[i] * h[i][j]) / hh;
+            for i in m..n {
+                h[i][j] -= f * ort[i];
+            }
+        }
+        for row in h.iter_mut() {
+            let f = (m..n).rev().fold(0.0, |sum, j| sum + ort[j] * row[j]) / hh;
+            for j in m..n {
+                row[j] -= f * ort[j];
+            }
+        }
+        ort[m] *= scale;
+        h[m][m - 1] = scale * g;
+    }
+
+    // accumulate the transformations
+    for (i, row) in v.iter_mut().enumerate() {
+        row[i] = 1.0;
+    }
+    for m in (1..high).rev() {
+        if h[m][m - 1] == 0.0 {
+            continue;
+        }
+        for i in (m + 1)..n {
+            ort[i] = h[i][m - 1];
+        }
+        for j in m..n {
+            let g = (m..n).fold(0.0, |sum, i| sum + ort[i] * v[i][j]) / ort[m] / h[m][m - 1];
+            for i in m..n {
+                v[i][j] += g * ort[i];
+            }
+        }
+    }
+}
+
+// reduces the Hessenberg matrix `h` to real Schur form with the shifted QR
+// algorithm, storing the eigenvalues in `d` (real part) and `e` (imaginary part).
+// The eigenvectors are back-substituted and transformed into `v` if `with_vectors`.
+#[allow(clippy::needless_range_loop)] // keeps the indexing of the original routines
+fn hqr2(h: &mut [Vec<f64>], v: &mut [Vec<f64>], d: &mut [f64], e: &mut [f64],
+        with_vectors: bool) -> Result<(), Error> {
+    let nn = h.len();
+    let max_iter = 30 * nn.max(10);
+    let eps = f64::EPSILON;
+    let mut exshift = 0.0;
+    let (mut r, mut s, mut z) = (0.0, 0.0, 0.0);
+    let (mut p, mut q, mut w, mut x, mut y): (f64, f64, f64, f64, f64);
+
+    let mut norm = 0.0;
+    for (i, row) in h.iter().enumerate() {
+        norm += row[i.max(1) - 1..].iter().fold(0.0, |sum, el| sum + el.abs());
+    }
+
+    // `active` is the number of rows whose eigenvalues are not found yet
+    let mut active = nn;
+    let mut iter = 0;
+    let mut total_iter = 0;
+    while active > 0 {
+        let n = active - 1;
+
+        // look for a single small sub-diagonal element
+        let mut l = n;
+        while l > 0 {
+            s = h[l - 1][l - 1].abs() + h[l][l].abs();
+            if s == 0.0 {
+                s = norm;
+            }
+            if h[l][l - 1].abs() < eps * s {
+                break;
+            }
+            l -= 1;
+        }
+
+        if l == n {
+            // one root found
+            h[n][n] += exshift;
+            d[n] = h[n][n];
+            e[n] = 0.0;
+            active -= 1;
+            iter = 0;
+        } else if l == n - 1 {
+            // two roots found
+            w = h[n][n - 1] * h[n - 1][n];
+            p = (h[n - 1][n - 1] - h[n][n]) / 2.0;
+            q = p * p + w;
+            z = q.abs().sqrt();
+            h[n][n] += exshift;
+            h[n - 1][n - 1] += exshift;
+            x = h[n][n];
+
+            if q >= 0.0 {
+                // real pair
+                z = if p >= 0.0 { p + z } else { p - z };
+                d[n - 1] = x + z;
+                d[n] = d[n - 1];
+                if z != 0.0 {
+                    d[n] = x - w / z;
+                }
+                e[n - 1] = 0.0;
+                e[n] = 0.0;
+                x = h[n][n - 1];
+                s = x.abs() + z.abs();
+                p = x / s;
+                q = z / s;
+                r = (p * p + q * q).sqrt();
+                p /= r;
+                q /= r;
+
+                // row, column and accumulated modification
+                for j in (n - 1)..nn {
+                    z = h[n - 1][j];
+                    h[n - 1][j] = q * z + p * h[n][j];
+                    h[n][j] = q * h[n][j] - p * z;
+                }
+                for row in h.iter_mut().take(n + 1) {
+                    z = row[n - 1];
+                    row[n - 1] = q * z + p * row[n];
+                    row[n] = q * row[n] - p * z;
+                }
+                if with_vectors {
+                    for row in v.iter_mut() {
+                        z = row[n - 1];
+                        row[n - 1] = q * z + p * row[n];
+                        row[n] = q * row[n] - p * z;
+                    }
+                }
+            } else {
+                // complex pair
+                d[n - 1] = x + p;
+                d[n] = x + p;
+                e[n - 1] = z;
+                e[n] = -z;
+            }
+            active -= 2;
+            iter = 0;
+        } else {
+            if total_iter == max_iter {
+                return Err(Error::NoConvergence { iterations: max_iter });
+            }
+
+            // form shift
+            x = h[n][n];
+            y = h[n - 1][n - 1];
+            w = h[n][n - 1] * h[n - 1][n];
+
+            // Wilkinson's original ad hoc shift
+            if iter == 10 {
+                exshift += x;
+                for (i, row) in h.iter_mut().enumerate().take(n + 1) {
+                    row[i] -= x;
+                }
+                s = h[n][n - 1].abs() + h[n - 1][n - 2].abs();
+                x = 0.75 * s;
+                y = x;
+                w = -0.4375 * s * s;
+            }
+            // MATLAB's new ad hoc shift
+            if iter == 30 {
+                s = (y - x) / 2.0;
+                s = s * s + w;
+                if s > 0.0 {
+                    s = s.sqrt();
+                    if y < x {
+                        s = -s;
+                    }
+                    s = x - w / ((y - x) / 2.0 + s);
+                    for (i, row) in h.iter_mut().enumerate().take(n + 1) {
+                        row[i] -= s;
+                    }
+                    exshift += s;
+                    x = 0.964;
+                    y = x;
+                    w = x;
+                }
+            }
+            iter += 1;
+            total_iter += 1;
+
+            // look for two consecutive small sub-diagonal elements
+            let mut m = n - 2;
+            loop {
+                z = h[m][m];
+                r = x - z;
+                s = y - z;
+                p = (r * s - w) / h[m + 1][m] + h[m][m + 1];
+                q = h[m + 1][m + 1] - z - r - s;
+                r = h[m + 2][m + 1];
+                s = p.abs() + q.abs() + r.abs();
+                p /= s;
+                q /= s;
+                r /= s;
+                if m == l {
+                    break;
+                }
+                if h[m][m - 1].abs() * (q.abs() + r.abs())
+                    < eps * (p.abs() * (h[m - 1][m - 1].abs() + z.abs() + h[m + 1][m + 1].abs())) {
+                    break;
+                }
+                m -= 1;
+            }
+            for i in (m + 2)..(n + 1) {
+                h[i][i - 2] = 0.0;
+                if i > m + 2 {
+                    h[i][i - 3] = 0.0;
+                }
+            }
+
+            // double QR step involving rows l..n and columns m..n
+            for k in m..n {
+                let notlast = k != n - 1;
+                if k != m {
+                    p = h[k][k - 1];
+                    q = h[k + 1][k - 1];
+                    r = if notlast { h[k + 2][k - 1] } else { 0.0 };
+                    x = p.abs() + q.abs() + r.abs();
+                    if x == 0.0 {
+                        continue;
+                    }
+                    p /= x;
+                    q /= x;
+                    r /= x;
+                }
+
+                s = (p * p + q * q + r * r).sqrt();
+                if p < 0.0 {
+                    s = -s;
+                }
+                if s == 0.0 {
+                    continue;
+                }
+                if k != m {
+                    h[k][k - 1] = -s * x;
+                } else if l != m {
+                    h[k][k - 1] = -h[k][k - 1];
+                }
+                p += s;
+                x = p / s;
+                y = q / s;
+                z = r / s;
+                q /= p;
+                r /= p;
+
+                // row modification
+                for j in k..nn {
+                    p = h[k][j] + q * h[k + 1][j];
+                    if notlast {
+                        p += r * h[k + 2][j];
+                        h[k + 2][j] -= p * z;
+                    }
+                    h[k][j] -= p * x;
+                    h[k + 1][j] -= p * y;
+                }
+                // column modification
+                for row in h.iter_mut().take(n.min(k + 3) + 1) {
+                    p = x * row[k] + y * row[k + 1];
+                    if notlast {
+                        p += z * row[k + 2];
+                        row[k + 2] -= p * r;
+                    }
+                    row[k] -= p;
+                    row[k + 1] -= p * q;
+                }
+                // accumulate transformations
+                if with_vectors {
+                    for row in v.iter_mut() {
+                        p = x * row[k] + y * row[k + 1];
+                        if notlast {
+                            p += z * row[k + 2];
+                            row[k + 2] -= p * r;
+                        }
+                        row[k] -= p;
+                        row[k + 1] -= p * q;
+                    }
+                }
+            }
+        }
+    }
+
+    if !with_vectors || norm == 0.0 {
+        return Ok(());
+    }
+
+    // back-substitute to find the vectors of the upper triangular form
+    for n in (0..nn).rev() {
+        p = d[n];
+        q = e[n];
+
+        if q == 0.0 {
+            // real vector
+            let mut l = n;
+            h[n][n] = 1.0;
+            for i in (0..n).rev() {
+                w = h[i][i] - p;
+                r = (l..(n + 1)).fold(0.0, |sum, j| sum + h[i][j] * h[j][n]);
+                if e[i] < 0.0 {
+                    z = w;
+                    s = r;
+                    continue;
+                }
+
+                l = i;
+                if e[i] == 0.0 {
+                    h[i][n] = if w != 0.0 { -r / w } else { -r / (eps * norm) };
+                } else {
+                    // solve the real equations
+                    x = h[i][i + 1];
+                    y = h[i + 1][i];
+                    q = (d[i] - p) * (d[i] - p) + e[i] * e[i];
+                    let t = (x * s - z * r) / q;
+                    h[i][n] = t;
+                    h[i + 1][n] = if x.abs() > z.abs() { (-r - w * t) / x } else { (-s - y * t) / z };
+                }
+
+                // overflow control
+                let t = h[i][n].abs();
+                if (eps * t) * t > 1.0 {
+                    for row in h.iter_mut().take(n + 1).skip(i) {
+                        row[n] /= t;
+                    }
+                }
+            }
+        } else if q < 0.0 {
+            // complex vector, the last component is chosen imaginary so that
+            // the matrix is triangular
+            let mut l = n - 1;
+            if h[n][n - 1].abs() > h[n - 1][n].abs() {
+                h[n - 1][n - 1] = q / h[n][n - 1];
+                h[n - 1][n] = -(h[n][n] - p) / h[n][n - 1];
+            } else {
+                let (re, im) = cdiv(0.0, -h[n - 1][n], h[n - 1][n - 1] - p, q);
+                h[n - 1][n - 1] = re;
+                h[n - 1][n] = im;
+            }
+            h[n][n - 1] = 0.0;
+            h[n][n] = 1.0;
+
+            for i in (0..(n - 1)).rev() {
+                let (mut ra, mut sa) = (0.0, 0.0);
+                for j in l..(n + 1) {
+                    ra += h[i][j] * h[j][n - 1];
+                    sa += h[i][j] * h[j][n];
+                }
+                w = h[i][i] - p;
+
+                if e[i] < 0.0 {
+                    z = w;
+                    r = ra;
+                    s = sa;
+                    continue;
+                }
+
+                l = i;
+                if e[i] == 0.0 {
+                    let (re, im) = cdiv(-ra, -sa, w, q);
+                    h[i][n - 1] = re;
+                    h[i][n] = im;
+                } else {
+                    // solve the complex equations
+                    x = h[i][i + 1];
+                    y = h[i + 1][i];
+                    let mut vr = (d[i] - p) * (d[i] - p) + e[i] * e[i] - q * q;
+                    let vi = (d[i] - p) * 2.0 * q;
+                    if vr == 0.0 && vi == 0.0 {
+                        vr = eps * norm * (w.abs() + q.abs() + x.abs() + y.abs() + z.abs());
+                    }
+                    let (re, im) = cdiv(x * r - z * ra + q * sa, x * s - z * sa - q * ra, vr, vi);
+                    h[i][n - 1] = re;
+                    h[i][n] = im;
+                    if x.abs() > z.abs() + q.abs() {
+                        h[i + 1][n - 1] = (-ra - w * h[i][n - 1] + q * h[i][n]) / x;
+                        h[i + 1][n] = (-sa - w * h[i][n] - q * h[i][n - 1]) / x;
+                    } else {
+                        let (re, im) = cdiv(-r - y * h[i][n - 1], -s - y * h[i][n], z, q);
+                        h[i + 1][n - 1] = re;
+                        h[i + 1][n] = im;
+                    }
+                }
+
+                // overflow control
+                let t = h[i][n - 1].abs().max(h[i][n].abs());
+                if (eps * t) * t > 1.0 {
+                    for row in h.iter_mut().take(n + 1).skip(i) {
+                        row[n - 1] /= t;
+                        row[n] /= t;
+                    }
+                }
+            }
+        }
+    }
+
+    // back transformation to get the eigenvectors of the original matrix
+    for j in (0..nn).rev() {
+        for row in v.iter_mut() {
+            z = (0..(j + 1)).fold(0.0, |sum, k| sum + row[k] * h[k][j]);
+            row[j] = z;
+        }
+    }
+    Ok(())
+}
+
+// complex scalar division (xr + i * xi) / (yr + i * yi) avoiding overflow
+fn cdiv(xr: f64, xi: f64, yr: f64, yi: f64) -> (f64, f64) {
+    if yr.abs() > yi.abs() {
+        let r = yi / yr;
+        let d = yr + r * yi;
+        ((xr + r * xi) / d, (xi - r * xr) / d)
+    } else {
+        let r = yr / yi;
+        let d = yi + r * yr;
+        ((r * xr + xi) / d, (r * xi - xr) / d)
+    }
+}
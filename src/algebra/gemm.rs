@@ -0,0 +1,136 @@
+use num::Num;
+use algebra::matrix::{Matrix, ParallelElement};
+use error::Error;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+// number of rows of the result computed by each block (and by each parallel task)
+const ROW_BLOCK: usize = 64;
+// number of elements of the inner dimension walked by each block
+const INNER_BLOCK: usize = 256;
+// number of columns of the result updated by each block
+const COL_BLOCK: usize = 512;
+// products with less multiplications than this are not worth to parallelize
+#[cfg(feature = "parallel")]
+const PARALLEL_THRESHOLD: usize = 64 * 64 * 64;
+
+////////////////////////////////////////////////////////////////////////////////
+// Inherent methods
+////////////////////////////////////////////////////////////////////////////////
+
+impl<N: Copy> Matrix<N> {
+
+    /// Accumulates a matrix product in place: `c = alpha * a * b + beta * c`
+    ///
+    /// # Arguments
+    ///
+    /// * `alpha`: factor of the product
+    /// * `a`: left-hand side of the product (`m x k`)
+    /// * `b`: right-hand side of the product (`k x n`)
+    /// * `beta`: factor of the current values of `c`
+    /// * `c`: accumulated result (`m x n`)
+    ///
+    /// # Remarks
+    ///
+    /// * Panics if the sizes are not compatible (see `try_gemm`)
+    /// * If `beta` is zero the current values of `c` are ignored, even if
+    ///   they are not finite
+    /// * With the `parallel` feature, big products are split by rows over
+    ///   the `rayon` thread pool
+    #[inline]
+    pub fn gemm(alpha: N, a: &Matrix<N>, b: &Matrix<N>, beta: N, c: &mut Matrix<N>)
+        where N: Num + ParallelElement {
+        if let Err(e) = Matrix::<N>::try_gemm(alpha, a, b, beta, c) {
+            panic!("{}", e);
+        }
+    }
+
+    /// Accumulates a matrix product in place: `c = alpha * a * b + beta * c`
+    ///
+    /// # Arguments
+    ///
+    /// * `alpha`: factor of the product
+    /// * `a`: left-hand side of the product (`m x k`)
+    /// * `b`: right-hand side of the product (`k x n`)
+    /// * `beta`: factor of the current values of `c`
+    /// * `c`: accumulated result (`m x n`)
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::DimensionMismatch` if `b` hasn't as many rows as
+    ///   columns in `a`, or `c` hasn't the size of the product
+    #[inline]
+    pub fn try_gemm(alpha: N, a: &Matrix<N>, b: &Matrix<N>, beta: N, c: &mut Matrix<N>)
+        -> Result<(), Error> where N: Num + ParallelElement {
+        if a.ncols() != b.nrows() {
+            return Err(Error::DimensionMismatch { expected: (a.ncols(), b.ncols()),
+                                                  found: (b.nrows(), b.ncols()) });
+        }
+        if c.nrows() != a.nrows() || c.ncols() != b.ncols() {
+            return Err(Error::DimensionMismatch { expected: (a.nrows(), b.ncols()),
+                                                  found: (c.nrows(), c.ncols()) });
+        }
+
+        gemm(alpha, a.as_slice(), b.as_slice(), beta, c.as_mut_slice(), a.ncols(), b.ncols());
+        Ok(())
+    }
+}
+
+// computes `c = alpha * a * b + beta * c` for row-major buffers, where `a` has
+// `k` columns and `b` and `c` have `n` columns
+pub(crate) fn gemm<N>(alpha: N, a: &[N], b: &[N], beta: N, c: &mut [N], k: usize, n: usize)
+    where N: Copy + Num + ParallelElement {
+    if n == 0 {
+        return;
+    }
+
+    #[cfg(feature = "parallel")]
+    {
+        let m = c.len() / n;
+        if m > ROW_BLOCK && m * n * k >= PARALLEL_THRESHOLD {
+            c.par_chunks_mut(ROW_BLOCK * n)
+             .zip(a.par_chunks(ROW_BLOCK * k.max(1)))
+             .for_each(|(c_block, a_block)| gemm_blocked(alpha, a_block, b, beta, c_block, k, n));
+            return;
+        }
+    }
+    gemm_blocked(alpha, a, b, beta, c, k, n);
+}
+
+// serial kernel: the product is walked by blocks of the result and the inner
+// dimension, so the rows of `b` that are reused stay in cache
+fn gemm_blocked<N>(alpha: N, a: &[N], b: &[N], beta: N, c: &mut [N], k: usize, n: usize)
+    where N: Copy + Num {
+    if beta == N::zero() {
+        for el in c.iter_mut() {
+            *el = N::zero();
+        }
+    } else if beta != N::one() {
+        for el in c.iter_mut() {
+            *el = beta * *el;
+        }
+    }
+    if k == 0 || alpha == N::zero() {
+        return;
+    }
+
+    for (c_rows, a_rows) in c.chunks_mut(ROW_BLOCK * n).zip(a.chunks(ROW_BLOCK * k)) {
+        for p0 in (0..k).step_by(INNER_BLOCK) {
+            let p1 = (p0 + INNER_BLOCK).min(k);
+            for j0 in (0..n).step_by(COL_BLOCK) {
+                let j1 = (j0 + COL_BLOCK).min(n);
+
+                for (c_row, a_row) in c_rows.chunks_mut(n).zip(a_rows.chunks(k)) {
+                    let c_row = &mut c_row[j0..j1];
+                    for (p, a_el) in a_row.iter().enumerate().take(p1).skip(p0) {
+                        let factor = alpha * *a_el;
+                        for (res, b_el) in c_row.iter_mut().zip(b[p * n + j0..p * n + j1].iter()) {
+                            *res = *res + factor * *b_el;
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,268 @@
+use num::{Float, NumCast};
+use algebra::matrix::Matrix;
+use algebra::view::MatrixView;
+use algebra::vector::Vector;
+use error::Error;
+
+/// LU decomposition with partial pivoting of a square matrix, so that
+/// `P * A = L * U`
+///
+/// # Remarks
+///
+/// * `L` is unit lower triangular and `U` upper triangular. Both are stored
+///   packed in a single matrix.
+/// * This struct is created by `Matrix::lu` and is just available for float types.
+pub struct LU<N: Copy> {
+    lu: Vec<N>,
+    size: usize,
+    perm: Vec<usize>,
+    sign: N,
+    tol: Vec<N>,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Inherent methods
+////////////////////////////////////////////////////////////////////////////////
+
+impl<N: Copy + Float> Matrix<N> {
+
+    /// Returns the LU decomposition with partial pivoting of the matrix
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::NotSquare` if the matrix is not square
+    /// * A singular matrix can be decomposed, but its `LU` can't be inverted
+    ///   or used to solve a system
+    #[inline]
+    pub fn lu(&self) -> Result<LU<N>, Error> {
+        if self.nrows() != self.ncols() {
+            return Err(Error::NotSquare { nrows: self.nrows(), ncols: self.ncols() });
+        }
+
+        let n = self.nrows();
+        let mut a: Vec<N> = self.as_slice().to_vec();
+        let mut perm: Vec<usize> = (0..n).collect();
+        let mut sign = N::one();
+
+        for k in 0..n {
+            let mut p = k;
+            for i in (k + 1)..n {
+                if a[i * n + k].abs() > a[p * n + k].abs() {
+                    p = i;
+                }
+            }
+            if p != k {
+                for j in 0..n {
+                    a.swap(k * n + j, p * n + j);
+                }
+                perm.swap(k, p);
+                sign = -sign;
+            }
+
+            let pivot = a[k * n + k];
+            if pivot == N::zero() { // nothing to eliminate in this column
+                continue;
+            }
+            for i in (k + 1)..n {
+                let factor = a[i * n + k] / pivot;
+                a[i * n + k] = factor;
+                for j in (k + 1)..n {
+                    a[i * n + j] = a[i * n + j] - factor * a[k * n + j];
+                }
+            }
+        }
+
+        // pivots smaller than the rounding error of their own elimination,
+        // `u_kk = a_kk - sum(l_kj * u_jk)`, are considered zero
+        let eps = N::epsilon() * <N as NumCast>::from(n).unwrap();
+        let mut tol: Vec<N> = Vec::with_capacity(n);
+        for k in 0..n {
+            let terms = (0..k).fold(N::zero(), |sum, j| sum + (a[k * n + j] * a[j * n + k]).abs());
+            tol.push(eps * (self.as_slice()[perm[k] * n + k].abs() + terms));
+        }
+
+        Ok(LU { lu: a, size: n, perm, sign, tol })
+    }
+
+    /// Returns the determinant of the matrix, computed with its LU decomposition
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::NotSquare` if the matrix is not square
+    #[inline]
+    pub fn determinant(&self) -> Result<N, Error> {
+        Ok(self.lu()?.determinant())
+    }
+
+    /// Returns the inverse of the matrix, computed with its LU decomposition
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::NotSquare` if the matrix is not square
+    /// * Returns `Error::Singular` if the matrix is singular
+    #[inline]
+    pub fn inverse(&self) -> Result<Matrix<N>, Error> {
+        self.lu()?.inverse()
+    }
+}
+
+impl<'a, N: Copy + Float> MatrixView<'a, N> {
+
+    /// Returns the LU decomposition with partial pivoting of the view (see `Matrix::lu`)
+    #[inline]
+    pub fn lu(&self) -> Result<LU<N>, Error> {
+        self.to_matrix().lu()
+    }
+
+    /// Returns the determinant of the view (see `Matrix::determinant`)
+    #[inline]
+    pub fn determinant(&self) -> Result<N, Error> {
+        self.to_matrix().determinant()
+    }
+
+    /// Returns the inverse of the view (see `Matrix::inverse`)
+    #[inline]
+    pub fn inverse(&self) -> Result<Matrix<N>, Error> {
+        self.to_matrix().inverse()
+    }
+}
+
+impl<N: Copy + Float> LU<N> {
+
+    /// Returns the unit lower triangular factor `L`
+    #[inline]
+    pub fn l(&self) -> Matrix<N> {
+        let n = self.size;
+        let mut values: Vec<N> = vec![N::zero(); n * n];
+        for i in 0..n {
+            values[i * n..i * n + i].copy_from_slice(&self.lu[i * n..i * n + i]);
+            values[i * n + i] = N::one();
+        }
+        Matrix::<N>::from_vec(n, n, values)
+    }
+
+    /// Returns the upper triangular factor `U`
+    #[inline]
+    pub fn u(&self) -> Matrix<N> {
+        let n = self.size;
+        let mut values: Vec<N> = vec![N::zero(); n * n];
+        for i in 0..n {
+            values[i * n + i..(i + 1) * n].copy_from_slice(&self.lu[i * n + i..(i + 1) * n]);
+        }
+        Matrix::<N>::from_vec(n, n, values)
+    }
+
+    /// Returns the row permutation: the row `i` of `P * A` is the row
+    /// `permutation()[i]` of `A`
+    #[inline]
+    pub fn permutation(&self) -> &[usize] {
+        &self.perm
+    }
+
+    /// Returns the permutation matrix `P`
+    #[inline]
+    pub fn p(&self) -> Matrix<N> {
+        let n = self.size;
+        let mut values: Vec<N> = vec![N::zero(); n * n];
+        for (i, p) in self.perm.iter().enumerate() {
+            values[i * n + p] = N::one();
+        }
+        Matrix::<N>::from_vec(n, n, values)
+    }
+
+    /// Returns if the decomposed matrix is singular
+    ///
+    /// # Remarks
+    ///
+    /// * A pivot is considered zero if it's not bigger than the rounding error
+    ///   of its own elimination, `n * epsilon * (|a_kk| + sum(|l_kj * u_jk|))`,
+    ///   so the result doesn't depend on the scale of the rows or columns
+    #[inline]
+    pub fn is_singular(&self) -> bool {
+        (0..self.size).any(|i| self.lu[i * self.size + i].abs() <= self.tol[i])
+    }
+
+    /// Returns the determinant of the decomposed matrix
+    #[inline]
+    pub fn determinant(&self) -> N {
+        (0..self.size).fold(self.sign, |det, i| det * self.lu[i * self.size + i])
+    }
+
+    /// Returns the inverse of the decomposed matrix
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::Singular` if the matrix is singular
+    #[inline]
+    pub fn inverse(&self) -> Result<Matrix<N>, Error> {
+        self.solve(&Matrix::<N>::create_identity(self.size))
+    }
+
+    /// Solves the system `A * X = B` for one or many right-hand sides
+    ///
+    /// # Arguments
+    ///
+    /// * `b`: right-hand sides, one per column
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::DimensionMismatch` if `b` hasn't as many rows as `A`
+    /// * Returns `Error::Singular` if the matrix is singular
+    #[inline]
+    pub fn solve(&self, b: &Matrix<N>) -> Result<Matrix<N>, Error> {
+        let n = self.size;
+        if b.nrows() != n {
+            return Err(Error::DimensionMismatch { expected: (n, b.ncols()), found: (b.nrows(), b.ncols()) });
+        }
+        if self.is_singular() {
+            return Err(Error::Singular);
+        }
+
+        let m = b.ncols();
+        let mut x: Vec<N> = Vec::with_capacity(n * m);
+        for p in self.perm.iter() {
+            x.extend_from_slice(&b.as_slice()[p * m..(p + 1) * m]);
+        }
+
+        // forward substitution with L
+        for i in 0..n {
+            for k in 0..i {
+                let factor = self.lu[i * n + k];
+                for j in 0..m {
+                    x[i * m + j] = x[i * m + j] - factor * x[k * m + j];
+                }
+            }
+        }
+        // back substitution with U
+        for i in (0..n).rev() {
+            for k in (i + 1)..n {
+                let factor = self.lu[i * n + k];
+                for j in 0..m {
+                    x[i * m + j] = x[i * m + j] - factor * x[k * m + j];
+                }
+            }
+            let pivot = self.lu[i * n + i];
+            for j in 0..m {
+                x[i * m + j] = x[i * m + j] / pivot;
+            }
+        }
+
+        Ok(Matrix::<N>::from_vec(n, m, x))
+    }
+
+    /// Solves the system `A * x = b` for a single right-hand side
+    ///
+    /// # Arguments
+    ///
+    /// * `b`: right-hand side
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::DimensionMismatch` if `b` hasn't as many elements as rows in `A`
+    /// * Returns `Error::Singular` if the matrix is singular
+    #[inline]
+    pub fn solve_vector(&self, b: &Vector<N>) -> Result<Vector<N>, Error> {
+        let x = self.solve(&Matrix::<N>::from_vec(b.size(), 1, b.as_slice().to_vec()))?;
+        Ok(Vector::<N>::from_vec(x.into_vec()))
+    }
+}
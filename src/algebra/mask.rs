@@ -0,0 +1,201 @@
+use std::ops::{BitAnd, BitOr, Not};
+use algebra::matrix::Matrix;
+use algebra::vector::Vector;
+use error::Error;
+
+////////////////////////////////////////////////////////////////////////////////
+// Inherent methods
+////////////////////////////////////////////////////////////////////////////////
+
+impl<N: Copy> Matrix<N> {
+
+    /// Returns the elements where a mask is true, row by row
+    ///
+    /// # Arguments
+    ///
+    /// * `mask`: matrix of booleans of the same size, for example the result
+    ///   of `bigger_than`
+    ///
+    /// # Remarks
+    ///
+    /// * Panics if the sizes are not equal (see `try_select`)
+    #[inline]
+    pub fn select(&self, mask: &Matrix<bool>) -> Vector<N> {
+        match self.try_select(mask) {
+            Ok(v) => v,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Returns the elements where a mask is true, row by row
+    ///
+    /// # Arguments
+    ///
+    /// * `mask`: matrix of booleans of the same size
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::DimensionMismatch` if the sizes are not equal
+    #[inline]
+    pub fn try_select(&self, mask: &Matrix<bool>) -> Result<Vector<N>, Error> {
+        self.check_same_size(mask)?;
+
+        let values: Vec<N> = self.as_slice().iter()
+                                            .zip(mask.as_slice().iter())
+                                            .filter(|&(_, m)| *m)
+                                            .map(|(el, _)| *el)
+                                            .collect();
+        Ok(Vector::<N>::from_vec(values))
+    }
+
+    /// Sets a value in every element where a mask is true
+    ///
+    /// # Arguments
+    ///
+    /// * `mask`: matrix of booleans of the same size
+    /// * `value`: new value of the selected elements
+    ///
+    /// # Remarks
+    ///
+    /// * Panics if the sizes are not equal (see `try_set_where`)
+    #[inline]
+    pub fn set_where(&mut self, mask: &Matrix<bool>, value: N) {
+        if let Err(e) = self.try_set_where(mask, value) {
+            panic!("{}", e);
+        }
+    }
+
+    /// Sets a value in every element where a mask is true
+    ///
+    /// # Arguments
+    ///
+    /// * `mask`: matrix of booleans of the same size
+    /// * `value`: new value of the selected elements
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::DimensionMismatch` if the sizes are not equal
+    #[inline]
+    pub fn try_set_where(&mut self, mask: &Matrix<bool>, value: N) -> Result<(), Error> {
+        self.check_same_size(mask)?;
+
+        for (el, m) in self.as_mut_slice().iter_mut().zip(mask.as_slice().iter()) {
+            if *m {
+                *el = value;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns a matrix with the elements of the matrix where a mask is true,
+    /// and the elements of another matrix where it's false
+    ///
+    /// # Arguments
+    ///
+    /// * `mask`: matrix of booleans of the same size
+    /// * `other`: matrix with the elements used where the mask is false
+    ///
+    /// # Remarks
+    ///
+    /// * Panics if the sizes are not equal (see `try_where_`)
+    #[inline]
+    pub fn where_(&self, mask: &Matrix<bool>, other: &Matrix<N>) -> Matrix<N> {
+        match self.try_where_(mask, other) {
+            Ok(m) => m,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Returns a matrix with the elements of the matrix where a mask is true,
+    /// and the elements of another matrix where it's false
+    ///
+    /// # Arguments
+    ///
+    /// * `mask`: matrix of booleans of the same size
+    /// * `other`: matrix with the elements used where the mask is false
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::DimensionMismatch` if the mask or the other matrix
+    ///   haven't the size of the matrix
+    #[inline]
+    pub fn try_where_(&self, mask: &Matrix<bool>, other: &Matrix<N>) -> Result<Matrix<N>, Error> {
+        self.check_same_size(mask)?;
+        self.check_same_size(other)?;
+
+        let values: Vec<N> = self.as_slice().iter()
+                                            .zip(other.as_slice().iter())
+                                            .zip(mask.as_slice().iter())
+                                            .map(|((a, b), m)| if *m { *a } else { *b })
+                                            .collect();
+        Ok(Matrix::<N>::from_vec(self.nrows(), self.ncols(), values))
+    }
+}
+
+impl Matrix<bool> {
+
+    /// Returns the number of true elements of a mask
+    #[inline]
+    pub fn count_true(&self) -> usize {
+        self.as_slice().iter().filter(|m| **m).count()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Traits
+////////////////////////////////////////////////////////////////////////////////
+
+/// Logical and ´&´ implementation for &Matrix<bool>
+impl<'a> BitAnd for &'a Matrix<bool> {
+    type Output = Matrix<bool>;
+
+    fn bitand(self, other: &'a Matrix<bool>) -> Matrix<bool> {
+        self.zip_map(other, |a, b| a & b)
+    }
+}
+
+/// Logical and ´&´ implementation for Matrix<bool>
+impl BitAnd for Matrix<bool> {
+    type Output = Matrix<bool>;
+
+    fn bitand(self, other: Matrix<bool>) -> Matrix<bool> {
+        (&self) & (&other)
+    }
+}
+
+/// Logical or ´|´ implementation for &Matrix<bool>
+impl<'a> BitOr for &'a Matrix<bool> {
+    type Output = Matrix<bool>;
+
+    fn bitor(self, other: &'a Matrix<bool>) -> Matrix<bool> {
+        self.zip_map(other, |a, b| a | b)
+    }
+}
+
+/// Logical or ´|´ implementation for Matrix<bool>
+impl BitOr for Matrix<bool> {
+    type Output = Matrix<bool>;
+
+    fn bitor(self, other: Matrix<bool>) -> Matrix<bool> {
+        (&self) | (&other)
+    }
+}
+
+/// Logical not ´!´ implementation for &Matrix<bool>
+impl Not for &Matrix<bool> {
+    type Output = Matrix<bool>;
+
+    fn not(self) -> Matrix<bool> {
+        self.map(|m| !m)
+    }
+}
+
+/// Logical not ´!´ implementation for Matrix<bool>
+impl Not for Matrix<bool> {
+    type Output = Matrix<bool>;
+
+    fn not(mut self) -> Matrix<bool> {
+        self.map_inplace(|m| !m);
+        self
+    }
+}
@@ -4,24 +4,72 @@ use rand::Rng;
 use num::pow;
 use num::{Num, NumCast, ToPrimitive};
 use std::fmt;
+use std::slice;
+use std::vec;
 use std::fmt::Display;
 use std::cmp::{PartialEq};
-use std::ops::{Add, Sub, Mul};
+use std::ops::{Add, Sub, Mul, Div, Neg, Index, IndexMut};
+use std::ops::{AddAssign, SubAssign, MulAssign, DivAssign};
 use rand::distributions::range::SampleRange;
+use error::Error;
+use algebra::gemm;
+use algebra::vector::Vector;
 
 /// Matrix with a defined number of rows and columns that can
 /// add, remove and edit values.
 ///
 /// # Remarks
 ///
-/// This struct is implemented to be used with numerical types, not tested
-/// for strings, bools, or other types.
+/// * This struct is implemented to be used with numerical types, not tested
+///   for strings, bools, or other types.
+/// * The values are stored in a single contiguous buffer in row-major order,
+///   so the element `(i, j)` lives at `i * row_stride + j * col_stride`.
 pub struct Matrix<N: Copy> {
-    values: Vec<Vec<N>>,
+    values: Vec<N>,
     nrows: usize,
     ncols: usize,
 }
 
+/// Orientation of a vector with respect to a matrix, used to broadcast a
+/// vector over a matrix
+///
+/// # Remarks
+///
+/// * `Axis::Row`: the vector is a row, it has one element per column and
+///   it's applied to every row of the matrix
+/// * `Axis::Col`: the vector is a column, it has one element per row and
+///   it's applied to every column of the matrix
+/// * Reductions keep the same orientation: along `Axis::Row` the rows are
+///   combined into a row (one value per column), along `Axis::Col` the
+///   columns are combined into a column (one value per row)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    Row,
+    Col,
+}
+
+/// Bound of the elements of the products, which can be computed in parallel
+///
+/// # Remarks
+///
+/// * With the `parallel` feature it's `Send + Sync`, so the elements can be
+///   shared with the `rayon` thread pool; without it every type has it
+#[cfg(feature = "parallel")]
+pub trait ParallelElement: Send + Sync {}
+#[cfg(feature = "parallel")]
+impl<T: Send + Sync> ParallelElement for T {}
+
+/// Bound of the elements of the products, which can be computed in parallel
+///
+/// # Remarks
+///
+/// * With the `parallel` feature it's `Send + Sync`, so the elements can be
+///   shared with the `rayon` thread pool; without it every type has it
+#[cfg(not(feature = "parallel"))]
+pub trait ParallelElement {}
+#[cfg(not(feature = "parallel"))]
+impl<T> ParallelElement for T {}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Inherent methods
 ////////////////////////////////////////////////////////////////////////////////
@@ -36,6 +84,13 @@ impl<N: Copy> Matrix<N> { // implementation of Matrix<N>
                       ncols: 0 }
     }
 
+    // internal use: builds a matrix from a row-major buffer
+    #[inline]
+    pub(crate) fn from_vec(nrows: usize, ncols: usize, values: Vec<N>) -> Matrix<N> {
+        debug_assert_eq!(values.len(), nrows * ncols);
+        Matrix::<N> { values, nrows, ncols }
+    }
+
     /// Initializes a Matrix variable with a defined capacity for the rows
     /// and the columns
     ///
@@ -45,11 +100,7 @@ impl<N: Copy> Matrix<N> { // implementation of Matrix<N>
     /// * `nc`: columns capacity
     #[inline]
     pub fn init_with_capacity(nr: usize, nc: usize) -> Matrix<N> {
-        let mut vec: Vec<Vec<N>> = Vec::with_capacity(nr);
-        for i in 0..vec.len() {
-            vec[i] = Vec::with_capacity(nc);
-        }
-        Matrix::<N> { values: vec,
+        Matrix::<N> { values: Vec::with_capacity(nr * nc),
                       nrows: nr,
                       ncols: nc }
     }
@@ -62,8 +113,9 @@ impl<N: Copy> Matrix<N> { // implementation of Matrix<N>
     #[inline]
     pub fn init(val: &Vec<Vec<N>>) -> Matrix<N> {
         let mut m = Matrix::<N>::new();
-        m.values = val.clone();
-        m.update_sizes();
+        for row in val.iter() {
+            m.push_row(row.clone());
+        }
         m
     }
 
@@ -74,24 +126,45 @@ impl<N: Copy> Matrix<N> { // implementation of Matrix<N>
     /// * `size_rows`: number of rows
     /// * `size_columns`: number of columns
     /// * `range`: range of the values
+    ///
+    /// # Remarks
+    ///
+    /// * Panics if the range is not ascendent (see `try_random`)
     #[inline]
     pub fn random(size_rows: usize, size_columns: usize, range: &[N; 2])
         -> Matrix<N> where N: Num + PartialOrd + SampleRange {
 
-        if range.len() != 2 {
-                panic!("just permitted range of size 2 (actual={})", range.len());
+        match Matrix::<N>::try_random(size_rows, size_columns, range) {
+            Ok(m) => m,
+            Err(e) => panic!("{}", e),
         }
+    }
 
-        let mut m = Matrix::<N>::new();
-        for _ in 0..size_rows {
-            let mut col: Vec<N> = Vec::new();
-            for _ in 0..size_columns {
-                col.push(rand::thread_rng().gen_range(range[0], range[1] + N::one()));
-            }
-            m.values.push(col);
+    /// Creates a Matrix variable with random values with a defined size
+    ///
+    /// # Arguments
+    ///
+    /// * `size_rows`: number of rows
+    /// * `size_columns`: number of columns
+    /// * `range`: range of the values
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::InvalidRange` if the range is not ascendent
+    #[inline]
+    pub fn try_random(size_rows: usize, size_columns: usize, range: &[N; 2])
+        -> Result<Matrix<N>, Error> where N: Num + PartialOrd + SampleRange {
+
+        if range[0] > range[1] {
+            return Err(Error::InvalidRange);
         }
-        m.update_sizes();
-        m
+
+        let mut rng = rand::thread_rng();
+        let mut values: Vec<N> = Vec::with_capacity(size_rows * size_columns);
+        for _ in 0..(size_rows * size_columns) {
+            values.push(rng.gen_range(range[0], range[1] + N::one()));
+        }
+        Ok(Matrix::<N>::from_vec(size_rows, size_columns, values))
     }
 
     /// Creates a Matrix of 0s
@@ -104,11 +177,7 @@ impl<N: Copy> Matrix<N> { // implementation of Matrix<N>
     pub fn zeros(size_rows: usize, size_columns: usize)
         -> Matrix<N> where N: Num + Default {
 
-        let mut m = Matrix::<N>::new();
-        for _ in 0..size_rows {
-            m.push_row(vec![N::zero(); size_columns]);
-        }
-        m
+        Matrix::<N>::from_vec(size_rows, size_columns, vec![N::zero(); size_rows * size_columns])
     }
 
     /// Creates a Matrix of 1s
@@ -121,11 +190,7 @@ impl<N: Copy> Matrix<N> { // implementation of Matrix<N>
     pub fn ones(size_rows: usize, size_columns: usize)
         -> Matrix<N> where N: Num + Default {
 
-        let mut m = Matrix::<N>::new();
-        for _ in 0..size_rows {
-            m.push_row(vec![N::one(); size_columns]);
-        }
-        m
+        Matrix::<N>::from_vec(size_rows, size_columns, vec![N::one(); size_rows * size_columns])
     }
 
     /// Creates a Matrix variable with the identity matrix values
@@ -137,17 +202,10 @@ impl<N: Copy> Matrix<N> { // implementation of Matrix<N>
     pub fn create_identity(size: usize) -> Matrix<N>
         where N: Num + NumCast + ToPrimitive {
 
-        let mut m: Matrix<N> = Matrix::<N>::init_with_capacity(size, size);
+        let zero: N = NumCast::from(0usize).unwrap();
+        let mut m: Matrix<N> = Matrix::<N>::from_vec(size, size, vec![zero; size * size]);
         for i in 0..size {
-            let mut col: Vec<N> = Vec::new();
-            for j in 0..size {
-                if i == j {
-                    col.push(NumCast::from(1usize).unwrap());
-                } else {
-                    col.push(NumCast::from(0usize).unwrap());
-                }
-            }
-            m.values.push(col);
+            m.values[i * (size + 1)] = NumCast::from(1usize).unwrap();
         }
         m
     }
@@ -176,19 +234,85 @@ impl<N: Copy> Matrix<N> { // implementation of Matrix<N>
         self.ncols
     }
 
+    /// Returns the elements of the matrix, row by row
+    #[inline]
+    pub fn as_slice(&self) -> &[N] {
+        &self.values
+    }
+
+    /// Returns the mutable elements of the matrix, row by row
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [N] {
+        &mut self.values
+    }
+
+    // internal use: consumes the matrix returning its row-major buffer
+    #[inline]
+    pub(crate) fn into_vec(self) -> Vec<N> {
+        self.values
+    }
+
+    /// Returns the strides `(row_stride, col_stride)` of the underlying buffer
+    ///
+    /// # Remarks
+    ///
+    /// * The element `(i, j)` is stored at `i * row_stride + j * col_stride`
+    #[inline]
+    pub fn strides(&self) -> (usize, usize) {
+        (self.ncols, 1)
+    }
+
+    // internal use: position of an element inside the buffer
+    #[inline]
+    fn offset(&self, i_row: usize, i_col: usize) -> usize {
+        let (row_stride, col_stride) = self.strides();
+        i_row * row_stride + i_col * col_stride
+    }
+
     /// Returns a defined row by an index
     ///
     /// # Arguments
     ///
     /// * `index`: index of the row that wants to be returned
+    ///
+    /// # Remarks
+    ///
+    /// * The row is copied, `row_slice` borrows it without copying
     #[inline]
-    pub fn row(&self, index: usize) -> Option<&Vec<N>> {
-        for (i, item) in self.values.iter().enumerate() {
-            if i == index {
-                return Some(item);
-            }
+    pub fn row(&self, index: usize) -> Option<Vec<N>> {
+        self.row_slice(index).map(|row| row.to_vec())
+    }
+
+    /// Returns a defined row by an index, borrowed from the matrix
+    ///
+    /// # Arguments
+    ///
+    /// * `index`: index of the row that wants to be returned
+    #[inline]
+    pub fn row_slice(&self, index: usize) -> Option<&[N]> {
+        if index >= self.nrows {
+            return None;
+        }
+
+        let start = self.offset(index, 0);
+        self.values.get(start..start + self.ncols)
+    }
+
+    /// Modifies a row with given index and value
+    ///
+    /// # Arguments
+    ///
+    /// * `index`: index of the row that must be modified
+    /// * `new_row`: new row value
+    ///
+    /// # Remarks
+    ///
+    /// * Panics if the index or the row's size are not valid (see `try_set_row`)
+    #[inline]
+    pub fn set_row(&mut self, index: usize, new_row: &[N]) {
+        if let Err(e) = self.try_set_row(index, new_row) {
+            panic!("{}", e);
         }
-        None
     }
 
     /// Modifies a row with given index and value
@@ -197,16 +321,23 @@ impl<N: Copy> Matrix<N> { // implementation of Matrix<N>
     ///
     /// * `index`: index of the row that must be modified
     /// * `new_row`: new row value
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::IndexOutOfBounds` if the row doesn't exist
+    /// * Returns `Error::DimensionMismatch` if the row's size is not the number of columns
     #[inline]
-    pub fn set_row(&mut self, index: usize, new_row: &Vec<N>) {
+    pub fn try_set_row(&mut self, index: usize, new_row: &[N]) -> Result<(), Error> {
         if index >= self.nrows() {
-            panic!("index out of range")
+            return Err(Error::IndexOutOfBounds { index, size: self.nrows });
         }
         if new_row.len() != self.ncols() {
-            panic!("input vector dimension mismatch");
+            return Err(Error::DimensionMismatch { expected: (1, self.ncols), found: (1, new_row.len()) });
         }
 
-        self.values[index].clone_from(new_row);
+        let start = self.offset(index, 0);
+        self.values[start..start + self.ncols].copy_from_slice(new_row);
+        Ok(())
     }
 
     /// Returns a defined column by an index
@@ -216,15 +347,28 @@ impl<N: Copy> Matrix<N> { // implementation of Matrix<N>
     /// * `index`: index of the column that wants to be returned
     #[inline]
     pub fn col(&self, index: usize) -> Option<Vec<N>> {
-        if self.ncols == 0 {
+        if index >= self.ncols || self.values.is_empty() {
             return None;
         }
 
-        let mut ret: Vec<N> = Vec::new();
-        for (_, item) in self.values.iter().enumerate() {
-            ret.push(item[index]);
+        Some(self.values.iter().skip(index).step_by(self.ncols).cloned().collect())
+    }
+
+    /// Modifies a column with given index and value
+    ///
+    /// # Arguments
+    ///
+    /// * `index`: index of the column that must be modified
+    /// * `new_col`: new column value
+    ///
+    /// # Remarks
+    ///
+    /// * Panics if the index or the column's size are not valid (see `try_set_col`)
+    #[inline]
+    pub fn set_col(&mut self, index: usize, new_col: &[N]) {
+        if let Err(e) = self.try_set_col(index, new_col) {
+            panic!("{}", e);
         }
-        Some(ret)
     }
 
     /// Modifies a column with given index and value
@@ -233,18 +377,25 @@ impl<N: Copy> Matrix<N> { // implementation of Matrix<N>
     ///
     /// * `index`: index of the column that must be modified
     /// * `new_col`: new column value
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::IndexOutOfBounds` if the column doesn't exist
+    /// * Returns `Error::DimensionMismatch` if the column's size is not the number of rows
     #[inline]
-    pub fn set_col(&mut self, index: usize, new_col: &Vec<N>) {
+    pub fn try_set_col(&mut self, index: usize, new_col: &[N]) -> Result<(), Error> {
         if index >= self.ncols() {
-            panic!("index out of range");
+            return Err(Error::IndexOutOfBounds { index, size: self.ncols });
         }
         if new_col.len() != self.nrows() {
-            panic!("input vector dimension mismatch");
+            return Err(Error::DimensionMismatch { expected: (self.nrows, 1), found: (new_col.len(), 1) });
         }
 
-        for i in 0..self.nrows() {
-            self.values[i][index] = new_col[i];
+        let ncols = self.ncols;
+        for (el, val) in self.values.iter_mut().skip(index).step_by(ncols).zip(new_col.iter()) {
+            *el = *val;
         }
+        Ok(())
     }
 
     /// Returns an element by coordinates
@@ -255,7 +406,7 @@ impl<N: Copy> Matrix<N> { // implementation of Matrix<N>
     /// * `i_col`: column's index
     #[inline]
     pub fn get_element(&self, i_row: usize, i_col: usize) -> N {
-        self.values[i_row][i_col]
+        self[(i_row, i_col)]
     }
 
     /// Modifies an element of the matrix
@@ -267,7 +418,7 @@ impl<N: Copy> Matrix<N> { // implementation of Matrix<N>
     /// * `val`: new element value
     #[inline]
     pub fn set_element(&mut self, i_row: usize, i_col: usize, val: &N) {
-        self.values[i_row][i_col] = *val;
+        self[(i_row, i_col)] = *val;
     }
 
     /// Returns the index of an element if it's present on the matrix
@@ -300,10 +451,10 @@ impl<N: Copy> Matrix<N> { // implementation of Matrix<N>
     ///
     /// If the value is found then `Some(usize)` is returned, if not `None`
     #[inline]
-    pub fn contains_row(&self, row: &Vec<N>) -> Option<usize>
-        where Vec<N>: PartialEq {
+    pub fn contains_row(&self, row: &[N]) -> Option<usize>
+        where N: PartialEq {
 
-        self.values.iter().position(|item| item == row)
+        self.row_iter().position(|item| item == row)
     }
 
     /// Returns the index of a column if it's present on the matrix
@@ -316,25 +467,30 @@ impl<N: Copy> Matrix<N> { // implementation of Matrix<N>
     ///
     /// If the value is found then `Some(usize)` is returned, if not `None`
     #[inline]
-    pub fn contains_col(&self, column: &Vec<N>) -> Option<usize>
-        where Vec<N>: PartialEq {
+    pub fn contains_col(&self, column: &[N]) -> Option<usize>
+        where N: PartialEq {
 
         for (i, icol) in self.col_iter().enumerate() {
-            if icol == *column {
+            if icol.as_slice() == column {
                 return Some(i);
             }
         }
         None
     }
 
-    // internal use
-    fn update_sizes(&mut self) { // helping function to update the size if matrix is modified
-        self.nrows = self.values.len();
-
-        if self.nrows > 0 {
-            self.ncols = self.values[0].len();
-        } else { // if number of columns is 0, the number of rows must be also 0
-            self.ncols = 0;
+    /// Appends a row to the end of the matrix
+    ///
+    /// # Arguments
+    ///
+    /// * `row`: row to push
+    ///
+    /// # Remarks
+    ///
+    /// * Panics if the row's size is not the number of columns (see `try_push_row`)
+    #[inline]
+    pub fn push_row(&mut self, row: Vec<N>) {
+        if let Err(e) = self.try_push_row(row) {
+            panic!("{}", e);
         }
     }
 
@@ -343,10 +499,22 @@ impl<N: Copy> Matrix<N> { // implementation of Matrix<N>
     /// # Arguments
     ///
     /// * `row`: row to push
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::DimensionMismatch` if the row's size is not the number of columns
     #[inline]
-    pub fn push_row(&mut self, row: Vec<N>) {
-        self.values.push(row);
-        self.update_sizes();
+    pub fn try_push_row(&mut self, row: Vec<N>) -> Result<(), Error> {
+        if self.values.is_empty() { // if self is empty -> the row defines the columns
+            self.ncols = row.len();
+            self.nrows = 0;
+        } else if self.ncols != row.len() {
+            return Err(Error::DimensionMismatch { expected: (1, self.ncols), found: (1, row.len()) });
+        }
+
+        self.values.extend_from_slice(&row);
+        self.nrows += 1;
+        Ok(())
     }
 
 
@@ -355,23 +523,46 @@ impl<N: Copy> Matrix<N> { // implementation of Matrix<N>
     /// # Arguments
     ///
     /// * `col`: column to push
+    ///
+    /// # Remarks
+    ///
+    /// * Panics if the column's size is not the number of rows (see `try_push_col`)
     #[inline]
     pub fn push_col(&mut self, col: Vec<N>) {
-        if self.ncols > 0 && self.nrows > 0 {
-            if self.nrows != col.len() {
-                panic!("invalid size for a row. ncols = {}, row.len() = {}", self.ncols, col.len())
-            }
+        if let Err(e) = self.try_push_col(col) {
+            panic!("{}", e);
+        }
+    }
 
-            for (i, item) in col.iter().enumerate() {
-                self.values[i].push(*item);
-            }
-        } else { // if self is empty -> push the first row
-            for (i, item) in col.iter().enumerate() {
-                self.values.push(Vec::new()); // initializye column vector
-                self.values[i].push(*item); // push element
-            }
+    /// Appends a column to the end of the matrix
+    ///
+    /// # Arguments
+    ///
+    /// * `col`: column to push
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::DimensionMismatch` if the column's size is not the number of rows
+    #[inline]
+    pub fn try_push_col(&mut self, col: Vec<N>) -> Result<(), Error> {
+        if self.values.is_empty() { // if self is empty -> push the first column
+            self.nrows = col.len();
+            self.ncols = 1;
+            self.values = col;
+            return Ok(());
+        }
+        if self.nrows != col.len() {
+            return Err(Error::DimensionMismatch { expected: (self.nrows, 1), found: (col.len(), 1) });
+        }
+
+        let mut values: Vec<N> = Vec::with_capacity(self.nrows * (self.ncols + 1));
+        for (row, item) in self.values.chunks(self.ncols).zip(col.iter()) {
+            values.extend_from_slice(row);
+            values.push(*item);
         }
-        self.update_sizes();
+        self.values = values;
+        self.ncols += 1;
+        Ok(())
     }
 
     /// Removes a row of the matrix by its index
@@ -379,20 +570,37 @@ impl<N: Copy> Matrix<N> { // implementation of Matrix<N>
     /// # Arguments
     ///
     /// `index`: index of the row that has to be removed
+    ///
+    /// # Remarks
+    ///
+    /// * Panics if the row doesn't exist (see `try_pop_row`)
     #[inline]
     pub fn pop_row(&mut self, index: usize) {
-        if index >= self.nrows {
-            panic!("invalid index({}). nrows = '{}'", index, self.nrows)
+        if let Err(e) = self.try_pop_row(index) {
+            panic!("{}", e);
         }
+    }
 
-        let mut new_values: Vec<Vec<N>> = Vec::new();
-        for (i, item) in self.values.iter().enumerate() {
-            if i != index {
-                new_values.push(item.clone());
-            }
+    /// Removes a row of the matrix by its index
+    ///
+    /// # Arguments
+    ///
+    /// `index`: index of the row that has to be removed
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::IndexOutOfBounds` if the row doesn't exist
+    #[inline]
+    pub fn try_pop_row(&mut self, index: usize) -> Result<(), Error> {
+        if index >= self.nrows {
+            return Err(Error::IndexOutOfBounds { index, size: self.nrows });
         }
-        self.values = new_values;
-        self.update_sizes();
+
+        let start = self.offset(index, 0);
+        self.values.drain(start..start + self.ncols);
+        self.nrows -= 1;
+        self.reset_if_empty();
+        Ok(())
     }
 
     /// Removes a column of the matrix by its index
@@ -400,16 +608,50 @@ impl<N: Copy> Matrix<N> { // implementation of Matrix<N>
     /// # Arguments
     ///
     /// `index`: index of the column that has to be removed
+    ///
+    /// # Remarks
+    ///
+    /// * Panics if the column doesn't exist (see `try_pop_col`)
     #[inline]
     pub fn pop_col(&mut self, index: usize) {
+        if let Err(e) = self.try_pop_col(index) {
+            panic!("{}", e);
+        }
+    }
+
+    /// Removes a column of the matrix by its index
+    ///
+    /// # Arguments
+    ///
+    /// `index`: index of the column that has to be removed
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::IndexOutOfBounds` if the column doesn't exist
+    #[inline]
+    pub fn try_pop_col(&mut self, index: usize) -> Result<(), Error> {
         if index >= self.ncols {
-            panic!("invalid index({}). ncols = '{}'", index, self.nrows)
+            return Err(Error::IndexOutOfBounds { index, size: self.ncols });
         }
 
-        for item in self.values.iter_mut() {
-            item.remove(index);
+        let ncols = self.ncols;
+        let mut j: usize = 0;
+        self.values.retain(|_| {
+            let keep = j % ncols != index;
+            j += 1;
+            keep
+        });
+        self.ncols -= 1;
+        self.reset_if_empty();
+        Ok(())
+    }
+
+    // internal use: a matrix without elements has no rows and no columns
+    fn reset_if_empty(&mut self) {
+        if self.values.is_empty() {
+            self.nrows = 0;
+            self.ncols = 0;
         }
-        self.update_sizes();
     }
 
     /// Swaps the position of two rows in the matrix
@@ -418,13 +660,40 @@ impl<N: Copy> Matrix<N> { // implementation of Matrix<N>
     ///
     /// * `index_a`: index of the first row that has to be swapped
     /// * `index_b`: index of the second row that has to be swapped
+    ///
+    /// # Remarks
+    ///
+    /// * Panics if one of the rows doesn't exist (see `try_swap_row`)
     #[inline]
     pub fn swap_row(&mut self, index_a: usize, index_b: usize) {
-        if (index_a >= self.nrows) || (index_b >= self.nrows) {
-            panic!("invalid indexes(index_a = {}, index_b = {}). nrows = {}",
-                                    index_a, index_b, self.nrows);
+        if let Err(e) = self.try_swap_row(index_a, index_b) {
+            panic!("{}", e);
+        }
+    }
+
+    /// Swaps the position of two rows in the matrix
+    ///
+    /// # Arguments
+    ///
+    /// * `index_a`: index of the first row that has to be swapped
+    /// * `index_b`: index of the second row that has to be swapped
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::IndexOutOfBounds` if one of the rows doesn't exist
+    #[inline]
+    pub fn try_swap_row(&mut self, index_a: usize, index_b: usize) -> Result<(), Error> {
+        let index = index_a.max(index_b);
+        if index >= self.nrows {
+            return Err(Error::IndexOutOfBounds { index, size: self.nrows });
         }
-        self.values.swap(index_a, index_b);
+        if index_a != index_b {
+            let (low, high) = if index_a < index_b { (index_a, index_b) } else { (index_b, index_a) };
+            let ncols = self.ncols;
+            let (head, tail) = self.values.split_at_mut(high * ncols);
+            head[low * ncols..(low + 1) * ncols].swap_with_slice(&mut tail[..ncols]);
+        }
+        Ok(())
     }
 
 
@@ -434,134 +703,400 @@ impl<N: Copy> Matrix<N> { // implementation of Matrix<N>
     ///
     /// * `index_a`: index of the first row that has to be swapped
     /// * `index_b`: index of the second row that has to be swapped
+    ///
+    /// # Remarks
+    ///
+    /// * Panics if one of the columns doesn't exist (see `try_swap_col`)
     #[inline]
     pub fn swap_col(&mut self, index_a: usize, index_b: usize) {
-        if (index_a >= self.ncols) || (index_b >= self.ncols) {
-            panic!("invalid indexes(index_a = {}, index_b = {}). ncols = {}",
-                                    index_a, index_b, self.ncols);
+        if let Err(e) = self.try_swap_col(index_a, index_b) {
+            panic!("{}", e);
+        }
+    }
+
+    /// Swaps the position of two columns in the matrix
+    ///
+    /// # Arguments
+    ///
+    /// * `index_a`: index of the first column that has to be swapped
+    /// * `index_b`: index of the second column that has to be swapped
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::IndexOutOfBounds` if one of the columns doesn't exist
+    #[inline]
+    pub fn try_swap_col(&mut self, index_a: usize, index_b: usize) -> Result<(), Error> {
+        let index = index_a.max(index_b);
+        if index >= self.ncols {
+            return Err(Error::IndexOutOfBounds { index, size: self.ncols });
         }
-        for item in self.values.iter_mut() {
-            item.swap(index_a, index_b);
+        for row in self.values.chunks_mut(self.ncols) {
+            row.swap(index_a, index_b);
         }
+        Ok(())
+    }
+
+    // internal use: checks that two matrices have the same size
+    pub(crate) fn check_same_size<M: Copy>(&self, other: &Matrix<M>) -> Result<(), Error> {
+        if (self.nrows != other.nrows) || (self.ncols != other.ncols) {
+            return Err(Error::DimensionMismatch { expected: (self.nrows, self.ncols),
+                                                  found: (other.nrows, other.ncols) });
+        }
+        Ok(())
     }
 
     /// Clears a Matrix
     #[inline]
     pub fn clear(&mut self) {
         self.values.clear();
-        self.update_sizes();
+        self.reset_if_empty();
     }
 
-    /// Returns a matrix showing at each coordinate if a member was
-    /// equal to a give value
+    /// Returns a matrix showing at each coordinate if the element is
+    /// equal to a value
     ///
     /// # Arguments
     ///
     /// * `value`: value to compare
     #[inline]
-    pub fn equal_to(&self, value: &N) -> Matrix<bool>
-        where N: Num + PartialEq<N> {
-
-        let mut m = Matrix::<bool>::new();
-        for col in self.row_iter() {
-            let mut row_out: Vec<bool> = Vec::new();
-
-            for el in col.iter() {
-                if el == value { row_out.push(true); }
-                else { row_out.push(false); }
-            }
-
-            m.push_row(row_out);
-        }
-        m
+    pub fn equal_to(&self, value: &N) -> Matrix<bool> where N: PartialEq {
+        self.map(|el| el == *value)
     }
 
-    /// Returns a matrix showing if two matrix were equal
+    /// Returns a matrix showing at each coordinate if the element is
+    /// equal to the element of another matrix
     ///
     /// # Arguments
     ///
     /// * `comp_matrix`: matrix to compare
+    ///
+    /// # Remarks
+    ///
+    /// * Panics if the sizes are not equal (see `try_equal_to_matrix`)
     #[inline]
-    pub fn equal_to_matrix(&self, comp_matrix: &Matrix<N>) -> Matrix<bool>
-        where N: Num + PartialEq<N> {
-
-        if (self.ncols != comp_matrix.ncols()) || (self.nrows != comp_matrix.nrows()) {
-            panic!("sizes not equal!");
+    pub fn equal_to_matrix(&self, comp_matrix: &Matrix<N>) -> Matrix<bool> where N: PartialEq {
+        match self.try_equal_to_matrix(comp_matrix) {
+            Ok(m) => m,
+            Err(e) => panic!("{}", e),
         }
+    }
 
-        let mut m = Matrix::<bool>::new();
-        for (i, row) in self.values.iter().enumerate() {
-            let mut row_out: Vec<bool> = Vec::new();
-
-            for (j, el) in row.iter().enumerate() {
-                if *el == comp_matrix.values[i][j] {
-                    row_out.push(true);
-                } else {
-                    row_out.push(false);
-                }
-            }
-
-            m.push_row(row_out);
-        }
-        m
+    /// Returns a matrix showing at each coordinate if the element is
+    /// equal to the element of another matrix
+    ///
+    /// # Arguments
+    ///
+    /// * `comp_matrix`: matrix to compare
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::DimensionMismatch` if the sizes are not equal
+    #[inline]
+    pub fn try_equal_to_matrix(&self, comp_matrix: &Matrix<N>) -> Result<Matrix<bool>, Error> where N: PartialEq {
+        self.try_zip_map(comp_matrix, |a, b| a == b)
     }
 
-    /// Returns a matrix showing at each coordinate if a member was
-    /// bigger than the value given
+    /// Returns a matrix showing at each coordinate if the element is
+    /// different from a value
     ///
     /// # Arguments
     ///
     /// * `value`: value to compare
     #[inline]
-    pub fn bigger_than(&self, value: &N) -> Matrix<bool>
-        where N: Num + PartialOrd<N> {
-
-        let mut m = Matrix::<bool>::new();
-        for row in self.row_iter() {
-            let mut row_out: Vec<bool> = Vec::new();
-
-            for el in row.iter() {
-                if el > value {
-                    row_out.push(true);
-                } else {
-                    row_out.push(false);
-                }
-            }
-
-            m.push_row(row_out);
-        }
-        m
+    pub fn not_equal(&self, value: &N) -> Matrix<bool> where N: PartialEq {
+        self.map(|el| el != *value)
     }
 
-    /// Returns a matrix comparing two matrixes and show which have bigger values
-    /// in an specific coordinate
+    /// Returns a matrix showing at each coordinate if the element is
+    /// different from the element of another matrix
     ///
     /// # Arguments
     ///
     /// * `comp_matrix`: matrix to compare
+    ///
+    /// # Remarks
+    ///
+    /// * Panics if the sizes are not equal (see `try_not_equal_matrix`)
     #[inline]
-    pub fn bigger_than_matrix(&self, comp_matrix: &Matrix<N>) -> Matrix<bool>
-        where N: Num + PartialOrd<N> {
-
-        if (self.ncols != comp_matrix.ncols()) || (self.nrows != comp_matrix.nrows()) {
-            panic!("sizes not equal!");
+    pub fn not_equal_matrix(&self, comp_matrix: &Matrix<N>) -> Matrix<bool> where N: PartialEq {
+        match self.try_not_equal_matrix(comp_matrix) {
+            Ok(m) => m,
+            Err(e) => panic!("{}", e),
         }
+    }
 
-        let mut m = Matrix::<bool>::new();
-        for (i, row) in self.row_iter().enumerate() {
-            let mut row_out: Vec<bool> = Vec::new();
+    /// Returns a matrix showing at each coordinate if the element is
+    /// different from the element of another matrix
+    ///
+    /// # Arguments
+    ///
+    /// * `comp_matrix`: matrix to compare
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::DimensionMismatch` if the sizes are not equal
+    #[inline]
+    pub fn try_not_equal_matrix(&self, comp_matrix: &Matrix<N>) -> Result<Matrix<bool>, Error> where N: PartialEq {
+        self.try_zip_map(comp_matrix, |a, b| a != b)
+    }
 
-            for (j, el) in row.iter().enumerate() {
-                if *el > comp_matrix.values[i][j] {
-                    row_out.push(true);
-                } else {
-                    row_out.push(false);
-                }
-            }
+    /// Returns a matrix showing at each coordinate if the element is
+    /// less than a value
+    ///
+    /// # Arguments
+    ///
+    /// * `value`: value to compare
+    #[inline]
+    pub fn less_than(&self, value: &N) -> Matrix<bool> where N: PartialOrd {
+        self.map(|el| el < *value)
+    }
 
-            m.push_row(row_out);
+    /// Returns a matrix showing at each coordinate if the element is
+    /// less than the element of another matrix
+    ///
+    /// # Arguments
+    ///
+    /// * `comp_matrix`: matrix to compare
+    ///
+    /// # Remarks
+    ///
+    /// * Panics if the sizes are not equal (see `try_less_than_matrix`)
+    #[inline]
+    pub fn less_than_matrix(&self, comp_matrix: &Matrix<N>) -> Matrix<bool> where N: PartialOrd {
+        match self.try_less_than_matrix(comp_matrix) {
+            Ok(m) => m,
+            Err(e) => panic!("{}", e),
         }
-        m
+    }
+
+    /// Returns a matrix showing at each coordinate if the element is
+    /// less than the element of another matrix
+    ///
+    /// # Arguments
+    ///
+    /// * `comp_matrix`: matrix to compare
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::DimensionMismatch` if the sizes are not equal
+    #[inline]
+    pub fn try_less_than_matrix(&self, comp_matrix: &Matrix<N>) -> Result<Matrix<bool>, Error> where N: PartialOrd {
+        self.try_zip_map(comp_matrix, |a, b| a < b)
+    }
+
+    /// Returns a matrix showing at each coordinate if the element is
+    /// less than or equal to a value
+    ///
+    /// # Arguments
+    ///
+    /// * `value`: value to compare
+    #[inline]
+    pub fn less_equal(&self, value: &N) -> Matrix<bool> where N: PartialOrd {
+        self.map(|el| el <= *value)
+    }
+
+    /// Returns a matrix showing at each coordinate if the element is
+    /// less than or equal to the element of another matrix
+    ///
+    /// # Arguments
+    ///
+    /// * `comp_matrix`: matrix to compare
+    ///
+    /// # Remarks
+    ///
+    /// * Panics if the sizes are not equal (see `try_less_equal_matrix`)
+    #[inline]
+    pub fn less_equal_matrix(&self, comp_matrix: &Matrix<N>) -> Matrix<bool> where N: PartialOrd {
+        match self.try_less_equal_matrix(comp_matrix) {
+            Ok(m) => m,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Returns a matrix showing at each coordinate if the element is
+    /// less than or equal to the element of another matrix
+    ///
+    /// # Arguments
+    ///
+    /// * `comp_matrix`: matrix to compare
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::DimensionMismatch` if the sizes are not equal
+    #[inline]
+    pub fn try_less_equal_matrix(&self, comp_matrix: &Matrix<N>) -> Result<Matrix<bool>, Error> where N: PartialOrd {
+        self.try_zip_map(comp_matrix, |a, b| a <= b)
+    }
+
+    /// Returns a matrix showing at each coordinate if the element is
+    /// bigger than a value
+    ///
+    /// # Arguments
+    ///
+    /// * `value`: value to compare
+    #[inline]
+    pub fn bigger_than(&self, value: &N) -> Matrix<bool> where N: PartialOrd {
+        self.map(|el| el > *value)
+    }
+
+    /// Returns a matrix showing at each coordinate if the element is
+    /// bigger than the element of another matrix
+    ///
+    /// # Arguments
+    ///
+    /// * `comp_matrix`: matrix to compare
+    ///
+    /// # Remarks
+    ///
+    /// * Panics if the sizes are not equal (see `try_bigger_than_matrix`)
+    #[inline]
+    pub fn bigger_than_matrix(&self, comp_matrix: &Matrix<N>) -> Matrix<bool> where N: PartialOrd {
+        match self.try_bigger_than_matrix(comp_matrix) {
+            Ok(m) => m,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Returns a matrix showing at each coordinate if the element is
+    /// bigger than the element of another matrix
+    ///
+    /// # Arguments
+    ///
+    /// * `comp_matrix`: matrix to compare
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::DimensionMismatch` if the sizes are not equal
+    #[inline]
+    pub fn try_bigger_than_matrix(&self, comp_matrix: &Matrix<N>) -> Result<Matrix<bool>, Error> where N: PartialOrd {
+        self.try_zip_map(comp_matrix, |a, b| a > b)
+    }
+
+    /// Returns a matrix showing at each coordinate if the element is
+    /// bigger than or equal to a value
+    ///
+    /// # Arguments
+    ///
+    /// * `value`: value to compare
+    #[inline]
+    pub fn bigger_equal(&self, value: &N) -> Matrix<bool> where N: PartialOrd {
+        self.map(|el| el >= *value)
+    }
+
+    /// Returns a matrix showing at each coordinate if the element is
+    /// bigger than or equal to the element of another matrix
+    ///
+    /// # Arguments
+    ///
+    /// * `comp_matrix`: matrix to compare
+    ///
+    /// # Remarks
+    ///
+    /// * Panics if the sizes are not equal (see `try_bigger_equal_matrix`)
+    #[inline]
+    pub fn bigger_equal_matrix(&self, comp_matrix: &Matrix<N>) -> Matrix<bool> where N: PartialOrd {
+        match self.try_bigger_equal_matrix(comp_matrix) {
+            Ok(m) => m,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Returns a matrix showing at each coordinate if the element is
+    /// bigger than or equal to the element of another matrix
+    ///
+    /// # Arguments
+    ///
+    /// * `comp_matrix`: matrix to compare
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::DimensionMismatch` if the sizes are not equal
+    #[inline]
+    pub fn try_bigger_equal_matrix(&self, comp_matrix: &Matrix<N>) -> Result<Matrix<bool>, Error> where N: PartialOrd {
+        self.try_zip_map(comp_matrix, |a, b| a >= b)
+    }
+
+    /// Returns the addition of two matrices
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: matrix to add
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::DimensionMismatch` if the sizes are not equal
+    #[inline]
+    pub fn try_add(&self, other: &Matrix<N>) -> Result<Matrix<N>, Error> where N: Num {
+        self.check_same_size(other)?;
+
+        let values: Vec<N> = self.values.iter()
+                                        .zip(other.values.iter())
+                                        .map(|(a, b)| *a + *b)
+                                        .collect();
+        Ok(Matrix::<N>::from_vec(self.nrows, self.ncols, values))
+    }
+
+    /// Returns the substraction of two matrices
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: matrix to substract
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::DimensionMismatch` if the sizes are not equal
+    #[inline]
+    pub fn try_sub(&self, other: &Matrix<N>) -> Result<Matrix<N>, Error> where N: Num {
+        self.check_same_size(other)?;
+
+        let values: Vec<N> = self.values.iter()
+                                        .zip(other.values.iter())
+                                        .map(|(a, b)| *a - *b)
+                                        .collect();
+        Ok(Matrix::<N>::from_vec(self.nrows, self.ncols, values))
+    }
+
+    /// Returns the product of two matrices
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: right-hand side of the product
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::DimensionMismatch` if `other` hasn't as many rows as
+    ///   columns in the matrix
+    #[inline]
+    pub fn try_mul(&self, other: &Matrix<N>) -> Result<Matrix<N>, Error> where N: Num + Default + ParallelElement {
+        if self.ncols != other.nrows {
+            return Err(Error::DimensionMismatch { expected: (self.ncols, other.ncols),
+                                                  found: (other.nrows, other.ncols) });
+        }
+
+        let mut values: Vec<N> = vec![N::default(); self.nrows * other.ncols];
+        gemm::gemm(N::one(), &self.values, &other.values, N::zero(), &mut values, self.ncols, other.ncols);
+        Ok(Matrix::<N>::from_vec(self.nrows, other.ncols, values))
+    }
+
+    /// Returns the product of the matrix with a column vector
+    ///
+    /// # Arguments
+    ///
+    /// * `vector`: right-hand side of the product
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::DimensionMismatch` if the vector hasn't as many
+    ///   elements as columns in the matrix
+    #[inline]
+    pub fn try_mul_vector(&self, vector: &Vector<N>) -> Result<Vector<N>, Error> where N: Num + ParallelElement {
+        if self.ncols != vector.size() {
+            return Err(Error::DimensionMismatch { expected: (self.ncols, 1), found: (vector.size(), 1) });
+        }
+
+        let mut values: Vec<N> = vec![N::zero(); self.nrows];
+        gemm::gemm(N::one(), &self.values, vector.as_slice(), N::zero(), &mut values, self.ncols, 1);
+        Ok(Vector::<N>::from_vec(values))
     }
 
     /// Returns the product of an scalar multiplication with the current matrix
@@ -571,54 +1106,287 @@ impl<N: Copy> Matrix<N> { // implementation of Matrix<N>
     /// * `scalar`: number to multiply
     #[inline]
     pub fn scalar_mul(&self, scalar: N) -> Matrix<N> where N: Num {
-        let mut m: Matrix<N> = Matrix::<N>::new();
+        let values: Vec<N> = self.values.iter().map(|el| scalar * (*el)).collect();
+        Matrix::<N>::from_vec(self.nrows, self.ncols, values)
+    }
+
+    /// Returns the element-wise (Hadamard) product of two matrices
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: matrix to multiply element by element
+    ///
+    /// # Remarks
+    ///
+    /// * Panics if the sizes are not equal (see `try_hadamard`)
+    #[inline]
+    pub fn hadamard(&self, other: &Matrix<N>) -> Matrix<N> where N: Num {
+        match self.try_hadamard(other) {
+            Ok(m) => m,
+            Err(e) => panic!("{}", e),
+        }
+    }
 
-        for col in self.row_iter() {
-            let mut new_row: Vec<N> = Vec::new();
+    /// Returns the element-wise (Hadamard) product of two matrices
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: matrix to multiply element by element
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::DimensionMismatch` if the sizes are not equal
+    #[inline]
+    pub fn try_hadamard(&self, other: &Matrix<N>) -> Result<Matrix<N>, Error> where N: Num {
+        self.check_same_size(other)?;
+
+        let values: Vec<N> = self.values.iter()
+                                        .zip(other.values.iter())
+                                        .map(|(a, b)| *a * *b)
+                                        .collect();
+        Ok(Matrix::<N>::from_vec(self.nrows, self.ncols, values))
+    }
 
-            for el in col.iter() {
-                new_row.push(scalar * (*el));
-            }
+    /// Returns the element-wise division of two matrices
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: matrix with the divisors
+    ///
+    /// # Remarks
+    ///
+    /// * Panics if the sizes are not equal (see `try_hadamard_div`)
+    #[inline]
+    pub fn hadamard_div(&self, other: &Matrix<N>) -> Matrix<N> where N: Num {
+        match self.try_hadamard_div(other) {
+            Ok(m) => m,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Returns the element-wise division of two matrices
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: matrix with the divisors
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::DimensionMismatch` if the sizes are not equal
+    /// * Integer matrices panic if a divisor is zero, like the `/` operator
+    #[inline]
+    pub fn try_hadamard_div(&self, other: &Matrix<N>) -> Result<Matrix<N>, Error> where N: Num {
+        self.check_same_size(other)?;
+
+        let values: Vec<N> = self.values.iter()
+                                        .zip(other.values.iter())
+                                        .map(|(a, b)| *a / *b)
+                                        .collect();
+        Ok(Matrix::<N>::from_vec(self.nrows, self.ncols, values))
+    }
+
+    /// Adds a vector to every row or column of the matrix
+    ///
+    /// # Arguments
+    ///
+    /// * `vector`: row or column to add
+    /// * `axis`: orientation of the vector (see `Axis`)
+    ///
+    /// # Remarks
+    ///
+    /// * Panics if the vector doesn't fit the matrix (see `try_broadcast_add`)
+    #[inline]
+    pub fn broadcast_add(&self, vector: &Vector<N>, axis: Axis) -> Matrix<N> where N: Num {
+        match self.try_broadcast_add(vector, axis) {
+            Ok(m) => m,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Adds a vector to every row or column of the matrix
+    ///
+    /// # Arguments
+    ///
+    /// * `vector`: row or column to add
+    /// * `axis`: orientation of the vector (see `Axis`)
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::DimensionMismatch` if a row vector hasn't as many
+    ///   elements as columns, or a column vector as many elements as rows
+    #[inline]
+    pub fn try_broadcast_add(&self, vector: &Vector<N>, axis: Axis) -> Result<Matrix<N>, Error> where N: Num {
+        self.broadcast_with(vector, axis, |a, b| a + b)
+    }
+
+    /// Substracts a vector from every row or column of the matrix
+    ///
+    /// # Arguments
+    ///
+    /// * `vector`: row or column to substract
+    /// * `axis`: orientation of the vector (see `Axis`)
+    ///
+    /// # Remarks
+    ///
+    /// * Panics if the vector doesn't fit the matrix (see `try_broadcast_sub`)
+    #[inline]
+    pub fn broadcast_sub(&self, vector: &Vector<N>, axis: Axis) -> Matrix<N> where N: Num {
+        match self.try_broadcast_sub(vector, axis) {
+            Ok(m) => m,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Substracts a vector from every row or column of the matrix
+    ///
+    /// # Arguments
+    ///
+    /// * `vector`: row or column to substract
+    /// * `axis`: orientation of the vector (see `Axis`)
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::DimensionMismatch` if a row vector hasn't as many
+    ///   elements as columns, or a column vector as many elements as rows
+    #[inline]
+    pub fn try_broadcast_sub(&self, vector: &Vector<N>, axis: Axis) -> Result<Matrix<N>, Error> where N: Num {
+        self.broadcast_with(vector, axis, |a, b| a - b)
+    }
+
+    /// Multiplies every row or column of the matrix by a vector, element by element
+    ///
+    /// # Arguments
+    ///
+    /// * `vector`: row or column with the factors
+    /// * `axis`: orientation of the vector (see `Axis`)
+    ///
+    /// # Remarks
+    ///
+    /// * Panics if the vector doesn't fit the matrix (see `try_broadcast_mul`)
+    #[inline]
+    pub fn broadcast_mul(&self, vector: &Vector<N>, axis: Axis) -> Matrix<N> where N: Num {
+        match self.try_broadcast_mul(vector, axis) {
+            Ok(m) => m,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Multiplies every row or column of the matrix by a vector, element by element
+    ///
+    /// # Arguments
+    ///
+    /// * `vector`: row or column with the factors
+    /// * `axis`: orientation of the vector (see `Axis`)
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::DimensionMismatch` if a row vector hasn't as many
+    ///   elements as columns, or a column vector as many elements as rows
+    #[inline]
+    pub fn try_broadcast_mul(&self, vector: &Vector<N>, axis: Axis) -> Result<Matrix<N>, Error> where N: Num {
+        self.broadcast_with(vector, axis, |a, b| a * b)
+    }
+
+    /// Divides every row or column of the matrix by a vector, element by element
+    ///
+    /// # Arguments
+    ///
+    /// * `vector`: row or column with the divisors
+    /// * `axis`: orientation of the vector (see `Axis`)
+    ///
+    /// # Remarks
+    ///
+    /// * Panics if the vector doesn't fit the matrix (see `try_broadcast_div`)
+    #[inline]
+    pub fn broadcast_div(&self, vector: &Vector<N>, axis: Axis) -> Matrix<N> where N: Num {
+        match self.try_broadcast_div(vector, axis) {
+            Ok(m) => m,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Divides every row or column of the matrix by a vector, element by element
+    ///
+    /// # Arguments
+    ///
+    /// * `vector`: row or column with the divisors
+    /// * `axis`: orientation of the vector (see `Axis`)
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::DimensionMismatch` if a row vector hasn't as many
+    ///   elements as columns, or a column vector as many elements as rows
+    #[inline]
+    pub fn try_broadcast_div(&self, vector: &Vector<N>, axis: Axis) -> Result<Matrix<N>, Error> where N: Num {
+        self.broadcast_with(vector, axis, |a, b| a / b)
+    }
 
-            m.values.push(new_row);
-        }
-        m.update_sizes();
-        m
+    // internal use: applies `f(element, vector element)` repeating the vector
+    // over the rows or the columns of the matrix
+    fn broadcast_with<F>(&self, vector: &Vector<N>, axis: Axis, f: F) -> Result<Matrix<N>, Error>
+        where F: Fn(N, N) -> N {
+        let vals = vector.as_slice();
+        let values: Vec<N> = match axis {
+            Axis::Row => {
+                if vals.len() != self.ncols {
+                    return Err(Error::DimensionMismatch { expected: (1, self.ncols), found: (1, vals.len()) });
+                }
+                self.values.iter().enumerate().map(|(i, a)| f(*a, vals[i % self.ncols])).collect()
+            },
+            Axis::Col => {
+                if vals.len() != self.nrows {
+                    return Err(Error::DimensionMismatch { expected: (self.nrows, 1), found: (vals.len(), 1) });
+                }
+                self.values.iter().enumerate().map(|(i, a)| f(*a, vals[i / self.ncols])).collect()
+            },
+        };
+        Ok(Matrix::<N>::from_vec(self.nrows, self.ncols, values))
     }
 
     /// Transposes a Matrix
     #[inline]
     pub fn transpose(&mut self) {
-        let mut res: Matrix<N> = Matrix::<N>::new();
-        for item in self.values.iter() {
-            res.push_col(item.clone());
+        let mut values: Vec<N> = Vec::with_capacity(self.values.len());
+        for j in 0..self.ncols {
+            values.extend(self.values.iter().skip(j).step_by(self.ncols).cloned());
         }
 
-        res.update_sizes();
+        let res: Matrix<N> = Matrix::<N>::from_vec(self.ncols, self.nrows, values);
         self.copy(res);
     }
 
     /// Returns the diagonal of a Matrix
+    ///
+    /// # Remarks
+    ///
+    /// * Panics if the matrix is not square (see `try_get_diagonal`)
     #[inline]
     pub fn get_diagonal(&self) -> Matrix<N> where N: Num + Default {
+        match self.try_get_diagonal() {
+            Ok(m) => m,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Returns the diagonal of a Matrix
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::NotSquare` if the matrix is not square
+    #[inline]
+    pub fn try_get_diagonal(&self) -> Result<Matrix<N>, Error> where N: Num + Default {
 
         if self.ncols != self.nrows {
-            panic!("get_diagonal just available for square matrices");
+            return Err(Error::NotSquare { nrows: self.nrows, ncols: self.ncols });
         }
 
-        let mut m: Matrix<N> = Matrix::<N>::init_with_capacity(self.ncols, self.nrows);
-        for i in 0..m.nrows {
-            let mut row: Vec<N> = Vec::new();
-            for j in 0..m.ncols {
-                if i != j {
-                    row.push(N::default());
-                } else {
-                    row.push(self.values[i][j]);
-                }
-            }
-            m.values.push(row);
+        let size = self.nrows;
+        let mut m: Matrix<N> = Matrix::<N>::from_vec(size, size, vec![N::default(); size * size]);
+        for i in 0..size {
+            let offset = self.offset(i, i);
+            m.values[offset] = self.values[offset];
         }
-        m
+        Ok(m)
     }
 
     /// Returns a submatrix of a matrix
@@ -627,25 +1395,84 @@ impl<N: Copy> Matrix<N> { // implementation of Matrix<N>
     ///
     /// * `range_row`: row's range of the submatrix
     /// * `range_col`: column's range of the submatrix
+    ///
+    /// # Remarks
+    ///
+    /// * Panics if the ranges are not valid (see `try_submatrix`)
+    /// * Deprecated: `m.slice(1..3, ..).to_matrix()` copies the same elements
+    ///   without the inclusive `[first, last]` arrays
     #[inline]
+    #[deprecated(since = "0.2.0", note = "use `slice(rows, cols).to_matrix()` with half-open ranges")]
     pub fn submatrix(&self, range_row: &[usize; 2], range_col: &[usize; 2]) -> Matrix<N> {
+        #[allow(deprecated)]
+        match self.try_submatrix(range_row, range_col) {
+            Ok(m) => m,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Returns a submatrix of a matrix
+    ///
+    /// # Arguments
+    ///
+    /// * `range_row`: row's range of the submatrix
+    /// * `range_col`: column's range of the submatrix
+    ///
+    /// # Remarks
+    ///
+    /// * Both ranges are inclusive, for example `[0, 3]`
+    /// * Returns `Error::InvalidRange` if a range is not ascendent
+    /// * Returns `Error::IndexOutOfBounds` if a range exceeds the matrix
+    #[inline]
+    #[deprecated(since = "0.2.0", note = "use `slice(rows, cols).to_matrix()` with half-open ranges")]
+    pub fn try_submatrix(&self, range_row: &[usize; 2], range_col: &[usize; 2])
+        -> Result<Matrix<N>, Error> {
+
         if range_col[0] > range_col[1] || range_row[0] > range_row[1] {
-            panic!("please use ascendent ranges. For example '[0 3]'");
+            return Err(Error::InvalidRange);
+        }
+        if range_row[1] >= self.nrows {
+            return Err(Error::IndexOutOfBounds { index: range_row[1], size: self.nrows });
         }
-        if range_col[1] > self.ncols || range_row[1] > self.nrows {
-            panic!("index out of range");
+        if range_col[1] >= self.ncols {
+            return Err(Error::IndexOutOfBounds { index: range_col[1], size: self.ncols });
         }
 
-        let mut submatrix: Matrix<N> = Matrix::<N>::init_with_capacity(self.ncols, self.nrows);
+        let nrows = range_row[1] + 1 - range_row[0];
+        let ncols = range_col[1] + 1 - range_col[0];
+        let mut values: Vec<N> = Vec::with_capacity(nrows * ncols);
         for i in range_row[0]..(range_row[1]+1) {
-            let mut row: Vec<N> = Vec::new();
-            for j in range_col[0]..(range_col[1]+1) {
-                row.push(self.values[i][j]);
+            let start = self.offset(i, range_col[0]);
+            values.extend_from_slice(&self.values[start..start + ncols]);
+        }
+        Ok(Matrix::<N>::from_vec(nrows, ncols, values))
+    }
+
+    /// Returns if the matrix is symmetric
+    ///
+    /// # Arguments
+    ///
+    /// * `tol`: maximal absolute difference allowed between `(i, j)` and `(j, i)`
+    ///
+    /// # Remarks
+    ///
+    /// * A non square matrix is never symmetric
+    #[inline]
+    pub fn is_symmetric(&self, tol: N) -> bool where N: Num + PartialOrd {
+        if self.nrows != self.ncols {
+            return false;
+        }
+
+        for i in 0..self.nrows {
+            for j in (i + 1)..self.ncols {
+                let (a, b) = (self.values[self.offset(i, j)], self.values[self.offset(j, i)]);
+                let diff = if a > b { a - b } else { b - a };
+                if diff > tol {
+                    return false;
+                }
             }
-            submatrix.values.push(row);
         }
-        submatrix.update_sizes();
-        submatrix
+        true
     }
 
     /// Returns a matrix with the Euclidean Distance between the rows
@@ -656,22 +1483,18 @@ impl<N: Copy> Matrix<N> { // implementation of Matrix<N>
     ///   of the given matrix
     #[inline]
     pub fn eucl_distance_row(&self) -> Matrix<f64> where N: Into<f64> + Num {
-        let mut m: Matrix<f64> = Matrix::<f64>::new();
+        let mut values: Vec<f64> = Vec::with_capacity(self.nrows * self.nrows);
 
-        for (_, row_i) in self.values.iter().enumerate() {
-            let mut eucl_row: Vec<f64> = Vec::new();
-
-            for (_, row_j) in self.values.iter().enumerate() {
+        for row_i in self.row_iter() {
+            for row_j in self.row_iter() {
                 let mut val: f64 = 0.0;
                 for x in 0..self.ncols {
                     val = val + (pow(row_i[x] - row_j[x], 2)).into();
                 }
-                eucl_row.push(val.sqrt());
+                values.push(val.sqrt());
             }
-            m.push_col(eucl_row);
         }
-        m.update_sizes();
-        m
+        Matrix::<f64>::from_vec(self.nrows, self.nrows, values)
     }
 
 
@@ -684,7 +1507,8 @@ impl<N: Copy> Matrix<N> { // implementation of Matrix<N>
     pub fn col_iter_at(&self, n: usize) -> IteratorCol<N> {
         IteratorCol {
             m: self,
-            index: n
+            index: n.min(self.ncols),
+            end: self.ncols
         }
     }
 
@@ -703,7 +1527,8 @@ impl<N: Copy> Matrix<N> { // implementation of Matrix<N>
     pub fn row_iter_at(&self, n: usize) -> IteratorRow<N> {
         IteratorRow {
             m: self,
-            index: n
+            index: n.min(self.nrows),
+            end: self.nrows
         }
     }
 
@@ -720,9 +1545,11 @@ impl<N: Copy> Matrix<N> { // implementation of Matrix<N>
     /// * `n`: index that the iterator will point to
     #[inline]
     pub fn el_iter_at(&self, n: usize) -> IteratorElement<N> {
+        let len = self.values.len();
         IteratorElement {
             m: self,
-            index: n
+            index: n.min(len),
+            end: len
         }
     }
 
@@ -731,6 +1558,61 @@ impl<N: Copy> Matrix<N> { // implementation of Matrix<N>
     pub fn el_iter(&self) -> IteratorElement<N> {
         self.el_iter_at(0)
     }
+
+    /// Returns an iterator over references to the elements, row by row
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'_, N> {
+        self.values.iter()
+    }
+
+    /// Returns an iterator over mutable references to the elements, row by row
+    #[inline]
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, N> {
+        self.values.iter_mut()
+    }
+
+    /// Builds a matrix with the elements of an iterator, row by row
+    ///
+    /// # Arguments
+    ///
+    /// * `nrows`: number of rows
+    /// * `ncols`: number of columns
+    /// * `iter`: elements of the matrix
+    ///
+    /// # Remarks
+    ///
+    /// * Panics if the iterator hasn't `nrows * ncols` elements (see
+    ///   `try_from_shape_iter`)
+    #[inline]
+    pub fn from_shape_iter<I>(nrows: usize, ncols: usize, iter: I) -> Matrix<N> where I: IntoIterator<Item = N> {
+        match Matrix::<N>::try_from_shape_iter(nrows, ncols, iter) {
+            Ok(m) => m,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Builds a matrix with the elements of an iterator, row by row
+    ///
+    /// # Arguments
+    ///
+    /// * `nrows`: number of rows
+    /// * `ncols`: number of columns
+    /// * `iter`: elements of the matrix
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::DimensionMismatch` with the number of elements if
+    ///   the iterator hasn't `nrows * ncols` elements
+    #[inline]
+    pub fn try_from_shape_iter<I>(nrows: usize, ncols: usize, iter: I) -> Result<Matrix<N>, Error>
+        where I: IntoIterator<Item = N> {
+
+        let values: Vec<N> = iter.into_iter().collect();
+        if values.len() != nrows * ncols {
+            return Err(Error::DimensionMismatch { expected: (nrows * ncols, 1), found: (values.len(), 1) });
+        }
+        Ok(Matrix::<N>::from_vec(nrows, ncols, values))
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -739,7 +1621,7 @@ impl<N: Copy> Matrix<N> { // implementation of Matrix<N>
 
 
 /// Copy implementation for Matrix
-impl<N: Copy> Copy for Matrix<N> where Vec<Vec<N>>: Copy + Clone { }
+impl<N: Copy> Copy for Matrix<N> where Vec<N>: Copy + Clone { }
 
 /// Clone implementation for Matrix
 impl<N: Copy> Clone for Matrix<N> {
@@ -756,14 +1638,30 @@ impl<N: Copy> Clone for Matrix<N> {
 /// Equivalence ´==´ implementation for Matrix
 impl<N: Copy + PartialEq> PartialEq for Matrix<N> {
     fn eq(&self, other: &Matrix<N>) -> bool {
-        for i in 0..self.nrows {
-            for j in 0..self.ncols {
-                if self.values[i][j] != other.values[i][j] {
-                    return false;
-                }
-            }
+        self.nrows == other.nrows && self.ncols == other.ncols && self.values == other.values
+    }
+}
+
+/// Indexing ´m[(i, j)]´ implementation for Matrix
+impl<N: Copy> Index<(usize, usize)> for Matrix<N> {
+    type Output = N;
+
+    fn index(&self, (i_row, i_col): (usize, usize)) -> &N {
+        if i_row >= self.nrows || i_col >= self.ncols {
+            panic!("index ({}, {}) out of range. size = {} x {}", i_row, i_col, self.nrows, self.ncols);
         }
-        true
+        &self.values[self.offset(i_row, i_col)]
+    }
+}
+
+/// Mutable indexing ´m[(i, j)] = val´ implementation for Matrix
+impl<N: Copy> IndexMut<(usize, usize)> for Matrix<N> {
+    fn index_mut(&mut self, (i_row, i_col): (usize, usize)) -> &mut N {
+        if i_row >= self.nrows || i_col >= self.ncols {
+            panic!("index ({}, {}) out of range. size = {} x {}", i_row, i_col, self.nrows, self.ncols);
+        }
+        let offset = self.offset(i_row, i_col);
+        &mut self.values[offset]
     }
 }
 
@@ -772,24 +1670,10 @@ impl<'a, N: Copy> Add for &'a Matrix<N> where N: Num + Add {
     type Output = Matrix<N>;
 
     fn add(self, other: &'a Matrix<N>) -> Matrix<N> {
-        if (self.nrows != other.nrows) || (self.ncols != other.ncols) {
-            panic!("sizes not compatible!");
-        }
-
-        let mut res: Matrix<N> = Matrix::<N>::new();
-        for i in 0..self.nrows {
-
-            let mut res_row: Vec<N> = Vec::new();
-            for j in 0..self.ncols {
-                let val = self.values[i][j] + other.values[i][j];
-                res_row.push(val);
-            }
-            res.values.push(res_row);
+        match self.try_add(other) {
+            Ok(m) => m,
+            Err(e) => panic!("{}", e),
         }
-        res.ncols = self.ncols;
-        res.nrows = self.nrows;
-
-        res
     }
 }
 
@@ -807,24 +1691,10 @@ impl<'a, N: Copy> Sub for &'a Matrix<N> where N: Num + Sub {
     type Output = Matrix<N>;
 
     fn sub(self, other: &'a Matrix<N>) -> Matrix<N> {
-        if (self.nrows != other.nrows) || (self.ncols != other.ncols) {
-            panic!("sizes not compatible!");
-        }
-
-        let mut res: Matrix<N> = Matrix::<N>::new();
-        for i in 0..self.nrows {
-
-            let mut res_row: Vec<N> = Vec::new();
-            for j in 0..self.ncols {
-                let val = self.values[i][j] - other.values[i][j];
-                res_row.push(val);
-            }
-            res.values.push(res_row);
+        match self.try_sub(other) {
+            Ok(m) => m,
+            Err(e) => panic!("{}", e),
         }
-        res.ncols = self.ncols;
-        res.nrows = self.nrows;
-
-        res
     }
 }
 
@@ -837,54 +1707,191 @@ impl<N: Copy> Sub for Matrix<N> where N: Num + Sub {
     }
 }
 
+/// Addition ´+´ of an scalar to every element of a &Matrix
+impl<N: Copy> Add<N> for &Matrix<N> where N: Num {
+    type Output = Matrix<N>;
+
+    fn add(self, scalar: N) -> Matrix<N> {
+        let values: Vec<N> = self.values.iter().map(|el| *el + scalar).collect();
+        Matrix::<N>::from_vec(self.nrows, self.ncols, values)
+    }
+}
+
+/// Addition ´+´ of an scalar to every element of a Matrix
+impl<N: Copy> Add<N> for Matrix<N> where N: Num {
+    type Output = Matrix<N>;
+
+    fn add(mut self, scalar: N) -> Matrix<N> {
+        self += scalar;
+        self
+    }
+}
+
+/// Substraction ´-´ of an scalar from every element of a &Matrix
+impl<N: Copy> Sub<N> for &Matrix<N> where N: Num {
+    type Output = Matrix<N>;
+
+    fn sub(self, scalar: N) -> Matrix<N> {
+        let values: Vec<N> = self.values.iter().map(|el| *el - scalar).collect();
+        Matrix::<N>::from_vec(self.nrows, self.ncols, values)
+    }
+}
+
+/// Substraction ´-´ of an scalar from every element of a Matrix
+impl<N: Copy> Sub<N> for Matrix<N> where N: Num {
+    type Output = Matrix<N>;
+
+    fn sub(mut self, scalar: N) -> Matrix<N> {
+        self -= scalar;
+        self
+    }
+}
+
+/// Multiplication ´*´ of every element of a &Matrix by an scalar
+impl<N: Copy> Mul<N> for &Matrix<N> where N: Num {
+    type Output = Matrix<N>;
+
+    fn mul(self, scalar: N) -> Matrix<N> {
+        self.scalar_mul(scalar)
+    }
+}
+
+/// Multiplication ´*´ of every element of a Matrix by an scalar
+impl<N: Copy> Mul<N> for Matrix<N> where N: Num {
+    type Output = Matrix<N>;
+
+    fn mul(mut self, scalar: N) -> Matrix<N> {
+        self *= scalar;
+        self
+    }
+}
+
+/// Division ´/´ of every element of a &Matrix by an scalar
+impl<N: Copy> Div<N> for &Matrix<N> where N: Num {
+    type Output = Matrix<N>;
+
+    fn div(self, scalar: N) -> Matrix<N> {
+        let values: Vec<N> = self.values.iter().map(|el| *el / scalar).collect();
+        Matrix::<N>::from_vec(self.nrows, self.ncols, values)
+    }
+}
+
+/// Division ´/´ of every element of a Matrix by an scalar
+impl<N: Copy> Div<N> for Matrix<N> where N: Num {
+    type Output = Matrix<N>;
+
+    fn div(mut self, scalar: N) -> Matrix<N> {
+        self /= scalar;
+        self
+    }
+}
+
+/// Negation ´-´ implementation for &Matrix
+impl<N: Copy> Neg for &Matrix<N> where N: Neg<Output = N> {
+    type Output = Matrix<N>;
+
+    fn neg(self) -> Matrix<N> {
+        let values: Vec<N> = self.values.iter().map(|el| -*el).collect();
+        Matrix::<N>::from_vec(self.nrows, self.ncols, values)
+    }
+}
+
+/// Negation ´-´ implementation for Matrix
+impl<N: Copy> Neg for Matrix<N> where N: Neg<Output = N> {
+    type Output = Matrix<N>;
+
+    fn neg(mut self) -> Matrix<N> {
+        for el in self.values.iter_mut() {
+            *el = -*el;
+        }
+        self
+    }
+}
+
+/// Addition assignment ´+=´ of a &Matrix
+impl<'a, N: Copy> AddAssign<&'a Matrix<N>> for Matrix<N> where N: Num {
+    fn add_assign(&mut self, other: &'a Matrix<N>) {
+        if let Err(e) = self.check_same_size(other) {
+            panic!("{}", e);
+        }
+        for (a, b) in self.values.iter_mut().zip(other.values.iter()) {
+            *a = *a + *b;
+        }
+    }
+}
+
+/// Addition assignment ´+=´ of an scalar to every element
+impl<N: Copy> AddAssign<N> for Matrix<N> where N: Num {
+    fn add_assign(&mut self, scalar: N) {
+        for el in self.values.iter_mut() {
+            *el = *el + scalar;
+        }
+    }
+}
+
+/// Substraction assignment ´-=´ of a &Matrix
+impl<'a, N: Copy> SubAssign<&'a Matrix<N>> for Matrix<N> where N: Num {
+    fn sub_assign(&mut self, other: &'a Matrix<N>) {
+        if let Err(e) = self.check_same_size(other) {
+            panic!("{}", e);
+        }
+        for (a, b) in self.values.iter_mut().zip(other.values.iter()) {
+            *a = *a - *b;
+        }
+    }
+}
+
+/// Substraction assignment ´-=´ of an scalar from every element
+impl<N: Copy> SubAssign<N> for Matrix<N> where N: Num {
+    fn sub_assign(&mut self, scalar: N) {
+        for el in self.values.iter_mut() {
+            *el = *el - scalar;
+        }
+    }
+}
+
+/// Multiplication assignment ´*=´ with a &Matrix, as the matrix product
+impl<'a, N: Copy + Default> MulAssign<&'a Matrix<N>> for Matrix<N> where N: Num + ParallelElement {
+    fn mul_assign(&mut self, other: &'a Matrix<N>) {
+        match self.try_mul(other) {
+            Ok(m) => *self = m,
+            Err(e) => panic!("{}", e),
+        }
+    }
+}
+
+/// Multiplication assignment ´*=´ of every element by an scalar
+impl<N: Copy> MulAssign<N> for Matrix<N> where N: Num {
+    fn mul_assign(&mut self, scalar: N) {
+        for el in self.values.iter_mut() {
+            *el = *el * scalar;
+        }
+    }
+}
+
+/// Division assignment ´/=´ of every element by an scalar
+impl<N: Copy> DivAssign<N> for Matrix<N> where N: Num {
+    fn div_assign(&mut self, scalar: N) {
+        for el in self.values.iter_mut() {
+            *el = *el / scalar;
+        }
+    }
+}
+
 /// Multiplication `*` implementation for &Matrix<N>
-// Note: it will be good to implement Mul using parallelism
-impl<'a, N: Copy + Default> Mul for &'a Matrix<N> where N: Num + Copy {
+impl<'a, N: Copy + Default> Mul for &'a Matrix<N> where N: Num + Copy + ParallelElement {
     type Output = Matrix<N>;
 
     fn mul(self, other: &'a Matrix<N>) -> Matrix<N> {
-        if self.nrows != other.ncols {
-            panic!("matrix dimension mismatch")
-        } else {
-            let mut res: Matrix<N> = Matrix::<N>::new();
-
-            for irs in 0..self.nrows {
-                let mut new_row: Vec<N> = Vec::new();
-                for ico in 0..other.ncols {
-                    let rs: Vec<N>;
-                    let result_r = self.row(irs);
-                    match result_r {
-                        Some(x) => rs = x.clone(),
-                        None => panic!("col of input matrix not found {}", irs),
-                    }
-
-                    let co: Vec<N>;
-                    let result_c = other.col(ico);
-                    match result_c {
-                        Some(x) => co = x,
-                        None => panic!("column of input matrix not found {}", ico),
-                    }
-
-                    if rs.len() != co.len() {
-                        panic!("size of picked row/column not equal")
-                    }
-
-                    let mut sum: N = N::default();
-                    for i in 0..rs.len() {
-                        sum = sum + (rs[i] * co[i]);
-                    }
-
-                    new_row.push(sum);
-                }
-                res.push_row(new_row);
-            }
-            res
+        match self.try_mul(other) {
+            Ok(m) => m,
+            Err(e) => panic!("{}", e),
         }
     }
 }
 
 /// Multiplication `*` implementation for Matrix<N> (clone() must be used)
-impl<N: Copy + Default> Mul for Matrix<N> where N: Num + Copy {
+impl<N: Copy + Default> Mul for Matrix<N> where N: Num + Copy + ParallelElement {
     type Output = Matrix<N>;
 
     fn mul(self, other: Matrix<N>) -> Matrix<N> {
@@ -892,28 +1899,76 @@ impl<N: Copy + Default> Mul for Matrix<N> where N: Num + Copy {
     }
 }
 
+/// Multiplication `*` implementation of &Matrix<N> with a column &Vector<N>
+impl<'a, N: Copy> Mul<&'a Vector<N>> for &'a Matrix<N> where N: Num + ParallelElement {
+    type Output = Vector<N>;
+
+    fn mul(self, other: &'a Vector<N>) -> Vector<N> {
+        match self.try_mul_vector(other) {
+            Ok(v) => v,
+            Err(e) => panic!("{}", e),
+        }
+    }
+}
+
+/// Multiplication `*` implementation of Matrix<N> with a column Vector<N>
+impl<N: Copy> Mul<Vector<N>> for Matrix<N> where N: Num + ParallelElement {
+    type Output = Vector<N>;
+
+    fn mul(self, other: Vector<N>) -> Vector<N> {
+        &self * &other
+    }
+}
+
 /// Display implementation for Matrix
 impl<N: Copy> fmt::Display for Matrix<N> where N: Display {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        try!(writeln!(f, "{{"));
-        for row in self.values.iter() {
-            try!(write!(f, "[ "));
-            for el in row.iter() {
-                try!(write!(f, "{} ", el));
-            }
-            try!(writeln!(f, "]"));
-        }
-        try!(writeln!(f, "}}"));
-        write!(f, "size: {row} x {col}", row = self.nrows, col = self.ncols)
+        self.as_view().fmt(f)
+    }
+}
+
+/// IntoIterator implementation for Matrix, consuming the elements row by row
+impl<N: Copy> IntoIterator for Matrix<N> {
+    type Item = N;
+    type IntoIter = vec::IntoIter<N>;
+
+    fn into_iter(self) -> vec::IntoIter<N> {
+        self.values.into_iter()
+    }
+}
+
+/// IntoIterator implementation for &Matrix
+impl<'a, N: Copy> IntoIterator for &'a Matrix<N> {
+    type Item = &'a N;
+    type IntoIter = slice::Iter<'a, N>;
+
+    fn into_iter(self) -> slice::Iter<'a, N> {
+        self.values.iter()
+    }
+}
+
+/// IntoIterator implementation for &mut Matrix
+impl<'a, N: Copy> IntoIterator for &'a mut Matrix<N> {
+    type Item = &'a mut N;
+    type IntoIter = slice::IterMut<'a, N>;
+
+    fn into_iter(self) -> slice::IterMut<'a, N> {
+        self.values.iter_mut()
     }
 }
 
 // --------------- Iterators ----------------------------------------
 
 /// Definition of IteratorCol: the iterator for the columns dimension
+///
+/// # Remarks
+///
+/// * The columns aren't contiguous, so they are returned as owned vectors;
+///   `col_view` borrows a column without copying it
 pub struct IteratorCol<'a, N: 'a + Copy> {
     m: &'a Matrix<N>,
-    index: usize
+    index: usize,
+    end: usize
 }
 
 /// Implementation of the Iterator for IteratorCol
@@ -921,37 +1976,82 @@ impl<'a, N: Clone + Copy> Iterator for IteratorCol<'a, N> {
     type Item = Vec<N>;
 
     fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.end {
+            return None;
+        }
         self.index += 1;
-        match self.index > self.m.ncols() {
-            true => None,
-            false => self.m.col(self.index - 1)
+        self.m.col(self.index - 1)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.index;
+        (len, Some(len))
+    }
+}
+
+/// Implementation of the DoubleEndedIterator for IteratorCol
+impl<'a, N: Clone + Copy> DoubleEndedIterator for IteratorCol<'a, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.index >= self.end {
+            return None;
         }
+        self.end -= 1;
+        self.m.col(self.end)
     }
 }
 
+/// Implementation of the ExactSizeIterator for IteratorCol
+impl<'a, N: Clone + Copy> ExactSizeIterator for IteratorCol<'a, N> { }
+
 /// Definition of IteratorRow: the iterator for the row dimension
+///
+/// # Remarks
+///
+/// * The rows are borrowed as slices of the matrix (see `row_slice`), since
+///   0.2.0 they aren't `&Vec<N>`
 pub struct IteratorRow<'a, N: 'a + Copy> {
     m: &'a Matrix<N>,
-    index: usize
+    index: usize,
+    end: usize
 }
 
 /// Implementation of the Iterator for IteratorRow
 impl<'a, N: Clone + Copy> Iterator for IteratorRow<'a, N> {
-    type Item = &'a Vec<N>;
+    type Item = &'a [N];
 
     fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.end {
+            return None;
+        }
         self.index += 1;
-        match self.index > self.m.nrows() {
-            true => None,
-            false => self.m.row(self.index - 1)
+        self.m.row_slice(self.index - 1)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.index;
+        (len, Some(len))
+    }
+}
+
+/// Implementation of the DoubleEndedIterator for IteratorRow
+impl<'a, N: Clone + Copy> DoubleEndedIterator for IteratorRow<'a, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.index >= self.end {
+            return None;
         }
+        self.end -= 1;
+        self.m.row_slice(self.end)
     }
 }
 
+/// Implementation of the ExactSizeIterator for IteratorRow
+impl<'a, N: Clone + Copy> ExactSizeIterator for IteratorRow<'a, N> { }
+
 /// Definition of IteratorElement: the iterator for each element
 pub struct IteratorElement<'a, N: 'a + Copy> {
     m: &'a Matrix<N>,
-    index: usize
+    index: usize,
+    end: usize
 }
 
 /// Implementation of the Iterator for IteratorElement
@@ -959,11 +2059,29 @@ impl<'a, N: Clone + Copy> Iterator for IteratorElement<'a, N> {
     type Item = N;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let row_i: usize = self.index / self.m.ncols();
-        let col_i: usize = self.index % self.m.ncols();
-
+        if self.index >= self.end {
+            return None;
+        }
         self.index += 1;
+        Some(self.m.values[self.index - 1])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.index;
+        (len, Some(len))
+    }
+}
 
-        Some(self.m.get_element(row_i, col_i))
+/// Implementation of the DoubleEndedIterator for IteratorElement
+impl<'a, N: Clone + Copy> DoubleEndedIterator for IteratorElement<'a, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.index >= self.end {
+            return None;
+        }
+        self.end -= 1;
+        Some(self.m.values[self.end])
     }
 }
+
+/// Implementation of the ExactSizeIterator for IteratorElement
+impl<'a, N: Clone + Copy> ExactSizeIterator for IteratorElement<'a, N> { }
@@ -1,2 +1,13 @@
 pub mod matrix;
 pub mod vector;
+pub mod view;
+mod gemm;
+mod combinators;
+mod mask;
+pub mod lu;
+pub mod qr;
+pub mod cholesky;
+pub mod eigen;
+pub mod svd;
+pub mod stats;
+pub mod order;
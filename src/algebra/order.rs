@@ -0,0 +1,295 @@
+use std::cmp::Ordering;
+use algebra::vector::Vector;
+use error::Error;
+
+/// Handling of the values that are not comparable (`NaN`) when a vector of
+/// floating point numbers is ordered
+///
+/// # Remarks
+///
+/// * `Propagate`: the `NaN` values are kept. Searches of the minimum or
+///   maximum return the first `NaN`, and sorts place them after all the
+///   numbers, in their original order
+/// * `Ignore`: the `NaN` values are skipped, and removed by the sorts
+/// * `Error`: the operation returns `Error::NotANumber` with the index of
+///   the first `NaN`, without modifying the vector
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NanPolicy {
+    Propagate,
+    Ignore,
+    Error,
+}
+
+/// Total order of floating point numbers, as defined by IEEE 754
+///
+/// # Remarks
+///
+/// * The order is `-NaN < -inf < ... < -0 < +0 < ... < +inf < +NaN`, so a
+///   vector of floats can be sorted without any policy
+pub trait TotalOrder: Copy {
+    /// Compares two numbers with the total order
+    fn total_cmp(&self, other: &Self) -> Ordering;
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Inherent methods
+////////////////////////////////////////////////////////////////////////////////
+
+impl<N: Copy> Vector<N> {
+
+    /// Returns the maximal element and its index, using `PartialOrd`
+    ///
+    /// # Arguments
+    ///
+    /// * `policy`: handling of the `NaN` values (see `NanPolicy`)
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `None` if there are no elements to compare
+    /// * On ties the first element is returned
+    #[inline]
+    pub fn partial_max(&self, policy: NanPolicy) -> Result<Option<(N, usize)>, Error> where N: PartialOrd {
+        self.partial_extreme(policy, Ordering::Greater)
+    }
+
+    /// Returns the minimal element and its index, using `PartialOrd`
+    ///
+    /// # Arguments
+    ///
+    /// * `policy`: handling of the `NaN` values (see `NanPolicy`)
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `None` if there are no elements to compare
+    /// * On ties the first element is returned
+    #[inline]
+    pub fn partial_min(&self, policy: NanPolicy) -> Result<Option<(N, usize)>, Error> where N: PartialOrd {
+        self.partial_extreme(policy, Ordering::Less)
+    }
+
+    /// Orders a vector from the minimum to maximum value, using `PartialOrd`
+    ///
+    /// # Arguments
+    ///
+    /// * `policy`: handling of the `NaN` values (see `NanPolicy`)
+    ///
+    /// # Remarks
+    ///
+    /// * The sort is stable
+    #[inline]
+    pub fn sort_min_partial(&mut self, policy: NanPolicy) -> Result<(), Error> where N: PartialOrd {
+        self.sort_partial_by(policy, |a, b| partial_cmp(a, b))
+    }
+
+    /// Orders a vector from the maximum to minimum value, using `PartialOrd`
+    ///
+    /// # Arguments
+    ///
+    /// * `policy`: handling of the `NaN` values (see `NanPolicy`), they are
+    ///   placed at the end as well
+    ///
+    /// # Remarks
+    ///
+    /// * The sort is stable
+    #[inline]
+    pub fn sort_max_partial(&mut self, policy: NanPolicy) -> Result<(), Error> where N: PartialOrd {
+        self.sort_partial_by(policy, |a, b| partial_cmp(b, a))
+    }
+
+    /// Returns the indices that would order the vector from the minimum to
+    /// the maximum value
+    ///
+    /// # Arguments
+    ///
+    /// * `policy`: handling of the `NaN` values (see `NanPolicy`), the
+    ///   ignored ones are not in the result
+    ///
+    /// # Remarks
+    ///
+    /// * Equal elements keep their relative order
+    #[inline]
+    pub fn argsort(&self, policy: NanPolicy) -> Result<Vec<usize>, Error> where N: PartialOrd {
+        let values = self.as_slice();
+        let (mut ordered, unordered) = split_unordered((0..values.len()).collect(), |i| values[*i], policy)?;
+        ordered.sort_by(|a, b| partial_cmp(&values[*a], &values[*b]));
+        Ok(join(ordered, unordered, policy))
+    }
+
+    /// Orders a vector comparing the keys extracted from its elements
+    ///
+    /// # Arguments
+    ///
+    /// * `f`: function returning the key of an element
+    ///
+    /// # Remarks
+    ///
+    /// * The sort is stable
+    #[inline]
+    pub fn sort_by_key<K: Ord, F>(&mut self, f: F) where F: FnMut(&N) -> K {
+        self.as_mut_slice().sort_by_key(f);
+    }
+
+    /// Orders a vector with a comparison function
+    ///
+    /// # Arguments
+    ///
+    /// * `compare`: function ordering two elements
+    ///
+    /// # Remarks
+    ///
+    /// * The sort is stable
+    #[inline]
+    pub fn sort_by<F>(&mut self, compare: F) where F: FnMut(&N, &N) -> Ordering {
+        self.as_mut_slice().sort_by(compare);
+    }
+
+    /// Orders a vector of floats from the minimum to maximum value with
+    /// their total order (see `TotalOrder`)
+    #[inline]
+    pub fn sort_total(&mut self) where N: TotalOrder {
+        self.as_mut_slice().sort_by(|a, b| a.total_cmp(b));
+    }
+
+    /// Moves the `k` smallest elements to the start of the vector, ordered
+    /// from the minimum to the maximum value
+    ///
+    /// # Arguments
+    ///
+    /// * `k`: number of elements to order
+    /// * `policy`: handling of the `NaN` values (see `NanPolicy`)
+    ///
+    /// # Remarks
+    ///
+    /// * The order of the remaining elements is unspecified
+    /// * It's faster than a full sort when `k` is small
+    #[inline]
+    pub fn partial_sort(&mut self, k: usize, policy: NanPolicy) -> Result<(), Error> where N: PartialOrd {
+        let values = self.as_slice().to_vec();
+        let (mut ordered, unordered) = split_unordered(values, |el| *el, policy)?;
+        smallest_first(&mut ordered, k, |a, b| partial_cmp(a, b));
+        *self = Vector::<N>::from_vec(join(ordered, unordered, policy));
+        Ok(())
+    }
+
+    /// Returns the `k` biggest elements, ordered from the maximum value
+    ///
+    /// # Arguments
+    ///
+    /// * `k`: number of elements to return
+    /// * `policy`: handling of the `NaN` values (see `NanPolicy`)
+    ///
+    /// # Remarks
+    ///
+    /// * Returns less than `k` elements if the vector is smaller
+    /// * The propagated `NaN` values go after all the numbers
+    #[inline]
+    pub fn top_k(&self, k: usize, policy: NanPolicy) -> Result<Vector<N>, Error> where N: PartialOrd {
+        let values = self.as_slice().to_vec();
+        let (mut ordered, unordered) = split_unordered(values, |el| *el, policy)?;
+        smallest_first(&mut ordered, k, |a, b| partial_cmp(b, a));
+        ordered.truncate(k);
+
+        let mut top = join(ordered, unordered, policy);
+        top.truncate(k);
+        Ok(Vector::<N>::from_vec(top))
+    }
+
+    // internal use: first element that is `target` than all the others
+    fn partial_extreme(&self, policy: NanPolicy, target: Ordering) -> Result<Option<(N, usize)>, Error>
+        where N: PartialOrd {
+        let mut best: Option<(N, usize)> = None;
+        for (i, el) in self.as_slice().iter().enumerate() {
+            if is_unordered(el) {
+                match policy {
+                    NanPolicy::Propagate => return Ok(Some((*el, i))),
+                    NanPolicy::Ignore => continue,
+                    NanPolicy::Error => return Err(Error::NotANumber { index: i }),
+                }
+            }
+            let replace = match best {
+                Some((b, _)) => el.partial_cmp(&b) == Some(target),
+                None => true,
+            };
+            if replace {
+                best = Some((*el, i));
+            }
+        }
+        Ok(best)
+    }
+
+    // internal use: stable sort of the comparable elements
+    fn sort_partial_by<F>(&mut self, policy: NanPolicy, compare: F) -> Result<(), Error>
+        where N: PartialOrd, F: FnMut(&N, &N) -> Ordering {
+        let values = self.as_slice().to_vec();
+        let (mut ordered, unordered) = split_unordered(values, |el| *el, policy)?;
+        ordered.sort_by(compare);
+        *self = Vector::<N>::from_vec(join(ordered, unordered, policy));
+        Ok(())
+    }
+}
+
+// internal use: values that are not comparable with themselves (`NaN`)
+fn is_unordered<N: PartialOrd>(el: &N) -> bool {
+    el.partial_cmp(el).is_none()
+}
+
+// internal use: comparison of two comparable elements
+fn partial_cmp<N: PartialOrd>(a: &N, b: &N) -> Ordering {
+    a.partial_cmp(b).unwrap_or(Ordering::Equal)
+}
+
+// internal use: separates the items with a comparable value from the others,
+// following the policy
+fn split_unordered<T, N, F>(items: Vec<T>, value: F, policy: NanPolicy) -> Result<(Vec<T>, Vec<T>), Error>
+    where N: PartialOrd, F: Fn(&T) -> N {
+    let mut ordered: Vec<T> = Vec::with_capacity(items.len());
+    let mut unordered: Vec<T> = Vec::new();
+    for (i, item) in items.into_iter().enumerate() {
+        if !is_unordered(&value(&item)) {
+            ordered.push(item);
+        } else if policy == NanPolicy::Error {
+            return Err(Error::NotANumber { index: i });
+        } else {
+            unordered.push(item);
+        }
+    }
+    Ok((ordered, unordered))
+}
+
+// internal use: appends the not comparable items if they are propagated
+fn join<T>(mut ordered: Vec<T>, unordered: Vec<T>, policy: NanPolicy) -> Vec<T> {
+    if policy == NanPolicy::Propagate {
+        ordered.extend(unordered);
+    }
+    ordered
+}
+
+// internal use: moves the first `k` items of the order to the start, sorted
+fn smallest_first<T, F>(items: &mut [T], k: usize, mut compare: F) where F: FnMut(&T, &T) -> Ordering {
+    if k == 0 {
+        return;
+    }
+    if k < items.len() {
+        items.select_nth_unstable_by(k - 1, &mut compare);
+    }
+    let end = k.min(items.len());
+    items[..end].sort_by(compare);
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Traits
+////////////////////////////////////////////////////////////////////////////////
+
+/// TotalOrder implementation for f32
+impl TotalOrder for f32 {
+    fn total_cmp(&self, other: &f32) -> Ordering {
+        f32::total_cmp(self, other)
+    }
+}
+
+/// TotalOrder implementation for f64
+impl TotalOrder for f64 {
+    fn total_cmp(&self, other: &f64) -> Ordering {
+        f64::total_cmp(self, other)
+    }
+}
@@ -0,0 +1,241 @@
+use num::{Float, NumCast};
+use algebra::matrix::Matrix;
+use algebra::view::MatrixView;
+use algebra::vector::Vector;
+use error::Error;
+
+/// QR decomposition of a `m x n` matrix computed with Householder reflections,
+/// so that `A = Q * R`
+///
+/// # Remarks
+///
+/// * With `p = min(m, n)`, `Q` is a `m x p` matrix with orthonormal columns and
+///   `R` a `p x n` upper triangular (trapezoidal) matrix.
+/// * The Householder vectors are stored below the diagonal of the packed matrix,
+///   the diagonal of `R` is stored apart.
+/// * This struct is created by `Matrix::qr` and is just available for float types.
+pub struct QR<N: Copy> {
+    qr: Vec<N>,
+    rdiag: Vec<N>,
+    nrows: usize,
+    ncols: usize,
+    tol: Vec<N>,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Inherent methods
+////////////////////////////////////////////////////////////////////////////////
+
+impl<N: Copy + Float> Matrix<N> {
+
+    /// Returns the QR decomposition of the matrix
+    #[inline]
+    pub fn qr(&self) -> QR<N> {
+        let (m, n) = (self.nrows(), self.ncols());
+        let p = m.min(n);
+        let mut qr: Vec<N> = self.as_slice().to_vec();
+        let mut rdiag: Vec<N> = vec![N::zero(); p];
+
+        // diagonal elements of R smaller than the rounding error of their own
+        // column, `max(m, n) * epsilon * ||a_k||`, are considered zero
+        let eps = N::epsilon() * <N as NumCast>::from(m.max(n)).unwrap();
+        let tol: Vec<N> = (0..p).map(|k| eps * (0..m).fold(N::zero(), |nrm, i| nrm.hypot(qr[i * n + k])))
+                                .collect();
+
+        for k in 0..p {
+            // norm of the k-th column below the diagonal
+            let mut nrm = N::zero();
+            for i in k..m {
+                nrm = nrm.hypot(qr[i * n + k]);
+            }
+
+            if nrm != N::zero() {
+                // reflector that maps the column onto a multiple of e_k
+                if qr[k * n + k] < N::zero() {
+                    nrm = -nrm;
+                }
+                for i in k..m {
+                    qr[i * n + k] = qr[i * n + k] / nrm;
+                }
+                qr[k * n + k] = qr[k * n + k] + N::one();
+
+                // apply the reflector to the remaining columns
+                for j in (k + 1)..n {
+                    let mut s = N::zero();
+                    for i in k..m {
+                        s = s + qr[i * n + k] * qr[i * n + j];
+                    }
+                    s = -s / qr[k * n + k];
+                    for i in k..m {
+                        qr[i * n + j] = qr[i * n + j] + s * qr[i * n + k];
+                    }
+                }
+            }
+            rdiag[k] = -nrm;
+        }
+
+        QR { qr, rdiag, nrows: m, ncols: n, tol }
+    }
+
+    /// Returns the least squares solution `x` that minimizes `||A * x - b||`
+    ///
+    /// # Arguments
+    ///
+    /// * `b`: right-hand side
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::DimensionMismatch` if `b` hasn't as many elements as rows in `A`
+    /// * Returns `Error::Underdetermined` if `A` has less rows than columns
+    /// * Returns `Error::Singular` if `A` is rank deficient
+    #[inline]
+    pub fn least_squares(&self, b: &Vector<N>) -> Result<Vector<N>, Error> {
+        self.qr().solve_vector(b)
+    }
+}
+
+impl<'a, N: Copy + Float> MatrixView<'a, N> {
+
+    /// Returns the QR decomposition of the view (see `Matrix::qr`)
+    #[inline]
+    pub fn qr(&self) -> QR<N> {
+        self.to_matrix().qr()
+    }
+
+    /// Returns the least squares solution of the view (see `Matrix::least_squares`)
+    #[inline]
+    pub fn least_squares(&self, b: &Vector<N>) -> Result<Vector<N>, Error> {
+        self.to_matrix().least_squares(b)
+    }
+}
+
+impl<N: Copy + Float> QR<N> {
+
+    /// Returns the orthonormal factor `Q` (`m x min(m, n)`)
+    #[inline]
+    pub fn q(&self) -> Matrix<N> {
+        let (m, n) = (self.nrows, self.ncols);
+        let p = m.min(n);
+        let mut q: Vec<N> = vec![N::zero(); m * p];
+
+        // accumulate the reflectors backwards over the first p columns of the identity
+        for k in (0..p).rev() {
+            q[k * p + k] = N::one();
+            for j in k..p {
+                if self.qr[k * n + k] != N::zero() {
+                    let mut s = N::zero();
+                    for i in k..m {
+                        s = s + self.qr[i * n + k] * q[i * p + j];
+                    }
+                    s = -s / self.qr[k * n + k];
+                    for i in k..m {
+                        q[i * p + j] = q[i * p + j] + s * self.qr[i * n + k];
+                    }
+                }
+            }
+        }
+        Matrix::<N>::from_vec(m, p, q)
+    }
+
+    /// Returns the upper triangular factor `R` (`min(m, n) x n`)
+    #[inline]
+    pub fn r(&self) -> Matrix<N> {
+        let n = self.ncols;
+        let p = self.rdiag.len();
+        let mut r: Vec<N> = vec![N::zero(); p * n];
+        for i in 0..p {
+            r[i * n + i] = self.rdiag[i];
+            for j in (i + 1)..n {
+                r[i * n + j] = self.qr[i * n + j];
+            }
+        }
+        Matrix::<N>::from_vec(p, n, r)
+    }
+
+    /// Returns if the decomposed matrix has full column rank
+    ///
+    /// # Remarks
+    ///
+    /// * A matrix with less rows than columns never has full column rank
+    /// * A diagonal element of `R` is considered zero if it's not bigger than
+    ///   the rounding error of its column (`max(m, n) * epsilon * ||a_k||`),
+    ///   so the result doesn't depend on the scale of the columns
+    #[inline]
+    pub fn is_full_rank(&self) -> bool {
+        self.nrows >= self.ncols && self.rdiag.iter().zip(self.tol.iter()).all(|(d, tol)| d.abs() > *tol)
+    }
+
+    /// Returns the least squares solution `X` that minimizes `||A * X - B||`
+    /// for one or many right-hand sides
+    ///
+    /// # Arguments
+    ///
+    /// * `b`: right-hand sides, one per column
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::DimensionMismatch` if `b` hasn't as many rows as `A`
+    /// * Returns `Error::Underdetermined` if `A` has less rows than columns
+    /// * Returns `Error::Singular` if `A` is rank deficient
+    #[inline]
+    pub fn solve(&self, b: &Matrix<N>) -> Result<Matrix<N>, Error> {
+        let (m, n) = (self.nrows, self.ncols);
+        if b.nrows() != m {
+            return Err(Error::DimensionMismatch { expected: (m, b.ncols()), found: (b.nrows(), b.ncols()) });
+        }
+        if m < n {
+            return Err(Error::Underdetermined { nrows: m, ncols: n });
+        }
+        if !self.is_full_rank() {
+            return Err(Error::Singular);
+        }
+
+        let nx = b.ncols();
+        let mut x: Vec<N> = b.as_slice().to_vec();
+
+        // compute Q' * B
+        for k in 0..n {
+            for j in 0..nx {
+                let mut s = N::zero();
+                for i in k..m {
+                    s = s + self.qr[i * n + k] * x[i * nx + j];
+                }
+                s = -s / self.qr[k * n + k];
+                for i in k..m {
+                    x[i * nx + j] = x[i * nx + j] + s * self.qr[i * n + k];
+                }
+            }
+        }
+        // solve R * X = Q' * B
+        for k in (0..n).rev() {
+            for j in 0..nx {
+                x[k * nx + j] = x[k * nx + j] / self.rdiag[k];
+            }
+            for i in 0..k {
+                for j in 0..nx {
+                    x[i * nx + j] = x[i * nx + j] - x[k * nx + j] * self.qr[i * n + k];
+                }
+            }
+        }
+
+        x.truncate(n * nx);
+        Ok(Matrix::<N>::from_vec(n, nx, x))
+    }
+
+    /// Returns the least squares solution `x` that minimizes `||A * x - b||`
+    ///
+    /// # Arguments
+    ///
+    /// * `b`: right-hand side
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::DimensionMismatch` if `b` hasn't as many elements as rows in `A`
+    /// * Returns `Error::Underdetermined` if `A` has less rows than columns
+    /// * Returns `Error::Singular` if `A` is rank deficient
+    #[inline]
+    pub fn solve_vector(&self, b: &Vector<N>) -> Result<Vector<N>, Error> {
+        let x = self.solve(&Matrix::<N>::from_vec(b.size(), 1, b.as_slice().to_vec()))?;
+        Ok(Vector::<N>::from_vec(x.into_vec()))
+    }
+}
@@ -0,0 +1,807 @@
+use num::ToPrimitive;
+use std::cmp::Ordering;
+use algebra::matrix::{Axis, Matrix};
+use algebra::vector::Vector;
+use error::Error;
+
+/// Method to compute a quantile that falls between two elements
+///
+/// # Remarks
+///
+/// * With the elements sorted, the quantile `q` is at the fractional rank
+///   `q * (n - 1)`, between the elements `lower` and `higher`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Interpolation {
+    /// Linear interpolation between `lower` and `higher`
+    Linear,
+    /// The `lower` element
+    Lower,
+    /// The `higher` element
+    Higher,
+    /// The closest element, `higher` on halfway ranks
+    Nearest,
+    /// The mean of `lower` and `higher`
+    Midpoint,
+}
+
+/// Online accumulator of the count, mean, variance and extremes of a
+/// sequence of values, without storing them
+///
+/// # Remarks
+///
+/// * It uses the Welford algorithm, so the variance stays accurate even
+///   after many values with a big mean
+/// * Two accumulators of different parts of a sequence can be merged
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RunningStats {
+    count: usize,
+    mean: f64,
+    m2: f64,
+    min: f64,
+    max: f64,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Inherent methods
+////////////////////////////////////////////////////////////////////////////////
+
+impl<N: Copy> Matrix<N> {
+
+    /// Returns the sum of every column (`Axis::Row`) or every row (`Axis::Col`)
+    /// as `f64`
+    ///
+    /// # Arguments
+    ///
+    /// * `axis`: direction of the sums (see `fold_axis`)
+    ///
+    /// # Remarks
+    ///
+    /// * Unlike `sum_axis`, it can't overflow the type of the elements
+    /// * Panics if an element can't be converted (see `try_sum_axis_f64`)
+    #[inline]
+    pub fn sum_axis_f64(&self, axis: Axis) -> Vector<f64> where N: ToPrimitive {
+        match self.try_sum_axis_f64(axis) {
+            Ok(v) => v,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Returns the sum of every column (`Axis::Row`) or every row (`Axis::Col`)
+    /// as `f64`
+    ///
+    /// # Arguments
+    ///
+    /// * `axis`: direction of the sums (see `fold_axis`)
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::NotRepresentable` if an element can't be converted
+    ///   to `f64`, with its index in the row-major order
+    #[inline]
+    pub fn try_sum_axis_f64(&self, axis: Axis) -> Result<Vector<f64>, Error> where N: ToPrimitive {
+        self.try_reduce_axis(axis, |line| line.iter().sum())
+    }
+
+    /// Returns the mean of every column (`Axis::Row`) or every row (`Axis::Col`)
+    ///
+    /// # Arguments
+    ///
+    /// * `axis`: direction of the means (see `fold_axis`)
+    ///
+    /// # Remarks
+    ///
+    /// * Panics if an element can't be converted (see `try_mean_axis`)
+    #[inline]
+    pub fn mean_axis(&self, axis: Axis) -> Vector<f64> where N: ToPrimitive {
+        match self.try_mean_axis(axis) {
+            Ok(v) => v,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Returns the mean of every column (`Axis::Row`) or every row (`Axis::Col`)
+    ///
+    /// # Arguments
+    ///
+    /// * `axis`: direction of the means (see `fold_axis`)
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::NotRepresentable` if an element can't be converted
+    ///   to `f64`, with its index in the row-major order
+    #[inline]
+    pub fn try_mean_axis(&self, axis: Axis) -> Result<Vector<f64>, Error> where N: ToPrimitive {
+        self.try_reduce_axis(axis, mean)
+    }
+
+    /// Returns the minimum of every column (`Axis::Row`) or every row
+    /// (`Axis::Col`) as `f64`
+    ///
+    /// # Arguments
+    ///
+    /// * `axis`: direction of the minimums (see `fold_axis`)
+    ///
+    /// # Remarks
+    ///
+    /// * `min_axis` keeps the type of the elements and gives their positions
+    /// * Panics if an element can't be converted (see `try_min_axis_f64`)
+    #[inline]
+    pub fn min_axis_f64(&self, axis: Axis) -> Vector<f64> where N: ToPrimitive {
+        match self.try_min_axis_f64(axis) {
+            Ok(v) => v,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Returns the minimum of every column (`Axis::Row`) or every row
+    /// (`Axis::Col`) as `f64`
+    ///
+    /// # Arguments
+    ///
+    /// * `axis`: direction of the minimums (see `fold_axis`)
+    ///
+    /// # Remarks
+    ///
+    /// * `NaN` values are skipped, it's `NaN` for the empty columns or rows
+    /// * Returns `Error::NotRepresentable` if an element can't be converted
+    ///   to `f64`, with its index in the row-major order
+    #[inline]
+    pub fn try_min_axis_f64(&self, axis: Axis) -> Result<Vector<f64>, Error> where N: ToPrimitive {
+        self.try_reduce_axis(axis, |line| line.iter().fold(f64::NAN, |acc, el| acc.min(*el)))
+    }
+
+    /// Returns the maximum of every column (`Axis::Row`) or every row
+    /// (`Axis::Col`) as `f64`
+    ///
+    /// # Arguments
+    ///
+    /// * `axis`: direction of the maximums (see `fold_axis`)
+    ///
+    /// # Remarks
+    ///
+    /// * `max_axis` keeps the type of the elements and gives their positions
+    /// * Panics if an element can't be converted (see `try_max_axis_f64`)
+    #[inline]
+    pub fn max_axis_f64(&self, axis: Axis) -> Vector<f64> where N: ToPrimitive {
+        match self.try_max_axis_f64(axis) {
+            Ok(v) => v,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Returns the maximum of every column (`Axis::Row`) or every row
+    /// (`Axis::Col`) as `f64`
+    ///
+    /// # Arguments
+    ///
+    /// * `axis`: direction of the maximums (see `fold_axis`)
+    ///
+    /// # Remarks
+    ///
+    /// * `NaN` values are skipped, it's `NaN` for the empty columns or rows
+    /// * Returns `Error::NotRepresentable` if an element can't be converted
+    ///   to `f64`, with its index in the row-major order
+    #[inline]
+    pub fn try_max_axis_f64(&self, axis: Axis) -> Result<Vector<f64>, Error> where N: ToPrimitive {
+        self.try_reduce_axis(axis, |line| line.iter().fold(f64::NAN, |acc, el| acc.max(*el)))
+    }
+
+    /// Returns the variance of every column (`Axis::Row`) or every row (`Axis::Col`)
+    ///
+    /// # Arguments
+    ///
+    /// * `axis`: direction of the variances (see `fold_axis`)
+    /// * `ddof`: delta degrees of freedom, the sum of squares is divided by
+    ///   `n - ddof`: `0` for the population variance, `1` for the sample one
+    ///
+    /// # Remarks
+    ///
+    /// * Panics if there are not enough elements or an element can't be
+    ///   converted (see `try_variance_axis`)
+    #[inline]
+    pub fn variance_axis(&self, axis: Axis, ddof: usize) -> Vector<f64> where N: ToPrimitive {
+        match self.try_variance_axis(axis, ddof) {
+            Ok(v) => v,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Returns the variance of every column (`Axis::Row`) or every row (`Axis::Col`)
+    ///
+    /// # Arguments
+    ///
+    /// * `axis`: direction of the variances (see `fold_axis`)
+    /// * `ddof`: delta degrees of freedom, the sum of squares is divided by
+    ///   `n - ddof`: `0` for the population variance, `1` for the sample one
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::InsufficientData` if the columns or rows haven't
+    ///   more than `ddof` elements
+    /// * Returns `Error::NotRepresentable` if an element can't be converted
+    ///   to `f64`, with its index in the row-major order
+    #[inline]
+    pub fn try_variance_axis(&self, axis: Axis, ddof: usize) -> Result<Vector<f64>, Error> where N: ToPrimitive {
+        let lines = self.lines(axis)?;
+        if let Some(line) = lines.first() {
+            if line.len() <= ddof {
+                return Err(Error::InsufficientData { required: ddof + 1, found: line.len() });
+            }
+        }
+        let values: Vec<f64> = lines.iter().map(|line| variance(line, ddof)).collect();
+        Ok(Vector::<f64>::from_vec(values))
+    }
+
+    /// Returns the standard deviation of every column (`Axis::Row`) or every
+    /// row (`Axis::Col`)
+    ///
+    /// # Arguments
+    ///
+    /// * `axis`: direction of the deviations (see `fold_axis`)
+    /// * `ddof`: delta degrees of freedom (see `variance_axis`)
+    ///
+    /// # Remarks
+    ///
+    /// * Panics if there are not enough elements or an element can't be
+    ///   converted (see `try_std_dev_axis`)
+    #[inline]
+    pub fn std_dev_axis(&self, axis: Axis, ddof: usize) -> Vector<f64> where N: ToPrimitive {
+        match self.try_std_dev_axis(axis, ddof) {
+            Ok(v) => v,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Returns the standard deviation of every column (`Axis::Row`) or every
+    /// row (`Axis::Col`)
+    ///
+    /// # Arguments
+    ///
+    /// * `axis`: direction of the deviations (see `fold_axis`)
+    /// * `ddof`: delta degrees of freedom (see `variance_axis`)
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::InsufficientData` if the columns or rows haven't
+    ///   more than `ddof` elements
+    /// * Returns `Error::NotRepresentable` like `try_variance_axis`
+    #[inline]
+    pub fn try_std_dev_axis(&self, axis: Axis, ddof: usize) -> Result<Vector<f64>, Error> where N: ToPrimitive {
+        let variances = self.try_variance_axis(axis, ddof)?;
+        Ok(Vector::<f64>::from_vec(variances.as_slice().iter().map(|v| v.sqrt()).collect()))
+    }
+
+    /// Returns the median of every column (`Axis::Row`) or every row (`Axis::Col`)
+    ///
+    /// # Arguments
+    ///
+    /// * `axis`: direction of the medians (see `fold_axis`)
+    ///
+    /// # Remarks
+    ///
+    /// * With an even number of elements it's the mean of the two middle ones
+    /// * It's `NaN` for the columns or rows containing a `NaN`
+    /// * Panics if an element can't be converted (see `try_percentile_axis`)
+    #[inline]
+    pub fn median_axis(&self, axis: Axis) -> Vector<f64> where N: ToPrimitive {
+        self.percentile_axis(axis, 50.0)
+    }
+
+    /// Returns a percentile of every column (`Axis::Row`) or every row (`Axis::Col`)
+    ///
+    /// # Arguments
+    ///
+    /// * `axis`: direction of the percentiles (see `fold_axis`)
+    /// * `p`: percentile to compute, between `0` and `100`
+    ///
+    /// # Remarks
+    ///
+    /// * Panics if the percentile is not valid or an element can't be
+    ///   converted (see `try_percentile_axis`)
+    #[inline]
+    pub fn percentile_axis(&self, axis: Axis, p: f64) -> Vector<f64> where N: ToPrimitive {
+        match self.try_percentile_axis(axis, p) {
+            Ok(v) => v,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Returns a percentile of every column (`Axis::Row`) or every row (`Axis::Col`)
+    ///
+    /// # Arguments
+    ///
+    /// * `axis`: direction of the percentiles (see `fold_axis`)
+    /// * `p`: percentile to compute, between `0` and `100`
+    ///
+    /// # Remarks
+    ///
+    /// * Values between two elements are linearly interpolated
+    /// * It's `NaN` for the columns or rows containing a `NaN`
+    /// * Returns `Error::InvalidArgument` if `p` is not inside `[0, 100]`
+    /// * Returns `Error::NotRepresentable` if an element can't be converted
+    ///   to `f64`, with its index in the row-major order
+    #[inline]
+    pub fn try_percentile_axis(&self, axis: Axis, p: f64) -> Result<Vector<f64>, Error> where N: ToPrimitive {
+        if !(0.0..=100.0).contains(&p) {
+            return Err(Error::InvalidArgument { name: "p" });
+        }
+        let values: Vec<f64> = self.lines(axis)?.into_iter()
+                                               .map(|line| quantile(line, p / 100.0, Interpolation::Linear))
+                                               .collect();
+        Ok(Vector::<f64>::from_vec(values))
+    }
+
+    /// Returns the covariance matrix of the columns, taking every column as
+    /// a variable and every row as an observation
+    ///
+    /// # Remarks
+    ///
+    /// * Panics if there are less than two rows or an element can't be
+    ///   converted (see `try_covariance`)
+    #[inline]
+    pub fn covariance(&self) -> Matrix<f64> where N: ToPrimitive {
+        match self.try_covariance() {
+            Ok(m) => m,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Returns the covariance matrix of the columns, taking every column as
+    /// a variable and every row as an observation
+    ///
+    /// # Remarks
+    ///
+    /// * It's the sample covariance: the products are divided by `nrows - 1`
+    /// * Returns `Error::InsufficientData` if there are less than two rows
+    /// * Returns `Error::NotRepresentable` if an element can't be converted
+    ///   to `f64`, with its index in the row-major order
+    #[inline]
+    pub fn try_covariance(&self) -> Result<Matrix<f64>, Error> where N: ToPrimitive {
+        if self.nrows() < 2 {
+            return Err(Error::InsufficientData { required: 2, found: self.nrows() });
+        }
+
+        let data = Matrix::<f64>::from_vec(self.nrows(), self.ncols(), to_f64_vec(self.as_slice())?);
+        let centered = data.broadcast_sub(&data.mean_axis(Axis::Row), Axis::Row);
+        let mut transposed = centered.clone();
+        transposed.transpose();
+        Ok(&transposed * &centered / (self.nrows() - 1) as f64)
+    }
+
+    /// Returns the matrix of Pearson correlation coefficients of the columns,
+    /// taking every column as a variable and every row as an observation
+    ///
+    /// # Remarks
+    ///
+    /// * Panics if there are less than two rows or an element can't be
+    ///   converted (see `try_correlation`)
+    #[inline]
+    pub fn correlation(&self) -> Matrix<f64> where N: ToPrimitive {
+        match self.try_correlation() {
+            Ok(m) => m,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Returns the matrix of Pearson correlation coefficients of the columns,
+    /// taking every column as a variable and every row as an observation
+    ///
+    /// # Remarks
+    ///
+    /// * The coefficients of a constant column are `NaN`
+    /// * Returns `Error::InsufficientData` and `Error::NotRepresentable` like
+    ///   `try_covariance`
+    #[inline]
+    pub fn try_correlation(&self) -> Result<Matrix<f64>, Error> where N: ToPrimitive {
+        let cov = self.try_covariance()?;
+        let n = cov.nrows();
+
+        let std_devs: Vec<f64> = (0..n).map(|i| cov.get_element(i, i).sqrt()).collect();
+        let mut corr = cov;
+        for i in 0..n {
+            for j in 0..n {
+                let val = if i == j && std_devs[i] != 0.0 {
+                    1.0
+                } else {
+                    corr.get_element(i, j) / (std_devs[i] * std_devs[j])
+                };
+                corr.set_element(i, j, &val);
+            }
+        }
+        Ok(corr)
+    }
+
+    // internal use: `f` applied to every column (`Axis::Row`) or every row
+    // (`Axis::Col`) converted to f64
+    fn try_reduce_axis<F>(&self, axis: Axis, f: F) -> Result<Vector<f64>, Error>
+        where N: ToPrimitive, F: Fn(&[f64]) -> f64 {
+
+        let values: Vec<f64> = self.lines(axis)?.iter().map(|line| f(line)).collect();
+        Ok(Vector::<f64>::from_vec(values))
+    }
+
+    // internal use: elements of every column (`Axis::Row`) or every row
+    // (`Axis::Col`) converted to f64
+    fn lines(&self, axis: Axis) -> Result<Vec<Vec<f64>>, Error> where N: ToPrimitive {
+        let (nrows, ncols) = (self.nrows(), self.ncols());
+        let values = to_f64_vec(self.as_slice())?;
+        Ok(match axis {
+            Axis::Row => (0..ncols).map(|j| (0..nrows).map(|i| values[i * ncols + j]).collect())
+                                   .collect(),
+            Axis::Col => (0..nrows).map(|i| values[i * ncols..(i + 1) * ncols].to_vec())
+                                   .collect(),
+        })
+    }
+}
+
+impl<N: Copy> Vector<N> {
+
+    /// Returns the arithmetic mean of the elements
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `NaN` if the vector is empty
+    /// * Panics if an element can't be converted to `f64`
+    #[inline]
+    pub fn mean(&self) -> f64 where N: ToPrimitive {
+        mean(&self.to_f64_vec())
+    }
+
+    /// Returns the median of the elements
+    ///
+    /// # Remarks
+    ///
+    /// * With an even number of elements it's the mean of the two middle ones
+    /// * Returns `NaN` if the vector is empty or contains a `NaN`
+    /// * Panics if an element can't be converted to `f64`
+    #[inline]
+    pub fn median(&self) -> f64 where N: ToPrimitive {
+        quantile(self.to_f64_vec(), 0.5, Interpolation::Linear)
+    }
+
+    /// Returns the population variance of the elements (divided by `n`)
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `NaN` if the vector is empty
+    /// * Panics if an element can't be converted to `f64`
+    #[inline]
+    pub fn variance(&self) -> f64 where N: ToPrimitive {
+        variance(&self.to_f64_vec(), 0)
+    }
+
+    /// Returns the sample variance of the elements (divided by `n - 1`)
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `NaN` if the vector has less than two elements
+    /// * Panics if an element can't be converted to `f64`
+    #[inline]
+    pub fn sample_variance(&self) -> f64 where N: ToPrimitive {
+        if self.size() < 2 {
+            return f64::NAN;
+        }
+        variance(&self.to_f64_vec(), 1)
+    }
+
+    /// Returns the population standard deviation of the elements
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `NaN` if the vector is empty
+    /// * Panics if an element can't be converted to `f64`
+    #[inline]
+    pub fn std_dev(&self) -> f64 where N: ToPrimitive {
+        self.variance().sqrt()
+    }
+
+    /// Returns the sample standard deviation of the elements
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `NaN` if the vector has less than two elements
+    /// * Panics if an element can't be converted to `f64`
+    #[inline]
+    pub fn sample_std_dev(&self) -> f64 where N: ToPrimitive {
+        self.sample_variance().sqrt()
+    }
+
+    /// Returns the most frequent element
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `None` if the vector is empty
+    /// * On ties the smallest of the most frequent elements is returned
+    /// * Elements that are not comparable with themselves (`NaN`) are skipped
+    #[inline]
+    pub fn mode(&self) -> Option<N> where N: PartialOrd {
+        let mut sorted: Vec<N> = self.as_slice().iter()
+                                                .cloned()
+                                                .filter(|el| el.partial_cmp(el).is_some())
+                                                .collect();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+
+        let mut best: Option<(N, usize)> = None;
+        let mut start = 0;
+        while start < sorted.len() {
+            let end = start + sorted[start..].iter().take_while(|el| **el == sorted[start]).count();
+            let better = match best {
+                Some((_, count)) => end - start > count,
+                None => true,
+            };
+            if better {
+                best = Some((sorted[start], end - start));
+            }
+            start = end;
+        }
+        best.map(|(el, _)| el)
+    }
+
+    /// Returns a quantile of the elements
+    ///
+    /// # Arguments
+    ///
+    /// * `q`: quantile to compute, between `0` and `1`
+    /// * `interpolation`: method used between two elements (see `Interpolation`)
+    ///
+    /// # Remarks
+    ///
+    /// * Panics if the arguments are not valid or an element can't be
+    ///   converted (see `try_quantile`)
+    #[inline]
+    pub fn quantile(&self, q: f64, interpolation: Interpolation) -> f64 where N: ToPrimitive {
+        match self.try_quantile(q, interpolation) {
+            Ok(v) => v,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Returns a quantile of the elements
+    ///
+    /// # Arguments
+    ///
+    /// * `q`: quantile to compute, between `0` and `1`
+    /// * `interpolation`: method used between two elements (see `Interpolation`)
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `NaN` if the vector contains a `NaN`
+    /// * Returns `Error::InvalidArgument` if `q` is not inside `[0, 1]`
+    /// * Returns `Error::InsufficientData` if the vector is empty
+    /// * Returns `Error::NotRepresentable` if an element can't be converted
+    ///   to `f64`
+    #[inline]
+    pub fn try_quantile(&self, q: f64, interpolation: Interpolation) -> Result<f64, Error> where N: ToPrimitive {
+        if !(0.0..=1.0).contains(&q) {
+            return Err(Error::InvalidArgument { name: "q" });
+        }
+        if self.size() == 0 {
+            return Err(Error::InsufficientData { required: 1, found: 0 });
+        }
+        Ok(quantile(to_f64_vec(self.as_slice())?, q, interpolation))
+    }
+
+    /// Returns the skewness of the elements
+    ///
+    /// # Remarks
+    ///
+    /// * It's the population (Fisher-Pearson) coefficient `m3 / m2^(3/2)`,
+    ///   with `mk` the k-th central moment
+    /// * Returns `NaN` if the vector is empty or all the elements are equal
+    /// * Panics if an element can't be converted to `f64`
+    #[inline]
+    pub fn skewness(&self) -> f64 where N: ToPrimitive {
+        let values = self.to_f64_vec();
+        let m2 = central_moment(&values, 2);
+        central_moment(&values, 3) / (m2 * m2.sqrt())
+    }
+
+    /// Returns the excess kurtosis of the elements
+    ///
+    /// # Remarks
+    ///
+    /// * It's the population coefficient `m4 / m2^2 - 3`, with `mk` the k-th
+    ///   central moment, so it's zero for a normal distribution
+    /// * Returns `NaN` if the vector is empty or all the elements are equal
+    /// * Panics if an element can't be converted to `f64`
+    #[inline]
+    pub fn kurtosis(&self) -> f64 where N: ToPrimitive {
+        let values = self.to_f64_vec();
+        let m2 = central_moment(&values, 2);
+        central_moment(&values, 4) / (m2 * m2) - 3.0
+    }
+
+    // internal use: elements converted to f64, panicking like the wrappers
+    // of the try_* methods
+    pub(crate) fn to_f64_vec(&self) -> Vec<f64> where N: ToPrimitive {
+        match to_f64_vec(self.as_slice()) {
+            Ok(values) => values,
+            Err(e) => panic!("{}", e),
+        }
+    }
+}
+
+impl RunningStats {
+
+    /// Initializes an empty accumulator
+    #[inline]
+    pub fn new() -> RunningStats {
+        RunningStats { count: 0, mean: 0.0, m2: 0.0, min: f64::NAN, max: f64::NAN }
+    }
+
+    /// Adds a value to the accumulator
+    ///
+    /// # Arguments
+    ///
+    /// * `value`: new value of the sequence
+    ///
+    /// # Remarks
+    ///
+    /// * Panics if the value can't be converted (see `try_push`)
+    #[inline]
+    pub fn push<N: ToPrimitive>(&mut self, value: N) {
+        if let Err(e) = self.try_push(value) {
+            panic!("{}", e);
+        }
+    }
+
+    /// Adds a value to the accumulator
+    ///
+    /// # Arguments
+    ///
+    /// * `value`: new value of the sequence
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::NotRepresentable` if the value can't be converted
+    ///   to `f64`, with the number of values accumulated before it as index;
+    ///   the accumulator is left unchanged
+    #[inline]
+    pub fn try_push<N: ToPrimitive>(&mut self, value: N) -> Result<(), Error> {
+        let x = value.to_f64().ok_or(Error::NotRepresentable { index: self.count })?;
+        self.count += 1;
+        let delta = x - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (x - self.mean);
+        if self.count == 1 {
+            self.min = x;
+            self.max = x;
+        } else {
+            self.min = self.min.min(x);
+            self.max = self.max.max(x);
+        }
+        Ok(())
+    }
+
+    /// Adds the values accumulated by another accumulator
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: accumulator of another part of the sequence
+    #[inline]
+    pub fn merge(&mut self, other: &RunningStats) {
+        if other.count == 0 {
+            return;
+        }
+        if self.count == 0 {
+            *self = *other;
+            return;
+        }
+        let count = self.count + other.count;
+        let delta = other.mean - self.mean;
+        self.mean += delta * other.count as f64 / count as f64;
+        self.m2 += other.m2 + delta * delta * (self.count * other.count) as f64 / count as f64;
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+        self.count = count;
+    }
+
+    /// Returns the number of accumulated values
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the mean of the values, `NaN` if there are none
+    #[inline]
+    pub fn mean(&self) -> f64 {
+        if self.count == 0 {
+            return f64::NAN;
+        }
+        self.mean
+    }
+
+    /// Returns the population variance of the values, `NaN` if there are none
+    #[inline]
+    pub fn variance(&self) -> f64 {
+        self.m2 / self.count as f64
+    }
+
+    /// Returns the sample variance of the values, `NaN` if there are less than two
+    #[inline]
+    pub fn sample_variance(&self) -> f64 {
+        if self.count < 2 {
+            return f64::NAN;
+        }
+        self.m2 / (self.count - 1) as f64
+    }
+
+    /// Returns the population standard deviation of the values
+    #[inline]
+    pub fn std_dev(&self) -> f64 {
+        self.variance().sqrt()
+    }
+
+    /// Returns the sample standard deviation of the values
+    #[inline]
+    pub fn sample_std_dev(&self) -> f64 {
+        self.sample_variance().sqrt()
+    }
+
+    /// Returns the minimal value, `NaN` if there are none
+    #[inline]
+    pub fn min(&self) -> f64 {
+        self.min
+    }
+
+    /// Returns the maximal value, `NaN` if there are none
+    #[inline]
+    pub fn max(&self) -> f64 {
+        self.max
+    }
+}
+
+// internal use: elements converted to f64, failing on the first one that
+// can't be represented
+pub(crate) fn to_f64_vec<N: Copy + ToPrimitive>(values: &[N]) -> Result<Vec<f64>, Error> {
+    values.iter()
+          .enumerate()
+          .map(|(index, el)| el.to_f64().ok_or(Error::NotRepresentable { index }))
+          .collect()
+}
+
+// internal use: mean of a line of elements
+fn mean(line: &[f64]) -> f64 {
+    line.iter().sum::<f64>() / line.len() as f64
+}
+
+// internal use: variance with two passes, more stable than the sum of squares
+fn variance(line: &[f64], ddof: usize) -> f64 {
+    let m = mean(line);
+    line.iter().map(|el| (el - m) * (el - m)).sum::<f64>() / (line.len() - ddof) as f64
+}
+
+// internal use: k-th moment around the mean
+fn central_moment(values: &[f64], k: i32) -> f64 {
+    let m = mean(values);
+    values.iter().map(|el| (el - m).powi(k)).sum::<f64>() / values.len() as f64
+}
+
+// internal use: quantile `q` in `[0, 1]` of a line of elements
+fn quantile(mut line: Vec<f64>, q: f64, interpolation: Interpolation) -> f64 {
+    if line.is_empty() || line.iter().any(|el| el.is_nan()) {
+        return f64::NAN;
+    }
+    line.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+
+    let rank = q * (line.len() - 1) as f64;
+    let (lower, higher) = (line[rank.floor() as usize], line[rank.ceil() as usize]);
+    match interpolation {
+        Interpolation::Linear => lower + (higher - lower) * (rank - rank.floor()),
+        Interpolation::Lower => lower,
+        Interpolation::Higher => higher,
+        Interpolation::Nearest => line[rank.round() as usize],
+        Interpolation::Midpoint => (lower + higher) / 2.0,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Traits
+////////////////////////////////////////////////////////////////////////////////
+
+/// Default implementation for RunningStats, an empty accumulator
+impl Default for RunningStats {
+    fn default() -> RunningStats {
+        RunningStats::new()
+    }
+}
@@ -0,0 +1,379 @@
+use std::cmp::Ordering;
+use num::{Float, NumCast};
+use algebra::matrix::Matrix;
+use algebra::view::MatrixView;
+use algebra::vector::Vector;
+use error::Error;
+
+/// Thin singular value decomposition of a `m x n` matrix computed with the
+/// one-sided Jacobi method, so that `A = U * S * V'`
+///
+/// # Remarks
+///
+/// * With `k = min(m, n)`, `U` is a `m x k` matrix with orthonormal columns,
+///   `S` the diagonal matrix of the `k` singular values, sorted in descending
+///   order, and `V'` a `k x n` matrix with orthonormal rows.
+/// * This struct is created by `Matrix::svd` and is just available for float types.
+pub struct SVD<N: Copy> {
+    u: Vec<N>,
+    s: Vec<N>,
+    vt: Vec<N>,
+    nrows: usize,
+    ncols: usize,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Inherent methods
+////////////////////////////////////////////////////////////////////////////////
+
+impl<N: Copy + Float> Matrix<N> {
+
+    /// Returns the singular value decomposition of the matrix
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::NoConvergence` if the columns are not orthogonalized
+    ///   after 60 sweeps
+    #[inline]
+    pub fn svd(&self) -> Result<SVD<N>, Error> {
+        let (m, n) = (self.nrows(), self.ncols());
+        if m >= n {
+            // the columns of A are orthogonalized, A = U * S * V'
+            let mut at = self.clone();
+            at.transpose();
+            let (ut, s, vt) = jacobi(at.into_vec(), n, m)?;
+            Ok(SVD { u: transpose(&ut, n, m), s, vt, nrows: m, ncols: n })
+        } else {
+            // the columns of A' are orthogonalized, A' = U * S * V' so A = V * S * U'
+            let (ut, s, vt) = jacobi(self.as_slice().to_vec(), m, n)?;
+            Ok(SVD { u: transpose(&vt, m, m), s, vt: ut, nrows: m, ncols: n })
+        }
+    }
+
+    /// Returns the Moore-Penrose pseudo-inverse of the matrix, computed with its SVD
+    ///
+    /// # Remarks
+    ///
+    /// * Singular values not bigger than `max(m, n) * epsilon * max(s)` are considered zero
+    #[inline]
+    pub fn pinv(&self) -> Result<Matrix<N>, Error> {
+        Ok(self.svd()?.pinv())
+    }
+
+    /// Returns the rank of the matrix, computed with its SVD
+    ///
+    /// # Arguments
+    ///
+    /// * `tol`: singular values not bigger than `tol` are considered zero
+    #[inline]
+    pub fn rank(&self, tol: N) -> Result<usize, Error> {
+        Ok(self.svd()?.rank(tol))
+    }
+
+    /// Returns the condition number of the matrix in the 2-norm, computed with its SVD
+    ///
+    /// # Remarks
+    ///
+    /// * The condition number of a rank deficient matrix is infinite
+    #[inline]
+    pub fn condition_number(&self) -> Result<N, Error> {
+        Ok(self.svd()?.condition_number())
+    }
+
+    /// Returns an orthonormal basis of the null space of the matrix, one vector
+    /// per column, computed with its SVD
+    ///
+    /// # Remarks
+    ///
+    /// * Singular values not bigger than `max(m, n) * epsilon * max(s)` are considered zero
+    /// * The result has `n - rank` columns
+    #[inline]
+    pub fn nullspace(&self) -> Result<Matrix<N>, Error> {
+        Ok(self.svd()?.nullspace())
+    }
+}
+
+impl<'a, N: Copy + Float> MatrixView<'a, N> {
+
+    /// Returns the singular value decomposition of the view (see `Matrix::svd`)
+    #[inline]
+    pub fn svd(&self) -> Result<SVD<N>, Error> {
+        self.to_matrix().svd()
+    }
+
+    /// Returns the pseudo-inverse of the view (see `Matrix::pinv`)
+    #[inline]
+    pub fn pinv(&self) -> Result<Matrix<N>, Error> {
+        self.to_matrix().pinv()
+    }
+
+    /// Returns the rank of the view (see `Matrix::rank`)
+    #[inline]
+    pub fn rank(&self, tol: N) -> Result<usize, Error> {
+        self.to_matrix().rank(tol)
+    }
+
+    /// Returns the condition number of the view (see `Matrix::condition_number`)
+    #[inline]
+    pub fn condition_number(&self) -> Result<N, Error> {
+        self.to_matrix().condition_number()
+    }
+
+    /// Returns an orthonormal basis of the null space of the view (see `Matrix::nullspace`)
+    #[inline]
+    pub fn nullspace(&self) -> Result<Matrix<N>, Error> {
+        self.to_matrix().nullspace()
+    }
+}
+
+impl<N: Copy + Float> SVD<N> {
+
+    /// Returns the left singular vectors `U` (`m x min(m, n)`)
+    #[inline]
+    pub fn u(&self) -> Matrix<N> {
+        Matrix::<N>::from_vec(self.nrows, self.s.len(), self.u.clone())
+    }
+
+    /// Returns the singular values in descending order
+    #[inline]
+    pub fn singular_values(&self) -> Vector<N> {
+        Vector::<N>::from_vec(self.s.clone())
+    }
+
+    /// Returns the transposed right singular vectors `V'` (`min(m, n) x n`)
+    #[inline]
+    pub fn vt(&self) -> Matrix<N> {
+        Matrix::<N>::from_vec(self.s.len(), self.ncols, self.vt.clone())
+    }
+
+    /// Returns the number of singular values bigger than `tol`
+    #[inline]
+    pub fn rank(&self, tol: N) -> usize {
+        self.s.iter().filter(|s| **s > tol).count()
+    }
+
+    /// Returns the condition number in the 2-norm, `max(s) / min(s)`
+    ///
+    /// # Remarks
+    ///
+    /// * The condition number of a rank deficient matrix is infinite
+    #[inline]
+    pub fn condition_number(&self) -> N {
+        match self.s.last() {
+            Some(min) if *min > N::zero() => self.s[0] / *min,
+            _ => N::infinity(),
+        }
+    }
+
+    /// Returns the Moore-Penrose pseudo-inverse of the decomposed matrix (`n x m`)
+    ///
+    /// # Remarks
+    ///
+    /// * Singular values not bigger than `max(m, n) * epsilon * max(s)` are considered zero
+    #[inline]
+    pub fn pinv(&self) -> Matrix<N> {
+        let (m, n, k) = (self.nrows, self.ncols, self.s.len());
+        let rank = self.rank(self.default_tol());
+        let mut values: Vec<N> = vec![N::zero(); n * m];
+        for l in 0..rank {
+            let inv = N::one() / self.s[l];
+            for i in 0..n {
+                let factor = self.vt[l * n + i] * inv;
+                for j in 0..m {
+                    values[i * m + j] = values[i * m + j] + factor * self.u[j * k + l];
+                }
+            }
+        }
+        Matrix::<N>::from_vec(n, m, values)
+    }
+
+    /// Returns an orthonormal basis of the null space of the decomposed matrix,
+    /// one vector per column
+    ///
+    /// # Remarks
+    ///
+    /// * Singular values not bigger than `max(m, n) * epsilon * max(s)` are considered zero
+    /// * The result has `n - rank` columns
+    #[inline]
+    pub fn nullspace(&self) -> Matrix<N> {
+        let n = self.ncols;
+        let rank = self.rank(self.default_tol());
+        let basis = complement(&self.vt[..rank * n], rank, n, n - rank);
+        Matrix::<N>::from_vec(n, n - rank, transpose(&basis, n - rank, n))
+    }
+
+    /// Returns the minimum norm least squares solution `X` that minimizes
+    /// `||A * X - B||` for one or many right-hand sides
+    ///
+    /// # Arguments
+    ///
+    /// * `b`: right-hand sides, one per column
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::DimensionMismatch` if `b` hasn't as many rows as `A`
+    /// * Rank deficient and underdetermined systems are solved too
+    #[inline]
+    pub fn solve(&self, b: &Matrix<N>) -> Result<Matrix<N>, Error> {
+        let (m, n) = (self.nrows, self.ncols);
+        if b.nrows() != m {
+            return Err(Error::DimensionMismatch { expected: (m, b.ncols()), found: (b.nrows(), b.ncols()) });
+        }
+
+        let nx = b.ncols();
+        let pinv = self.pinv().into_vec();
+        let mut x: Vec<N> = vec![N::zero(); n * nx];
+        for i in 0..n {
+            for k in 0..m {
+                let factor = pinv[i * m + k];
+                for j in 0..nx {
+                    x[i * nx + j] = x[i * nx + j] + factor * b.as_slice()[k * nx + j];
+                }
+            }
+        }
+        Ok(Matrix::<N>::from_vec(n, nx, x))
+    }
+
+    /// Returns the minimum norm least squares solution `x` that minimizes `||A * x - b||`
+    ///
+    /// # Arguments
+    ///
+    /// * `b`: right-hand side
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::DimensionMismatch` if `b` hasn't as many elements as rows in `A`
+    /// * Rank deficient and underdetermined systems are solved too
+    #[inline]
+    pub fn solve_vector(&self, b: &Vector<N>) -> Result<Vector<N>, Error> {
+        let x = self.solve(&Matrix::<N>::from_vec(b.size(), 1, b.as_slice().to_vec()))?;
+        Ok(Vector::<N>::from_vec(x.into_vec()))
+    }
+
+    // internal use: tolerance under which singular values are considered zero
+    fn default_tol(&self) -> N {
+        let max = self.s.first().cloned().unwrap_or_else(N::zero);
+        N::epsilon() * <N as NumCast>::from(self.nrows.max(self.ncols)).unwrap() * max
+    }
+}
+
+// `U'`, `S` and `V'` buffers of a decomposition
+type Factors<N> = (Vec<N>, Vec<N>, Vec<N>);
+
+// orthogonalizes the `k` rows of length `len` (`k <= len`) of `w` with plane
+// rotations. Returns the normalized rows (`U'`), their norms (`S`) and the
+// accumulated rotations (`V'`), sorted by descending norm.
+fn jacobi<N: Copy + Float>(mut w: Vec<N>, k: usize, len: usize) -> Result<Factors<N>, Error> {
+    let max_sweeps = 60;
+    let mut vt: Vec<N> = Matrix::<N>::create_identity(k).into_vec();
+    let dot = |w: &[N], p: usize, q: usize| {
+        (0..len).fold(N::zero(), |sum, i| sum + w[p * len + i] * w[q * len + i])
+    };
+    // rows with a squared norm under the rounding error of A are considered zero
+    let frobenius = w.iter().fold(N::zero(), |sum, el| sum + *el * *el);
+    let negligible = N::epsilon() * N::epsilon() * frobenius;
+
+    let mut sweeps = 0;
+    loop {
+        let mut rotated = false;
+        for p in 0..k {
+            for q in (p + 1)..k {
+                let (alpha, beta, gamma) = (dot(&w, p, p), dot(&w, q, q), dot(&w, p, q));
+                if alpha <= negligible || beta <= negligible
+                    || gamma.abs() <= N::epsilon() * (alpha * beta).sqrt() {
+                    continue;
+                }
+                rotated = true;
+
+                // rotation that makes the rows p and q orthogonal
+                let two = N::one() + N::one();
+                let zeta = (beta - alpha) / (two * gamma);
+                let t = zeta.signum() / (zeta.abs() + (zeta * zeta + N::one()).sqrt());
+                let c = N::one() / (t * t + N::one()).sqrt();
+                let s = t * c;
+                rotate(&mut w, len, p, q, c, s);
+                rotate(&mut vt, k, p, q, c, s);
+            }
+        }
+
+        if !rotated {
+            break;
+        }
+        sweeps += 1;
+        if sweeps == max_sweeps {
+            return Err(Error::NoConvergence { iterations: max_sweeps });
+        }
+    }
+
+    // sort by descending norm, normalize the rows and complete the zero ones
+    let norms: Vec<N> = (0..k).map(|p| {
+        let norm = dot(&w, p, p);
+        if norm <= negligible { N::zero() } else { norm.sqrt() }
+    }).collect();
+    let mut order: Vec<usize> = (0..k).collect();
+    order.sort_by(|&i, &j| norms[j].partial_cmp(&norms[i]).unwrap_or(Ordering::Equal));
+
+    let s: Vec<N> = order.iter().map(|&p| norms[p]).collect();
+    let nonzero = s.iter().filter(|s| **s > N::zero()).count();
+    let mut ut: Vec<N> = Vec::with_capacity(k * len);
+    for &p in order.iter().take(nonzero) {
+        ut.extend(w[p * len..(p + 1) * len].iter().map(|el| *el / norms[p]));
+    }
+    let completion = complement(&ut, nonzero, len, k - nonzero);
+    ut.extend(completion);
+
+    let mut sorted_vt: Vec<N> = Vec::with_capacity(k * k);
+    for &p in order.iter() {
+        sorted_vt.extend_from_slice(&vt[p * k..(p + 1) * k]);
+    }
+    Ok((ut, s, sorted_vt))
+}
+
+// rotates the rows `p` and `q` (of length `len`) of `w`
+fn rotate<N: Copy + Float>(w: &mut [N], len: usize, p: usize, q: usize, c: N, s: N) {
+    for i in 0..len {
+        let (wp, wq) = (w[p * len + i], w[q * len + i]);
+        w[p * len + i] = c * wp - s * wq;
+        w[q * len + i] = s * wp + c * wq;
+    }
+}
+
+// returns `needed` orthonormal rows of length `len` that are orthogonal to the
+// `count` orthonormal rows of `basis`, using Gram-Schmidt on the canonical basis
+fn complement<N: Copy + Float>(basis: &[N], count: usize, len: usize, needed: usize) -> Vec<N> {
+    let mut rows: Vec<N> = basis[..count * len].to_vec();
+
+    for _ in 0..needed {
+        // the canonical vector with the biggest component out of the current rows
+        let mut best: (N, Vec<N>) = (N::zero(), Vec::new());
+        for e in 0..len {
+            let mut candidate: Vec<N> = vec![N::zero(); len];
+            candidate[e] = N::one();
+            // orthogonalize twice to keep the rounding error small
+            for _ in 0..2 {
+                for row in rows.chunks(len) {
+                    let proj = row.iter().zip(candidate.iter()).fold(N::zero(), |sum, (r, c)| sum + *r * *c);
+                    for (c, r) in candidate.iter_mut().zip(row.iter()) {
+                        *c = *c - proj * *r;
+                    }
+                }
+            }
+
+            let norm = candidate.iter().fold(N::zero(), |sum, el| sum + *el * *el).sqrt();
+            if norm > best.0 {
+                best = (norm, candidate);
+            }
+        }
+        rows.extend(best.1.iter().map(|el| *el / best.0));
+    }
+    rows.split_off(count * len)
+}
+
+// transposes a row-major `nrows x ncols` buffer
+fn transpose<N: Copy>(values: &[N], nrows: usize, ncols: usize) -> Vec<N> {
+    let mut t: Vec<N> = Vec::with_capacity(nrows * ncols);
+    for j in 0..ncols {
+        t.extend(values.iter().skip(j).step_by(ncols).take(nrows).cloned());
+    }
+    t
+}
@@ -1,10 +1,18 @@
 use rand;
 use rand::Rng;
-use num::{Num, ToPrimitive};
+use num::{Num, Float, ToPrimitive};
 use std::cmp::Ord;
 use std::fmt;
 use std::fmt::Display;
+use std::iter::FromIterator;
+use std::slice;
+use std::vec;
+use std::ops::{Add, Sub, Mul, Div, Neg, Index, IndexMut};
 use rand::distributions::range::SampleRange;
+use error::Error;
+use algebra::gemm;
+use algebra::matrix::{Matrix, ParallelElement};
+use algebra::stats::to_f64_vec;
 
 /// Vector with a defined number of elements that can
 /// add, remove and edit values.
@@ -68,16 +76,70 @@ impl<N: Copy> Vector<N> {
     ///
     /// * `size`: vector's size
     /// * `range`: values range
+    ///
+    /// # Remarks
+    ///
+    /// * Panics if the range is not ascendent (see `try_random`)
     #[inline]
     pub fn random(size: usize, range: &[N; 2]) -> Vector<N> where N: Num + PartialOrd + SampleRange {
-        if range.len() != 2 {
-                panic!("just permitted range of size 2 (actual={})", range.len());
+        match Vector::<N>::try_random(size, range) {
+            Ok(v) => v,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    ///  Generates a random vector within a range
+    ///
+    /// # Parameters
+    ///
+    /// * `size`: vector's size
+    /// * `range`: values range
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::InvalidRange` if the range is not ascendent
+    #[inline]
+    pub fn try_random(size: usize, range: &[N; 2]) -> Result<Vector<N>, Error>
+        where N: Num + PartialOrd + SampleRange {
+
+        if range[0] > range[1] {
+            return Err(Error::InvalidRange);
         }
         let mut v = Vector::<N>::new();
         for _ in 0..size {
             v.push(rand::thread_rng().gen_range(range[0], range[1] + N::one()));
         }
-        v
+        Ok(v)
+    }
+
+    // internal use: builds a vector from its values
+    #[inline]
+    pub(crate) fn from_vec(values: Vec<N>) -> Vector<N> {
+        Vector::<N> { values }
+    }
+
+    /// Returns the elements of the vector
+    #[inline]
+    pub fn as_slice(&self) -> &[N] {
+        &self.values
+    }
+
+    /// Returns the mutable elements of the vector
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [N] {
+        &mut self.values
+    }
+
+    /// Returns an iterator over references to the elements
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'_, N> {
+        self.values.iter()
+    }
+
+    /// Returns an iterator over mutable references to the elements
+    #[inline]
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, N> {
+        self.values.iter_mut()
     }
 
     /// Returns the size of Vector
@@ -149,12 +211,20 @@ impl<N: Copy> Vector<N> {
     }
 
     /// Orders a vector from the minimum to maximum value
+    ///
+    /// # Remarks
+    ///
+    /// * Needs `Ord` elements, floats can use `sort_min_partial`
     #[inline]
     pub fn sort_min(&mut self) where N: Num + Ord {
         self.values.sort_by(|a, b| a.cmp(b));
     }
 
     /// Orders a vector from the maximum to minimum value
+    ///
+    /// # Remarks
+    ///
+    /// * Needs `Ord` elements, floats can use `sort_max_partial`
     #[inline]
     pub fn sort_max(&mut self) where N: Num + Ord {
         self.values.sort_by(|a, b| b.cmp(a));
@@ -182,6 +252,10 @@ impl<N: Copy> Vector<N> {
     }
 
     /// Returns the maximal element and its index of a Vector
+    ///
+    /// # Remarks
+    ///
+    /// * Needs `Ord` elements, floats can use `partial_max`
     #[inline]
     pub fn max(&self) -> (N, usize) where N: Num + Ord {
         let mut i: usize = 0;
@@ -196,6 +270,10 @@ impl<N: Copy> Vector<N> {
     }
 
     /// Returns the minimal element and its index of a Vector
+    ///
+    /// # Remarks
+    ///
+    /// * Needs `Ord` elements, floats can use `partial_min`
     #[inline]
     pub fn min(&self) -> (N, usize) where N: Num + Ord {
         let mut i: usize = 0;
@@ -209,11 +287,251 @@ impl<N: Copy> Vector<N> {
         (self.values[i], i)
     }
 
-    /// Returns the median value of the Vector's elements value
+    /// Returns the product of the vector, as a row vector, with a matrix
+    ///
+    /// # Arguments
+    ///
+    /// * `matrix`: right-hand side of the product
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::DimensionMismatch` if the matrix hasn't as many rows
+    ///   as elements in the vector
+    #[inline]
+    pub fn try_mul_matrix(&self, matrix: &Matrix<N>) -> Result<Vector<N>, Error> where N: Num + ParallelElement {
+        if self.size() != matrix.nrows() {
+            return Err(Error::DimensionMismatch { expected: (self.size(), matrix.ncols()),
+                                                  found: (matrix.nrows(), matrix.ncols()) });
+        }
+
+        let mut values: Vec<N> = vec![N::zero(); matrix.ncols()];
+        gemm::gemm(N::one(), &self.values, matrix.as_slice(), N::zero(), &mut values,
+                   self.size(), matrix.ncols());
+        Ok(Vector::<N>::from_vec(values))
+    }
+
+    /// Returns the addition of two vectors
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: vector to add
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::DimensionMismatch` if the sizes are not equal
     #[inline]
-    pub fn median(&self) -> f64 where N: Num + Default + ToPrimitive {
-        let sum = self.values.iter().fold(N::zero(), |sum, &el| sum + el).to_f64().unwrap();
-        (sum / self.values.len() as f64)
+    pub fn try_add(&self, other: &Vector<N>) -> Result<Vector<N>, Error> where N: Num {
+        self.check_same_size(other)?;
+        Ok(Vector::<N>::from_vec(self.values.iter().zip(other.values.iter()).map(|(a, b)| *a + *b).collect()))
+    }
+
+    /// Returns the substraction of two vectors
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: vector to substract
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::DimensionMismatch` if the sizes are not equal
+    #[inline]
+    pub fn try_sub(&self, other: &Vector<N>) -> Result<Vector<N>, Error> where N: Num {
+        self.check_same_size(other)?;
+        Ok(Vector::<N>::from_vec(self.values.iter().zip(other.values.iter()).map(|(a, b)| *a - *b).collect()))
+    }
+
+    /// Returns the dot product of two vectors
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: right-hand side of the product
+    ///
+    /// # Remarks
+    ///
+    /// * Panics if the sizes are not equal (see `try_dot`)
+    #[inline]
+    pub fn dot(&self, other: &Vector<N>) -> N where N: Num {
+        match self.try_dot(other) {
+            Ok(d) => d,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Returns the dot product of two vectors
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: right-hand side of the product
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::DimensionMismatch` if the sizes are not equal
+    #[inline]
+    pub fn try_dot(&self, other: &Vector<N>) -> Result<N, Error> where N: Num {
+        self.check_same_size(other)?;
+        Ok(self.values.iter().zip(other.values.iter()).fold(N::zero(), |acc, (a, b)| acc + *a * *b))
+    }
+
+    /// Returns the L1 norm: the sum of the absolute values
+    ///
+    /// # Remarks
+    ///
+    /// * Panics if an element can't be converted to `f64`
+    #[inline]
+    pub fn norm_l1(&self) -> f64 where N: ToPrimitive {
+        self.to_f64_vec().iter().map(|el| el.abs()).sum()
+    }
+
+    /// Returns the L2 (Euclidean) norm: the square root of the sum of squares
+    ///
+    /// # Remarks
+    ///
+    /// * Panics if an element can't be converted to `f64`
+    #[inline]
+    pub fn norm_l2(&self) -> f64 where N: ToPrimitive {
+        self.to_f64_vec().iter().map(|el| el.powi(2)).sum::<f64>().sqrt()
+    }
+
+    /// Returns the infinity norm: the maximal absolute value
+    ///
+    /// # Remarks
+    ///
+    /// * The norm of an empty vector is zero
+    /// * Panics if an element can't be converted to `f64`
+    #[inline]
+    pub fn norm_inf(&self) -> f64 where N: ToPrimitive {
+        self.to_f64_vec().iter().map(|el| el.abs()).fold(0.0, f64::max)
+    }
+
+    /// Returns the vector divided by its L2 norm
+    ///
+    /// # Remarks
+    ///
+    /// * The elements of the normalized zero vector are `NaN`
+    #[inline]
+    pub fn normalize(&self) -> Vector<N> where N: Float {
+        let norm = self.dot(self).sqrt();
+        self / norm
+    }
+
+    /// Returns the Euclidean distance between two vectors
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: vector to measure the distance to
+    ///
+    /// # Remarks
+    ///
+    /// * Panics if the sizes are not equal or an element can't be converted
+    ///   (see `try_distance`)
+    #[inline]
+    pub fn distance(&self, other: &Vector<N>) -> f64 where N: ToPrimitive {
+        match self.try_distance(other) {
+            Ok(d) => d,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Returns the Euclidean distance between two vectors
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: vector to measure the distance to
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::DimensionMismatch` if the sizes are not equal
+    /// * Returns `Error::NotRepresentable` if an element can't be converted
+    ///   to `f64`, with its index in its vector
+    #[inline]
+    pub fn try_distance(&self, other: &Vector<N>) -> Result<f64, Error> where N: ToPrimitive {
+        self.check_same_size(other)?;
+        let (a, b) = (to_f64_vec(&self.values)?, to_f64_vec(&other.values)?);
+        Ok(a.iter()
+            .zip(b.iter())
+            .map(|(a, b)| (a - b).powi(2))
+            .sum::<f64>()
+            .sqrt())
+    }
+
+    /// Returns the cosine of the angle between two vectors
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: vector to compare
+    ///
+    /// # Remarks
+    ///
+    /// * Panics if the sizes are not equal or an element can't be converted
+    ///   (see `try_cosine_similarity`)
+    #[inline]
+    pub fn cosine_similarity(&self, other: &Vector<N>) -> f64 where N: ToPrimitive {
+        match self.try_cosine_similarity(other) {
+            Ok(c) => c,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Returns the cosine of the angle between two vectors
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: vector to compare
+    ///
+    /// # Remarks
+    ///
+    /// * It's `NaN` if one of the vectors is zero
+    /// * Returns `Error::DimensionMismatch` if the sizes are not equal
+    /// * Returns `Error::NotRepresentable` if an element can't be converted
+    ///   to `f64`, with its index in its vector
+    #[inline]
+    pub fn try_cosine_similarity(&self, other: &Vector<N>) -> Result<f64, Error> where N: ToPrimitive {
+        self.check_same_size(other)?;
+        let (a, b) = (to_f64_vec(&self.values)?, to_f64_vec(&other.values)?);
+        let dot: f64 = a.iter().zip(b.iter()).map(|(a, b)| a * b).sum();
+        let norm = |v: &[f64]| v.iter().map(|el| el * el).sum::<f64>().sqrt();
+        Ok(dot / (norm(&a) * norm(&b)))
+    }
+
+    /// Returns the outer product of two vectors: the matrix with the element
+    /// `(i, j)` equal to `self[i] * other[j]`
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: right-hand side of the product
+    #[inline]
+    pub fn outer(&self, other: &Vector<N>) -> Matrix<N> where N: Num {
+        let mut values: Vec<N> = Vec::with_capacity(self.size() * other.size());
+        for a in self.values.iter() {
+            values.extend(other.values.iter().map(|b| *a * *b));
+        }
+        Matrix::<N>::from_vec(self.size(), other.size(), values)
+    }
+
+    /// Returns the cumulative sums of the elements
+    #[inline]
+    pub fn cumsum(&self) -> Vector<N> where N: Num {
+        let values: Vec<N> = self.values.iter()
+                                        .scan(N::zero(), |acc, el| { *acc = *acc + *el; Some(*acc) })
+                                        .collect();
+        Vector::<N>::from_vec(values)
+    }
+
+    /// Returns the cumulative products of the elements
+    #[inline]
+    pub fn cumprod(&self) -> Vector<N> where N: Num {
+        let values: Vec<N> = self.values.iter()
+                                        .scan(N::one(), |acc, el| { *acc = *acc * *el; Some(*acc) })
+                                        .collect();
+        Vector::<N>::from_vec(values)
+    }
+
+    // internal use: checks that two vectors have the same size
+    fn check_same_size(&self, other: &Vector<N>) -> Result<(), Error> {
+        if self.size() != other.size() {
+            return Err(Error::DimensionMismatch { expected: (self.size(), 1), found: (other.size(), 1) });
+        }
+        Ok(())
     }
 }
 
@@ -227,3 +545,187 @@ impl<N: Copy> Display for Vector<N> where N: Display {
         writeln!(f, "]")
     }
 }
+
+/// Indexing `v[i]` implementation for Vector
+impl<N: Copy> Index<usize> for Vector<N> {
+    type Output = N;
+
+    fn index(&self, idx: usize) -> &N {
+        &self.values[idx]
+    }
+}
+
+/// Mutable indexing `v[i] = val` implementation for Vector
+impl<N: Copy> IndexMut<usize> for Vector<N> {
+    fn index_mut(&mut self, idx: usize) -> &mut N {
+        &mut self.values[idx]
+    }
+}
+
+/// Addition `+` implementation for &Vector
+impl<'a, N: Copy> Add for &'a Vector<N> where N: Num {
+    type Output = Vector<N>;
+
+    fn add(self, other: &'a Vector<N>) -> Vector<N> {
+        match self.try_add(other) {
+            Ok(v) => v,
+            Err(e) => panic!("{}", e),
+        }
+    }
+}
+
+/// Addition `+` implementation for Vector
+impl<N: Copy> Add for Vector<N> where N: Num {
+    type Output = Vector<N>;
+
+    fn add(self, other: Vector<N>) -> Vector<N> {
+        &self + &other
+    }
+}
+
+/// Substraction `-` implementation for &Vector
+impl<'a, N: Copy> Sub for &'a Vector<N> where N: Num {
+    type Output = Vector<N>;
+
+    fn sub(self, other: &'a Vector<N>) -> Vector<N> {
+        match self.try_sub(other) {
+            Ok(v) => v,
+            Err(e) => panic!("{}", e),
+        }
+    }
+}
+
+/// Substraction `-` implementation for Vector
+impl<N: Copy> Sub for Vector<N> where N: Num {
+    type Output = Vector<N>;
+
+    fn sub(self, other: Vector<N>) -> Vector<N> {
+        &self - &other
+    }
+}
+
+/// Multiplication `*` of every element of a &Vector by an scalar
+impl<N: Copy> Mul<N> for &Vector<N> where N: Num {
+    type Output = Vector<N>;
+
+    fn mul(self, scalar: N) -> Vector<N> {
+        Vector::<N>::from_vec(self.values.iter().map(|el| *el * scalar).collect())
+    }
+}
+
+/// Multiplication `*` of every element of a Vector by an scalar
+impl<N: Copy> Mul<N> for Vector<N> where N: Num {
+    type Output = Vector<N>;
+
+    fn mul(self, scalar: N) -> Vector<N> {
+        &self * scalar
+    }
+}
+
+/// Division `/` of every element of a &Vector by an scalar
+impl<N: Copy> Div<N> for &Vector<N> where N: Num {
+    type Output = Vector<N>;
+
+    fn div(self, scalar: N) -> Vector<N> {
+        Vector::<N>::from_vec(self.values.iter().map(|el| *el / scalar).collect())
+    }
+}
+
+/// Division `/` of every element of a Vector by an scalar
+impl<N: Copy> Div<N> for Vector<N> where N: Num {
+    type Output = Vector<N>;
+
+    fn div(self, scalar: N) -> Vector<N> {
+        &self / scalar
+    }
+}
+
+/// Negation `-` implementation for &Vector
+impl<N: Copy> Neg for &Vector<N> where N: Neg<Output = N> {
+    type Output = Vector<N>;
+
+    fn neg(self) -> Vector<N> {
+        Vector::<N>::from_vec(self.values.iter().map(|el| -*el).collect())
+    }
+}
+
+/// Negation `-` implementation for Vector
+impl<N: Copy> Neg for Vector<N> where N: Neg<Output = N> {
+    type Output = Vector<N>;
+
+    fn neg(self) -> Vector<N> {
+        -&self
+    }
+}
+
+/// Multiplication `*` implementation of a row &Vector<N> with &Matrix<N>
+impl<'a, N: Copy> Mul<&'a Matrix<N>> for &'a Vector<N> where N: Num + ParallelElement {
+    type Output = Vector<N>;
+
+    fn mul(self, other: &'a Matrix<N>) -> Vector<N> {
+        match self.try_mul_matrix(other) {
+            Ok(v) => v,
+            Err(e) => panic!("{}", e),
+        }
+    }
+}
+
+/// Multiplication `*` implementation of a row Vector<N> with Matrix<N>
+impl<N: Copy> Mul<Matrix<N>> for Vector<N> where N: Num + ParallelElement {
+    type Output = Vector<N>;
+
+    fn mul(self, other: Matrix<N>) -> Vector<N> {
+        &self * &other
+    }
+}
+
+/// FromIterator implementation for Vector, to `collect` the elements of an iterator
+impl<N: Copy> FromIterator<N> for Vector<N> {
+    fn from_iter<I: IntoIterator<Item = N>>(iter: I) -> Vector<N> {
+        Vector::<N>::from_vec(iter.into_iter().collect())
+    }
+}
+
+/// IntoIterator implementation for Vector, consuming the elements
+impl<N: Copy> IntoIterator for Vector<N> {
+    type Item = N;
+    type IntoIter = vec::IntoIter<N>;
+
+    fn into_iter(self) -> vec::IntoIter<N> {
+        self.values.into_iter()
+    }
+}
+
+/// IntoIterator implementation for &Vector
+impl<'a, N: Copy> IntoIterator for &'a Vector<N> {
+    type Item = &'a N;
+    type IntoIter = slice::Iter<'a, N>;
+
+    fn into_iter(self) -> slice::Iter<'a, N> {
+        self.values.iter()
+    }
+}
+
+/// IntoIterator implementation for &mut Vector
+impl<'a, N: Copy> IntoIterator for &'a mut Vector<N> {
+    type Item = &'a mut N;
+    type IntoIter = slice::IterMut<'a, N>;
+
+    fn into_iter(self) -> slice::IterMut<'a, N> {
+        self.values.iter_mut()
+    }
+}
+
+/// Extend implementation for Vector, appending the elements of an iterator
+impl<N: Copy> Extend<N> for Vector<N> {
+    fn extend<I: IntoIterator<Item = N>>(&mut self, iter: I) {
+        self.values.extend(iter);
+    }
+}
+
+/// Extend implementation for Vector, appending the referenced elements of an iterator
+impl<'a, N: Copy + 'a> Extend<&'a N> for Vector<N> {
+    fn extend<I: IntoIterator<Item = &'a N>>(&mut self, iter: I) {
+        self.values.extend(iter);
+    }
+}
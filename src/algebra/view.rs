@@ -0,0 +1,853 @@
+use std::fmt;
+use std::fmt::Display;
+use std::ops::{Add, Sub, Mul, Index, IndexMut, Range, RangeBounds, Bound};
+use num::Num;
+use algebra::matrix::{Matrix, ParallelElement};
+use error::Error;
+
+/// Borrowed view of a rectangular part of a Matrix, that doesn't copy its elements
+///
+/// # Remarks
+///
+/// * The element `(i, j)` of the view lives at `i * row_stride + j * col_stride`
+///   from the first element of the view, so row, column and transposed views
+///   just need different strides.
+/// * Views are created by `Matrix::view`, `Matrix::slice`, `Matrix::row_view`,
+///   `Matrix::col_view` or `Matrix::transpose_view`, and are cheap to copy.
+pub struct MatrixView<'a, N: Copy + 'a> {
+    data: &'a [N],
+    nrows: usize,
+    ncols: usize,
+    row_stride: usize,
+    col_stride: usize,
+}
+
+/// Mutable borrowed view of a rectangular part of a Matrix
+///
+/// # Remarks
+///
+/// * Writing through the view modifies the viewed matrix.
+/// * Views are created by `Matrix::view_mut`, `Matrix::row_view_mut` or
+///   `Matrix::col_view_mut`.
+pub struct MatrixViewMut<'a, N: Copy + 'a> {
+    data: &'a mut [N],
+    nrows: usize,
+    ncols: usize,
+    row_stride: usize,
+    col_stride: usize,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Inherent methods
+////////////////////////////////////////////////////////////////////////////////
+
+impl<N: Copy> Matrix<N> {
+
+    /// Returns a view of the whole matrix
+    #[inline]
+    pub fn as_view(&self) -> MatrixView<'_, N> {
+        let (row_stride, col_stride) = self.strides();
+        MatrixView { data: self.as_slice(), nrows: self.nrows(), ncols: self.ncols(), row_stride, col_stride }
+    }
+
+    /// Returns a mutable view of the whole matrix
+    #[inline]
+    pub fn as_view_mut(&mut self) -> MatrixViewMut<'_, N> {
+        let (nrows, ncols) = (self.nrows(), self.ncols());
+        let (row_stride, col_stride) = self.strides();
+        MatrixViewMut { data: self.as_mut_slice(), nrows, ncols, row_stride, col_stride }
+    }
+
+    /// Returns a view of the given rows and columns
+    ///
+    /// # Arguments
+    ///
+    /// * `rows`: range of rows, for example `1..3`
+    /// * `cols`: range of columns
+    ///
+    /// # Remarks
+    ///
+    /// * Panics if the ranges are not valid (see `try_view`)
+    #[inline]
+    pub fn view(&self, rows: Range<usize>, cols: Range<usize>) -> MatrixView<'_, N> {
+        match self.try_view(rows, cols) {
+            Ok(v) => v,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Returns a view of the given rows and columns
+    ///
+    /// # Arguments
+    ///
+    /// * `rows`: range of rows, for example `1..3`
+    /// * `cols`: range of columns
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::InvalidRange` if a range is not ascendent
+    /// * Returns `Error::IndexOutOfBounds` if a range exceeds the matrix
+    #[inline]
+    pub fn try_view(&self, rows: Range<usize>, cols: Range<usize>) -> Result<MatrixView<'_, N>, Error> {
+        self.as_view().try_view(rows, cols)
+    }
+
+    /// Returns a mutable view of the given rows and columns
+    ///
+    /// # Arguments
+    ///
+    /// * `rows`: range of rows, for example `1..3`
+    /// * `cols`: range of columns
+    ///
+    /// # Remarks
+    ///
+    /// * Panics if the ranges are not valid (see `try_view_mut`)
+    #[inline]
+    pub fn view_mut(&mut self, rows: Range<usize>, cols: Range<usize>) -> MatrixViewMut<'_, N> {
+        match self.try_view_mut(rows, cols) {
+            Ok(v) => v,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Returns a mutable view of the given rows and columns
+    ///
+    /// # Arguments
+    ///
+    /// * `rows`: range of rows, for example `1..3`
+    /// * `cols`: range of columns
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::InvalidRange` if a range is not ascendent
+    /// * Returns `Error::IndexOutOfBounds` if a range exceeds the matrix
+    #[inline]
+    pub fn try_view_mut(&mut self, rows: Range<usize>, cols: Range<usize>) -> Result<MatrixViewMut<'_, N>, Error> {
+        self.as_view_mut().try_into_view(rows, cols)
+    }
+
+    /// Returns a view of a row, as a `1 x n` matrix
+    ///
+    /// # Arguments
+    ///
+    /// * `index`: index of the row
+    #[inline]
+    pub fn row_view(&self, index: usize) -> Option<MatrixView<'_, N>> {
+        self.try_view(index..index + 1, 0..self.ncols()).ok()
+    }
+
+    /// Returns a view of a column, as a `m x 1` matrix
+    ///
+    /// # Arguments
+    ///
+    /// * `index`: index of the column
+    #[inline]
+    pub fn col_view(&self, index: usize) -> Option<MatrixView<'_, N>> {
+        self.try_view(0..self.nrows(), index..index + 1).ok()
+    }
+
+    /// Returns a mutable view of a row, as a `1 x n` matrix
+    ///
+    /// # Arguments
+    ///
+    /// * `index`: index of the row
+    #[inline]
+    pub fn row_view_mut(&mut self, index: usize) -> Option<MatrixViewMut<'_, N>> {
+        let ncols = self.ncols();
+        self.try_view_mut(index..index + 1, 0..ncols).ok()
+    }
+
+    /// Returns a mutable view of a column, as a `m x 1` matrix
+    ///
+    /// # Arguments
+    ///
+    /// * `index`: index of the column
+    #[inline]
+    pub fn col_view_mut(&mut self, index: usize) -> Option<MatrixViewMut<'_, N>> {
+        let nrows = self.nrows();
+        self.try_view_mut(0..nrows, index..index + 1).ok()
+    }
+
+    /// Returns a transposed view of the matrix
+    #[inline]
+    pub fn transpose_view(&self) -> MatrixView<'_, N> {
+        self.as_view().transpose()
+    }
+
+    /// Returns a view of the given rows and columns
+    ///
+    /// # Arguments
+    ///
+    /// * `rows`: range of rows, for example `1..3`, `..2`, `1..=2` or `..`
+    /// * `cols`: range of columns
+    ///
+    /// # Remarks
+    ///
+    /// * Panics if the ranges are not valid (see `try_slice`)
+    #[inline]
+    pub fn slice<R1, R2>(&self, rows: R1, cols: R2) -> MatrixView<'_, N>
+        where R1: RangeBounds<usize>, R2: RangeBounds<usize> {
+        match self.try_slice(rows, cols) {
+            Ok(v) => v,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Returns a view of the given rows and columns
+    ///
+    /// # Arguments
+    ///
+    /// * `rows`: range of rows, for example `1..3`, `..2`, `1..=2` or `..`
+    /// * `cols`: range of columns
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::InvalidRange` if a range is not ascendent
+    /// * Returns `Error::IndexOutOfBounds` if a range exceeds the matrix
+    #[inline]
+    pub fn try_slice<R1, R2>(&self, rows: R1, cols: R2) -> Result<MatrixView<'_, N>, Error>
+        where R1: RangeBounds<usize>, R2: RangeBounds<usize> {
+        self.as_view().try_slice(rows, cols)
+    }
+
+    /// Returns a mutable view of the given rows and columns
+    ///
+    /// # Arguments
+    ///
+    /// * `rows`: range of rows, for example `1..3`, `..2`, `1..=2` or `..`
+    /// * `cols`: range of columns
+    ///
+    /// # Remarks
+    ///
+    /// * Panics if the ranges are not valid (see `try_slice_mut`)
+    #[inline]
+    pub fn slice_mut<R1, R2>(&mut self, rows: R1, cols: R2) -> MatrixViewMut<'_, N>
+        where R1: RangeBounds<usize>, R2: RangeBounds<usize> {
+        match self.try_slice_mut(rows, cols) {
+            Ok(v) => v,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Returns a mutable view of the given rows and columns
+    ///
+    /// # Arguments
+    ///
+    /// * `rows`: range of rows, for example `1..3`, `..2`, `1..=2` or `..`
+    /// * `cols`: range of columns
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::InvalidRange` if a range is not ascendent
+    /// * Returns `Error::IndexOutOfBounds` if a range exceeds the matrix
+    #[inline]
+    pub fn try_slice_mut<R1, R2>(&mut self, rows: R1, cols: R2) -> Result<MatrixViewMut<'_, N>, Error>
+        where R1: RangeBounds<usize>, R2: RangeBounds<usize> {
+        let rows = to_range(&rows, self.nrows())?;
+        let cols = to_range(&cols, self.ncols())?;
+        self.try_view_mut(rows, cols)
+    }
+}
+
+impl<'a, N: Copy> MatrixView<'a, N> {
+
+    /// Returns the number of rows
+    #[inline]
+    pub fn nrows(&self) -> usize {
+        self.nrows
+    }
+
+    /// Returns the number of columns
+    #[inline]
+    pub fn ncols(&self) -> usize {
+        self.ncols
+    }
+
+    /// Returns the strides `(row_stride, col_stride)` of the view
+    #[inline]
+    pub fn strides(&self) -> (usize, usize) {
+        (self.row_stride, self.col_stride)
+    }
+
+    /// Returns an element by coordinates, if it exists
+    ///
+    /// # Arguments
+    ///
+    /// * `i_row`: row's index
+    /// * `i_col`: column's index
+    #[inline]
+    pub fn get(&self, i_row: usize, i_col: usize) -> Option<N> {
+        if i_row >= self.nrows || i_col >= self.ncols {
+            return None;
+        }
+        Some(self.data[i_row * self.row_stride + i_col * self.col_stride])
+    }
+
+    /// Returns an element by coordinates
+    ///
+    /// # Arguments
+    ///
+    /// * `i_row`: row's index
+    /// * `i_col`: column's index
+    ///
+    /// # Remarks
+    ///
+    /// * Panics if the element doesn't exist
+    #[inline]
+    pub fn get_element(&self, i_row: usize, i_col: usize) -> N {
+        match self.get(i_row, i_col) {
+            Some(el) => el,
+            None => panic!("index ({}, {}) out of range. size = {} x {}", i_row, i_col, self.nrows, self.ncols),
+        }
+    }
+
+    /// Returns a view of the given rows and columns of the view
+    ///
+    /// # Arguments
+    ///
+    /// * `rows`: range of rows, for example `1..3`
+    /// * `cols`: range of columns
+    ///
+    /// # Remarks
+    ///
+    /// * Panics if the ranges are not valid (see `try_view`)
+    #[inline]
+    pub fn view(&self, rows: Range<usize>, cols: Range<usize>) -> MatrixView<'a, N> {
+        match self.try_view(rows, cols) {
+            Ok(v) => v,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Returns a view of the given rows and columns of the view
+    ///
+    /// # Arguments
+    ///
+    /// * `rows`: range of rows, for example `1..3`
+    /// * `cols`: range of columns
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::InvalidRange` if a range is not ascendent
+    /// * Returns `Error::IndexOutOfBounds` if a range exceeds the view
+    #[inline]
+    pub fn try_view(&self, rows: Range<usize>, cols: Range<usize>) -> Result<MatrixView<'a, N>, Error> {
+        check_range(&rows, self.nrows)?;
+        check_range(&cols, self.ncols)?;
+
+        let data = match start_offset(&rows, &cols, self.row_stride, self.col_stride) {
+            Some(start) => &self.data[start..],
+            None => &self.data[..0],
+        };
+        Ok(MatrixView { data,
+                        nrows: rows.end - rows.start, ncols: cols.end - cols.start,
+                        row_stride: self.row_stride, col_stride: self.col_stride })
+    }
+
+    /// Returns a view of the given rows and columns of the view
+    ///
+    /// # Arguments
+    ///
+    /// * `rows`: range of rows, for example `1..3`, `..2`, `1..=2` or `..`
+    /// * `cols`: range of columns
+    ///
+    /// # Remarks
+    ///
+    /// * Panics if the ranges are not valid (see `try_slice`)
+    #[inline]
+    pub fn slice<R1, R2>(&self, rows: R1, cols: R2) -> MatrixView<'a, N>
+        where R1: RangeBounds<usize>, R2: RangeBounds<usize> {
+        match self.try_slice(rows, cols) {
+            Ok(v) => v,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Returns a view of the given rows and columns of the view
+    ///
+    /// # Arguments
+    ///
+    /// * `rows`: range of rows, for example `1..3`, `..2`, `1..=2` or `..`
+    /// * `cols`: range of columns
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::InvalidRange` if a range is not ascendent
+    /// * Returns `Error::IndexOutOfBounds` if a range exceeds the view
+    #[inline]
+    pub fn try_slice<R1, R2>(&self, rows: R1, cols: R2) -> Result<MatrixView<'a, N>, Error>
+        where R1: RangeBounds<usize>, R2: RangeBounds<usize> {
+        self.try_view(to_range(&rows, self.nrows)?, to_range(&cols, self.ncols)?)
+    }
+
+    /// Returns a view of a row of the view
+    ///
+    /// # Arguments
+    ///
+    /// * `index`: index of the row
+    #[inline]
+    pub fn row_view(&self, index: usize) -> Option<MatrixView<'a, N>> {
+        self.try_view(index..index + 1, 0..self.ncols).ok()
+    }
+
+    /// Returns a view of a column of the view
+    ///
+    /// # Arguments
+    ///
+    /// * `index`: index of the column
+    #[inline]
+    pub fn col_view(&self, index: usize) -> Option<MatrixView<'a, N>> {
+        self.try_view(0..self.nrows, index..index + 1).ok()
+    }
+
+    /// Returns the transposed view
+    #[inline]
+    pub fn transpose(&self) -> MatrixView<'a, N> {
+        MatrixView { data: self.data, nrows: self.ncols, ncols: self.nrows,
+                     row_stride: self.col_stride, col_stride: self.row_stride }
+    }
+
+    /// Returns an iterator over the elements of the view, row by row
+    #[inline]
+    pub fn iter(&self) -> IteratorView<'a, N> {
+        IteratorView { view: *self, index: 0, end: self.nrows * self.ncols }
+    }
+
+    /// Returns an owned matrix with a copy of the viewed elements
+    #[inline]
+    pub fn to_matrix(&self) -> Matrix<N> {
+        Matrix::<N>::from_vec(self.nrows, self.ncols, self.iter().collect())
+    }
+
+    /// Returns the element-wise sum of two views
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: right-hand side of the sum
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::DimensionMismatch` if the sizes are not equal
+    #[inline]
+    pub fn try_add(&self, other: &MatrixView<N>) -> Result<Matrix<N>, Error> where N: Num {
+        self.zip_with(other, |a, b| a + b)
+    }
+
+    /// Returns the element-wise difference of two views
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: right-hand side of the difference
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::DimensionMismatch` if the sizes are not equal
+    #[inline]
+    pub fn try_sub(&self, other: &MatrixView<N>) -> Result<Matrix<N>, Error> where N: Num {
+        self.zip_with(other, |a, b| a - b)
+    }
+
+    /// Returns the matrix product of two views
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: right-hand side of the product
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::DimensionMismatch` if `other` hasn't as many rows as
+    ///   columns in the view
+    #[inline]
+    pub fn try_mul(&self, other: &MatrixView<N>) -> Result<Matrix<N>, Error>
+        where N: Num + Default + ParallelElement {
+        if self.ncols != other.nrows {
+            return Err(Error::DimensionMismatch { expected: (self.ncols, other.ncols),
+                                                  found: (other.nrows, other.ncols) });
+        }
+        // the kernel needs contiguous operands
+        self.to_matrix().try_mul(&other.to_matrix())
+    }
+
+    // internal use: combines the elements of two views of the same size
+    fn zip_with<F>(&self, other: &MatrixView<N>, f: F) -> Result<Matrix<N>, Error> where F: Fn(N, N) -> N {
+        if self.nrows != other.nrows || self.ncols != other.ncols {
+            return Err(Error::DimensionMismatch { expected: (self.nrows, self.ncols),
+                                                  found: (other.nrows, other.ncols) });
+        }
+        let values: Vec<N> = self.iter().zip(other.iter()).map(|(a, b)| f(a, b)).collect();
+        Ok(Matrix::<N>::from_vec(self.nrows, self.ncols, values))
+    }
+}
+
+impl<'a, N: Copy> MatrixViewMut<'a, N> {
+
+    /// Returns the number of rows
+    #[inline]
+    pub fn nrows(&self) -> usize {
+        self.nrows
+    }
+
+    /// Returns the number of columns
+    #[inline]
+    pub fn ncols(&self) -> usize {
+        self.ncols
+    }
+
+    /// Returns the strides `(row_stride, col_stride)` of the view
+    #[inline]
+    pub fn strides(&self) -> (usize, usize) {
+        (self.row_stride, self.col_stride)
+    }
+
+    /// Returns a read-only view of the same elements
+    #[inline]
+    pub fn as_view(&self) -> MatrixView<'_, N> {
+        MatrixView { data: self.data, nrows: self.nrows, ncols: self.ncols,
+                     row_stride: self.row_stride, col_stride: self.col_stride }
+    }
+
+    /// Returns an element by coordinates
+    ///
+    /// # Arguments
+    ///
+    /// * `i_row`: row's index
+    /// * `i_col`: column's index
+    ///
+    /// # Remarks
+    ///
+    /// * Panics if the element doesn't exist
+    #[inline]
+    pub fn get_element(&self, i_row: usize, i_col: usize) -> N {
+        self.as_view().get_element(i_row, i_col)
+    }
+
+    /// Modifies an element of the viewed matrix
+    ///
+    /// # Arguments
+    ///
+    /// * `i_row`: row's index
+    /// * `i_col`: column's index
+    /// * `val`: new element value
+    ///
+    /// # Remarks
+    ///
+    /// * Panics if the element doesn't exist
+    #[inline]
+    pub fn set_element(&mut self, i_row: usize, i_col: usize, val: &N) {
+        self[(i_row, i_col)] = *val;
+    }
+
+    /// Sets all the viewed elements to a value
+    ///
+    /// # Arguments
+    ///
+    /// * `val`: new value of the elements
+    #[inline]
+    pub fn fill(&mut self, val: N) {
+        for i in 0..self.nrows {
+            for j in 0..self.ncols {
+                self.data[i * self.row_stride + j * self.col_stride] = val;
+            }
+        }
+    }
+
+    /// Copies the elements of another view into the viewed elements
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: view with the new values
+    ///
+    /// # Remarks
+    ///
+    /// * Panics if the sizes are not equal (see `try_copy_from`)
+    #[inline]
+    pub fn copy_from(&mut self, other: &MatrixView<N>) {
+        if let Err(e) = self.try_copy_from(other) {
+            panic!("{}", e);
+        }
+    }
+
+    /// Copies the elements of another view into the viewed elements
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: view with the new values
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::DimensionMismatch` if the sizes are not equal
+    #[inline]
+    pub fn try_copy_from(&mut self, other: &MatrixView<N>) -> Result<(), Error> {
+        if self.nrows != other.nrows || self.ncols != other.ncols {
+            return Err(Error::DimensionMismatch { expected: (self.nrows, self.ncols),
+                                                  found: (other.nrows, other.ncols) });
+        }
+        for i in 0..self.nrows {
+            for j in 0..self.ncols {
+                self.data[i * self.row_stride + j * self.col_stride] = other.get_element(i, j);
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the transposed mutable view
+    #[inline]
+    pub fn transpose(self) -> MatrixViewMut<'a, N> {
+        MatrixViewMut { data: self.data, nrows: self.ncols, ncols: self.nrows,
+                        row_stride: self.col_stride, col_stride: self.row_stride }
+    }
+
+    /// Returns an owned matrix with a copy of the viewed elements
+    #[inline]
+    pub fn to_matrix(&self) -> Matrix<N> {
+        self.as_view().to_matrix()
+    }
+
+    // internal use: narrows the mutable view to the given rows and columns
+    fn try_into_view(self, rows: Range<usize>, cols: Range<usize>) -> Result<MatrixViewMut<'a, N>, Error> {
+        check_range(&rows, self.nrows)?;
+        check_range(&cols, self.ncols)?;
+
+        let data = match start_offset(&rows, &cols, self.row_stride, self.col_stride) {
+            Some(start) => &mut self.data[start..],
+            None => &mut self.data[..0],
+        };
+        Ok(MatrixViewMut { data,
+                           nrows: rows.end - rows.start, ncols: cols.end - cols.start,
+                           row_stride: self.row_stride, col_stride: self.col_stride })
+    }
+}
+
+// internal use: checks that a range is ascendent and inside a dimension
+fn check_range(range: &Range<usize>, size: usize) -> Result<(), Error> {
+    if range.start > range.end {
+        return Err(Error::InvalidRange);
+    }
+    if range.end > size {
+        return Err(Error::IndexOutOfBounds { index: range.end - 1, size });
+    }
+    Ok(())
+}
+
+// internal use: converts any range of indices into a half-open range, an
+// unbounded end being the size of the dimension
+fn to_range<R: RangeBounds<usize>>(range: &R, size: usize) -> Result<Range<usize>, Error> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.checked_add(1).ok_or(Error::InvalidRange)?,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end.checked_add(1).ok_or(Error::IndexOutOfBounds { index: end, size })?,
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => size,
+    };
+    Ok(start..end)
+}
+
+// internal use: panics if an element is not inside a matrix or a view
+fn check_index(i_row: usize, i_col: usize, nrows: usize, ncols: usize) {
+    if i_row >= nrows || i_col >= ncols {
+        panic!("index ({}, {}) out of range. size = {} x {}", i_row, i_col, nrows, ncols);
+    }
+}
+
+// internal use: position of the first element of a view, an empty view has none
+fn start_offset(rows: &Range<usize>, cols: &Range<usize>, row_stride: usize, col_stride: usize) -> Option<usize> {
+    if rows.start == rows.end || cols.start == cols.end {
+        return None;
+    }
+    Some(rows.start * row_stride + cols.start * col_stride)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Traits
+////////////////////////////////////////////////////////////////////////////////
+
+/// Copy implementation for MatrixView
+impl<'a, N: Copy> Copy for MatrixView<'a, N> { }
+
+/// Clone implementation for MatrixView
+impl<'a, N: Copy> Clone for MatrixView<'a, N> {
+    fn clone(&self) -> MatrixView<'a, N> {
+        *self
+    }
+}
+
+/// Conversion of a borrowed Matrix into a view of the whole matrix
+impl<'a, N: Copy> From<&'a Matrix<N>> for MatrixView<'a, N> {
+    fn from(m: &'a Matrix<N>) -> MatrixView<'a, N> {
+        m.as_view()
+    }
+}
+
+/// Equivalence `==` implementation for MatrixView
+impl<'a, 'b, N: Copy + PartialEq> PartialEq<MatrixView<'b, N>> for MatrixView<'a, N> {
+    fn eq(&self, other: &MatrixView<'b, N>) -> bool {
+        self.nrows == other.nrows && self.ncols == other.ncols && self.iter().eq(other.iter())
+    }
+}
+
+/// Indexing `view[(i, j)]` implementation for MatrixView
+impl<'a, N: Copy> Index<(usize, usize)> for MatrixView<'a, N> {
+    type Output = N;
+
+    fn index(&self, (i_row, i_col): (usize, usize)) -> &N {
+        check_index(i_row, i_col, self.nrows, self.ncols);
+        &self.data[i_row * self.row_stride + i_col * self.col_stride]
+    }
+}
+
+/// Indexing `view[(i, j)]` implementation for MatrixViewMut
+impl<'a, N: Copy> Index<(usize, usize)> for MatrixViewMut<'a, N> {
+    type Output = N;
+
+    fn index(&self, (i_row, i_col): (usize, usize)) -> &N {
+        check_index(i_row, i_col, self.nrows, self.ncols);
+        &self.data[i_row * self.row_stride + i_col * self.col_stride]
+    }
+}
+
+/// Mutable indexing `view[(i, j)] = val` implementation for MatrixViewMut
+impl<'a, N: Copy> IndexMut<(usize, usize)> for MatrixViewMut<'a, N> {
+    fn index_mut(&mut self, (i_row, i_col): (usize, usize)) -> &mut N {
+        check_index(i_row, i_col, self.nrows, self.ncols);
+        &mut self.data[i_row * self.row_stride + i_col * self.col_stride]
+    }
+}
+
+/// Addition `+` implementation for MatrixView with a view or a &Matrix
+impl<'a, 'b, N: Copy + 'b, R> Add<R> for MatrixView<'a, N> where N: Num, R: Into<MatrixView<'b, N>> {
+    type Output = Matrix<N>;
+
+    fn add(self, other: R) -> Matrix<N> {
+        match self.try_add(&other.into()) {
+            Ok(m) => m,
+            Err(e) => panic!("{}", e),
+        }
+    }
+}
+
+/// Addition `+` implementation for &Matrix with a view
+impl<'b, N: Copy + 'b> Add<MatrixView<'b, N>> for &Matrix<N> where N: Num {
+    type Output = Matrix<N>;
+
+    fn add(self, other: MatrixView<'b, N>) -> Matrix<N> {
+        self.as_view() + other
+    }
+}
+
+/// Substraction `-` implementation for MatrixView with a view or a &Matrix
+impl<'a, 'b, N: Copy + 'b, R> Sub<R> for MatrixView<'a, N> where N: Num, R: Into<MatrixView<'b, N>> {
+    type Output = Matrix<N>;
+
+    fn sub(self, other: R) -> Matrix<N> {
+        match self.try_sub(&other.into()) {
+            Ok(m) => m,
+            Err(e) => panic!("{}", e),
+        }
+    }
+}
+
+/// Substraction `-` implementation for &Matrix with a view
+impl<'b, N: Copy + 'b> Sub<MatrixView<'b, N>> for &Matrix<N> where N: Num {
+    type Output = Matrix<N>;
+
+    fn sub(self, other: MatrixView<'b, N>) -> Matrix<N> {
+        self.as_view() - other
+    }
+}
+
+/// Multiplication `*` implementation for MatrixView with a view or a &Matrix
+impl<'a, 'b, N: Copy + 'b, R> Mul<R> for MatrixView<'a, N>
+    where N: Num + Default + ParallelElement, R: Into<MatrixView<'b, N>> {
+    type Output = Matrix<N>;
+
+    fn mul(self, other: R) -> Matrix<N> {
+        match self.try_mul(&other.into()) {
+            Ok(m) => m,
+            Err(e) => panic!("{}", e),
+        }
+    }
+}
+
+/// Multiplication `*` implementation for &Matrix with a view
+impl<'b, N: Copy + 'b> Mul<MatrixView<'b, N>> for &Matrix<N> where N: Num + Default + ParallelElement {
+    type Output = Matrix<N>;
+
+    fn mul(self, other: MatrixView<'b, N>) -> Matrix<N> {
+        self.as_view() * other
+    }
+}
+
+/// Display implementation for MatrixView
+impl<'a, N: Copy> fmt::Display for MatrixView<'a, N> where N: Display {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{{")?;
+        for i in 0..self.nrows {
+            write!(f, "[ ")?;
+            for j in 0..self.ncols {
+                write!(f, "{} ", self.get_element(i, j))?;
+            }
+            writeln!(f, "]")?;
+        }
+        writeln!(f, "}}")?;
+        write!(f, "size: {row} x {col}", row = self.nrows, col = self.ncols)
+    }
+}
+
+/// Display implementation for MatrixViewMut
+impl<'a, N: Copy> fmt::Display for MatrixViewMut<'a, N> where N: Display {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.as_view().fmt(f)
+    }
+}
+
+// --------------- Iterators ----------------------------------------
+
+/// Definition of IteratorView: the iterator over the elements of a view, row by row
+pub struct IteratorView<'a, N: 'a + Copy> {
+    view: MatrixView<'a, N>,
+    index: usize,
+    end: usize,
+}
+
+impl<'a, N: Copy> IteratorView<'a, N> {
+    // internal use: element at a row-major position of the view
+    fn at(&self, index: usize) -> N {
+        let (i, j) = (index / self.view.ncols, index % self.view.ncols);
+        self.view.data[i * self.view.row_stride + j * self.view.col_stride]
+    }
+}
+
+/// Implementation of the Iterator for IteratorView
+impl<'a, N: Copy> Iterator for IteratorView<'a, N> {
+    type Item = N;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.end {
+            return None;
+        }
+        self.index += 1;
+        Some(self.at(self.index - 1))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.index;
+        (len, Some(len))
+    }
+}
+
+/// Implementation of the DoubleEndedIterator for IteratorView
+impl<'a, N: Copy> DoubleEndedIterator for IteratorView<'a, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.index >= self.end {
+            return None;
+        }
+        self.end -= 1;
+        Some(self.at(self.end))
+    }
+}
+
+/// Implementation of the ExactSizeIterator for IteratorView
+impl<'a, N: Copy> ExactSizeIterator for IteratorView<'a, N> { }
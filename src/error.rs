@@ -0,0 +1,80 @@
+use std::error;
+use std::fmt;
+
+/// Errors returned by the fallible (`try_*`) operations of the library
+///
+/// # Remarks
+///
+/// * The panicking counterparts of these operations panic with the
+///   `Display` message of the error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// The shape of an operand does not fit the operation
+    DimensionMismatch { expected: (usize, usize), found: (usize, usize) },
+    /// An index is bigger than the size of the accessed dimension
+    IndexOutOfBounds { index: usize, size: usize },
+    /// The operation is just available for square matrices
+    NotSquare { nrows: usize, ncols: usize },
+    /// A range is not ascendent
+    InvalidRange,
+    /// The matrix is singular (not invertible) or rank deficient
+    Singular,
+    /// The system has less equations (rows) than unknowns (columns)
+    Underdetermined { nrows: usize, ncols: usize },
+    /// The matrix is not symmetric
+    NotSymmetric,
+    /// The matrix is not positive definite
+    NotPositiveDefinite,
+    /// An iterative algorithm did not converge within the given iterations
+    NoConvergence { iterations: usize },
+    /// An argument is outside of its valid domain
+    InvalidArgument { name: &'static str },
+    /// There are less observations than the operation needs
+    InsufficientData { required: usize, found: usize },
+    /// A value is not a number (`NaN`)
+    NotANumber { index: usize },
+    /// A value can't be represented by the type it's converted to
+    NotRepresentable { index: usize },
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Traits
+////////////////////////////////////////////////////////////////////////////////
+
+/// Display implementation for Error
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::DimensionMismatch { expected, found } =>
+                write!(f, "dimension mismatch: expected {} x {}, found {} x {}",
+                       expected.0, expected.1, found.0, found.1),
+            Error::IndexOutOfBounds { index, size } =>
+                write!(f, "index out of range: index = {}, size = {}", index, size),
+            Error::NotSquare { nrows, ncols } =>
+                write!(f, "matrix is not square: {} x {}", nrows, ncols),
+            Error::InvalidRange =>
+                write!(f, "invalid range: please use ascendent ranges"),
+            Error::Singular =>
+                write!(f, "matrix is singular"),
+            Error::Underdetermined { nrows, ncols } =>
+                write!(f, "system is underdetermined: {} x {}", nrows, ncols),
+            Error::NotSymmetric =>
+                write!(f, "matrix is not symmetric"),
+            Error::NotPositiveDefinite =>
+                write!(f, "matrix is not positive definite"),
+            Error::NoConvergence { iterations } =>
+                write!(f, "no convergence after {} iterations", iterations),
+            Error::InvalidArgument { name } =>
+                write!(f, "invalid argument: {}", name),
+            Error::InsufficientData { required, found } =>
+                write!(f, "insufficient data: {} observations required, found {}", required, found),
+            Error::NotANumber { index } =>
+                write!(f, "not a number (NaN) found at index {}", index),
+            Error::NotRepresentable { index } =>
+                write!(f, "value at index {} can't be represented by the target type", index),
+        }
+    }
+}
+
+/// Error implementation for Error
+impl error::Error for Error {}
@@ -1,5 +1,10 @@
 extern crate rand;
 extern crate num;
+#[cfg(feature = "parallel")]
+extern crate rayon;
 
 pub mod algebra;
 pub mod linspace;
+pub mod error;
+
+pub use error::Error;
@@ -0,0 +1,790 @@
+use num::{Float, Num};
+use std::fmt;
+use std::ops::{Mul, Index, IndexMut};
+use error::Error;
+use linspace::point::{Point2D, Point3D};
+use linspace::vector::{Vector2D, Vector3D, Vector4D};
+use linspace::quat::Quat;
+
+/// Matrix of 2 x 2 elements stored in the stack, for the linear transforms
+/// of the plane
+///
+/// # Remarks
+///
+/// * The elements are stored by rows, `m[(i, j)]` is the element of the row
+///   `i` and column `j`.
+/// * The vectors are columns: a transform is applied as `m * v`.
+#[derive(Clone, Copy, Default)]
+pub struct Matrix2<N: Copy> {
+    m: [[N; 2]; 2],
+}
+
+/// Matrix of 3 x 3 elements stored in the stack, for the linear transforms
+/// of the 3D space
+///
+/// # Remarks
+///
+/// * The elements are stored by rows, `m[(i, j)]` is the element of the row
+///   `i` and column `j`.
+/// * The vectors are columns: a transform is applied as `m * v`.
+#[derive(Clone, Copy, Default)]
+pub struct Matrix3<N: Copy> {
+    m: [[N; 3]; 3],
+}
+
+/// Matrix of 4 x 4 elements stored in the stack, for the affine and
+/// projective transforms of the 3D space in homogeneous coordinates
+///
+/// # Remarks
+///
+/// * The elements are stored by rows, `m[(i, j)]` is the element of the row
+///   `i` and column `j`.
+/// * The vectors are columns: a transform is applied as `m * v`, and the
+///   translation is stored in the last column.
+#[derive(Clone, Copy, Default)]
+pub struct Matrix4<N: Copy> {
+    m: [[N; 4]; 4],
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Inherent methods
+////////////////////////////////////////////////////////////////////////////////
+
+impl<N: Copy + Num> Matrix2<N> {
+
+    /// Initializes a Matrix2 with default values
+    #[inline]
+    pub fn new() -> Matrix2<N> where N: Default {
+        Matrix2::default()
+    }
+
+    /// Initializes a Matrix2 with defined rows
+    ///
+    /// # Arguments
+    ///
+    /// * `rows`: elements of the matrix, row by row
+    #[inline]
+    pub fn init(rows: [[N; 2]; 2]) -> Matrix2<N> {
+        Matrix2 { m: rows }
+    }
+
+    /// Returns the identity matrix
+    #[inline]
+    pub fn identity() -> Matrix2<N> {
+        let (o, l) = (N::zero(), N::one());
+        Matrix2 { m: [[l, o], [o, l]] }
+    }
+
+    /// Returns the elements of the matrix, row by row
+    #[inline]
+    pub fn to_array(&self) -> [[N; 2]; 2] {
+        self.m
+    }
+
+    /// Returns the transpose of the matrix
+    #[inline]
+    pub fn transpose(&self) -> Matrix2<N> {
+        let m = &self.m;
+        Matrix2 { m: [[m[0][0], m[1][0]], [m[0][1], m[1][1]]] }
+    }
+
+    /// Returns the determinant of the matrix
+    #[inline]
+    pub fn determinant(&self) -> N {
+        let m = &self.m;
+        m[0][0] * m[1][1] - m[0][1] * m[1][0]
+    }
+
+    /// Returns the inverse of the matrix
+    ///
+    /// # Remarks
+    ///
+    /// * Panics if the matrix is singular (see `try_inverse`)
+    #[inline]
+    pub fn inverse(&self) -> Matrix2<N> where N: Float {
+        match self.try_inverse() {
+            Ok(m) => m,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Returns the inverse of the matrix
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::Singular` if the determinant is zero
+    #[inline]
+    pub fn try_inverse(&self) -> Result<Matrix2<N>, Error> where N: Float {
+        let det = self.determinant();
+        if det == N::zero() {
+            return Err(Error::Singular);
+        }
+        let m = &self.m;
+        let inv = Matrix2 { m: [[m[1][1], -m[0][1]], [-m[1][0], m[0][0]]] };
+        Ok(inv * det.recip())
+    }
+
+    /// Returns the matrix scaling every axis by a factor
+    ///
+    /// # Arguments
+    ///
+    /// * `sx`: X factor
+    /// * `sy`: Y factor
+    #[inline]
+    pub fn scaling(sx: N, sy: N) -> Matrix2<N> {
+        let o = N::zero();
+        Matrix2 { m: [[sx, o], [o, sy]] }
+    }
+
+    /// Returns the matrix of a counterclockwise rotation
+    ///
+    /// # Arguments
+    ///
+    /// * `angle`: angle of the rotation, in radians
+    #[inline]
+    pub fn rotation(angle: N) -> Matrix2<N> where N: Float {
+        let (sin, cos) = angle.sin_cos();
+        Matrix2 { m: [[cos, -sin], [sin, cos]] }
+    }
+}
+
+impl<N: Copy + Num> Matrix3<N> {
+
+    /// Initializes a Matrix3 with default values
+    #[inline]
+    pub fn new() -> Matrix3<N> where N: Default {
+        Matrix3::default()
+    }
+
+    /// Initializes a Matrix3 with defined rows
+    ///
+    /// # Arguments
+    ///
+    /// * `rows`: elements of the matrix, row by row
+    #[inline]
+    pub fn init(rows: [[N; 3]; 3]) -> Matrix3<N> {
+        Matrix3 { m: rows }
+    }
+
+    /// Returns the identity matrix
+    #[inline]
+    pub fn identity() -> Matrix3<N> {
+        let (o, l) = (N::zero(), N::one());
+        Matrix3 { m: [[l, o, o], [o, l, o], [o, o, l]] }
+    }
+
+    /// Returns the elements of the matrix, row by row
+    #[inline]
+    pub fn to_array(&self) -> [[N; 3]; 3] {
+        self.m
+    }
+
+    /// Returns the transpose of the matrix
+    #[inline]
+    pub fn transpose(&self) -> Matrix3<N> {
+        let mut t = self.m;
+        for (i, row) in t.iter_mut().enumerate() {
+            for (j, el) in row.iter_mut().enumerate() {
+                *el = self.m[j][i];
+            }
+        }
+        Matrix3 { m: t }
+    }
+
+    /// Returns the determinant of the matrix
+    #[inline]
+    pub fn determinant(&self) -> N {
+        let m = &self.m;
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1]) -
+        m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0]) +
+        m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    }
+
+    /// Returns the inverse of the matrix
+    ///
+    /// # Remarks
+    ///
+    /// * Panics if the matrix is singular (see `try_inverse`)
+    #[inline]
+    pub fn inverse(&self) -> Matrix3<N> where N: Float {
+        match self.try_inverse() {
+            Ok(m) => m,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Returns the inverse of the matrix
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::Singular` if the determinant is zero
+    #[inline]
+    pub fn try_inverse(&self) -> Result<Matrix3<N>, Error> where N: Float {
+        let det = self.determinant();
+        if det == N::zero() {
+            return Err(Error::Singular);
+        }
+        let m = &self.m;
+        let adjugate = Matrix3 { m: [
+            [m[1][1] * m[2][2] - m[1][2] * m[2][1],
+             m[0][2] * m[2][1] - m[0][1] * m[2][2],
+             m[0][1] * m[1][2] - m[0][2] * m[1][1]],
+            [m[1][2] * m[2][0] - m[1][0] * m[2][2],
+             m[0][0] * m[2][2] - m[0][2] * m[2][0],
+             m[0][2] * m[1][0] - m[0][0] * m[1][2]],
+            [m[1][0] * m[2][1] - m[1][1] * m[2][0],
+             m[0][1] * m[2][0] - m[0][0] * m[2][1],
+             m[0][0] * m[1][1] - m[0][1] * m[1][0]],
+        ] };
+        Ok(adjugate * det.recip())
+    }
+
+    /// Returns the matrix scaling every axis by a factor
+    ///
+    /// # Arguments
+    ///
+    /// * `sx`: X factor
+    /// * `sy`: Y factor
+    /// * `sz`: Z factor
+    #[inline]
+    pub fn scaling(sx: N, sy: N, sz: N) -> Matrix3<N> {
+        let o = N::zero();
+        Matrix3 { m: [[sx, o, o], [o, sy, o], [o, o, sz]] }
+    }
+
+    /// Returns the matrix of a rotation about an axis, counterclockwise when
+    /// the axis points to the viewer
+    ///
+    /// # Arguments
+    ///
+    /// * `axis`: direction of the axis of rotation, it's normalized
+    /// * `angle`: angle of the rotation, in radians
+    ///
+    /// # Remarks
+    ///
+    /// * The elements of the rotation about the zero vector are `NaN`
+    #[inline]
+    pub fn rotation(axis: &Vector3D<N>, angle: N) -> Matrix3<N> where N: Float {
+        let u = axis.norm();
+        let (sin, cos) = angle.sin_cos();
+        let t = N::one() - cos;
+        Matrix3 { m: [
+            [t * u.x * u.x + cos, t * u.x * u.y - sin * u.z, t * u.x * u.z + sin * u.y],
+            [t * u.x * u.y + sin * u.z, t * u.y * u.y + cos, t * u.y * u.z - sin * u.x],
+            [t * u.x * u.z - sin * u.y, t * u.y * u.z + sin * u.x, t * u.z * u.z + cos],
+        ] }
+    }
+
+    /// Returns the rotation matrix of a quaternion
+    ///
+    /// # Arguments
+    ///
+    /// * `q`: quaternion of the rotation, it doesn't need to be unitary
+    ///
+    /// # Remarks
+    ///
+    /// * The elements of the matrix of the zero quaternion are `NaN`
+    #[inline]
+    pub fn from_quat(q: &Quat<N>) -> Matrix3<N> where N: Float {
+        let (x, y, z, w) = (q.x(), q.y(), q.z(), q.w());
+        let l = N::one();
+        let s = (l + l) / (x * x + y * y + z * z + w * w);
+        Matrix3 { m: [
+            [l - s * (y * y + z * z), s * (x * y - z * w), s * (x * z + y * w)],
+            [s * (x * y + z * w), l - s * (x * x + z * z), s * (y * z - x * w)],
+            [s * (x * z - y * w), s * (y * z + x * w), l - s * (x * x + y * y)],
+        ] }
+    }
+}
+
+impl<N: Copy + Num> Matrix4<N> {
+
+    /// Initializes a Matrix4 with default values
+    #[inline]
+    pub fn new() -> Matrix4<N> where N: Default {
+        Matrix4::default()
+    }
+
+    /// Initializes a Matrix4 with defined rows
+    ///
+    /// # Arguments
+    ///
+    /// * `rows`: elements of the matrix, row by row
+    #[inline]
+    pub fn init(rows: [[N; 4]; 4]) -> Matrix4<N> {
+        Matrix4 { m: rows }
+    }
+
+    /// Returns the identity matrix
+    #[inline]
+    pub fn identity() -> Matrix4<N> {
+        let (o, l) = (N::zero(), N::one());
+        Matrix4 { m: [[l, o, o, o], [o, l, o, o], [o, o, l, o], [o, o, o, l]] }
+    }
+
+    /// Returns the elements of the matrix, row by row
+    #[inline]
+    pub fn to_array(&self) -> [[N; 4]; 4] {
+        self.m
+    }
+
+    /// Returns the transpose of the matrix
+    #[inline]
+    pub fn transpose(&self) -> Matrix4<N> {
+        let mut t = self.m;
+        for (i, row) in t.iter_mut().enumerate() {
+            for (j, el) in row.iter_mut().enumerate() {
+                *el = self.m[j][i];
+            }
+        }
+        Matrix4 { m: t }
+    }
+
+    /// Returns the determinant of the matrix
+    #[inline]
+    pub fn determinant(&self) -> N {
+        let (s, c) = self.minors();
+        s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0]
+    }
+
+    /// Returns the inverse of the matrix
+    ///
+    /// # Remarks
+    ///
+    /// * Panics if the matrix is singular (see `try_inverse`)
+    #[inline]
+    pub fn inverse(&self) -> Matrix4<N> where N: Float {
+        match self.try_inverse() {
+            Ok(m) => m,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Returns the inverse of the matrix
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `Error::Singular` if the determinant is zero
+    #[inline]
+    pub fn try_inverse(&self) -> Result<Matrix4<N>, Error> where N: Float {
+        let det = self.determinant();
+        if det == N::zero() {
+            return Err(Error::Singular);
+        }
+        let m = &self.m;
+        let (s, c) = self.minors();
+        let adjugate = Matrix4 { m: [
+            [m[1][1] * c[5] - m[1][2] * c[4] + m[1][3] * c[3],
+             -m[0][1] * c[5] + m[0][2] * c[4] - m[0][3] * c[3],
+             m[3][1] * s[5] - m[3][2] * s[4] + m[3][3] * s[3],
+             -m[2][1] * s[5] + m[2][2] * s[4] - m[2][3] * s[3]],
+            [-m[1][0] * c[5] + m[1][2] * c[2] - m[1][3] * c[1],
+             m[0][0] * c[5] - m[0][2] * c[2] + m[0][3] * c[1],
+             -m[3][0] * s[5] + m[3][2] * s[2] - m[3][3] * s[1],
+             m[2][0] * s[5] - m[2][2] * s[2] + m[2][3] * s[1]],
+            [m[1][0] * c[4] - m[1][1] * c[2] + m[1][3] * c[0],
+             -m[0][0] * c[4] + m[0][1] * c[2] - m[0][3] * c[0],
+             m[3][0] * s[4] - m[3][1] * s[2] + m[3][3] * s[0],
+             -m[2][0] * s[4] + m[2][1] * s[2] - m[2][3] * s[0]],
+            [-m[1][0] * c[3] + m[1][1] * c[1] - m[1][2] * c[0],
+             m[0][0] * c[3] - m[0][1] * c[1] + m[0][2] * c[0],
+             -m[3][0] * s[3] + m[3][1] * s[1] - m[3][2] * s[0],
+             m[2][0] * s[3] - m[2][1] * s[1] + m[2][2] * s[0]],
+        ] };
+        Ok(adjugate * det.recip())
+    }
+
+    /// Returns the matrix of a translation
+    ///
+    /// # Arguments
+    ///
+    /// * `tx`: X displacement
+    /// * `ty`: Y displacement
+    /// * `tz`: Z displacement
+    ///
+    /// # Remarks
+    ///
+    /// * The vectors (directions) are not modified by a translation, just
+    ///   the points
+    #[inline]
+    pub fn translation(tx: N, ty: N, tz: N) -> Matrix4<N> {
+        let mut t = Matrix4::identity();
+        t.m[0][3] = tx;
+        t.m[1][3] = ty;
+        t.m[2][3] = tz;
+        t
+    }
+
+    /// Returns the matrix scaling every axis by a factor
+    ///
+    /// # Arguments
+    ///
+    /// * `sx`: X factor
+    /// * `sy`: Y factor
+    /// * `sz`: Z factor
+    #[inline]
+    pub fn scaling(sx: N, sy: N, sz: N) -> Matrix4<N> {
+        Matrix4::from(Matrix3::scaling(sx, sy, sz))
+    }
+
+    /// Returns the matrix of a rotation about an axis, counterclockwise when
+    /// the axis points to the viewer (see `Matrix3::rotation`)
+    ///
+    /// # Arguments
+    ///
+    /// * `axis`: direction of the axis of rotation, it's normalized
+    /// * `angle`: angle of the rotation, in radians
+    #[inline]
+    pub fn rotation(axis: &Vector3D<N>, angle: N) -> Matrix4<N> where N: Float {
+        Matrix4::from(Matrix3::rotation(axis, angle))
+    }
+
+    /// Returns the rotation matrix of a quaternion (see `Matrix3::from_quat`)
+    ///
+    /// # Arguments
+    ///
+    /// * `q`: quaternion of the rotation, it doesn't need to be unitary
+    #[inline]
+    pub fn from_quat(q: &Quat<N>) -> Matrix4<N> where N: Float {
+        Matrix4::from(Matrix3::from_quat(q))
+    }
+
+    /// Returns the transform of a point, in homogeneous coordinates
+    ///
+    /// # Arguments
+    ///
+    /// * `p`: point to transform, its `w` is one
+    ///
+    /// # Remarks
+    ///
+    /// * The result is divided by its `w` if it isn't one, as in the
+    ///   projective transforms
+    /// * Returns `None` if the resulting `w` is zero (the point is sent to
+    ///   infinity), like `Point3D::from_homogeneous`
+    #[inline]
+    pub fn try_transform_point(&self, p: &Point3D<N>) -> Option<Point3D<N>> {
+        Point3D::from_homogeneous(&(*self * p.to_homogeneous()))
+    }
+
+    // internal use: determinants of the 2 x 2 submatrices of the two upper
+    // rows (`s`) and the two lower rows (`c`)
+    fn minors(&self) -> ([N; 6], [N; 6]) {
+        let m = &self.m;
+        let s = [m[0][0] * m[1][1] - m[1][0] * m[0][1],
+                 m[0][0] * m[1][2] - m[1][0] * m[0][2],
+                 m[0][0] * m[1][3] - m[1][0] * m[0][3],
+                 m[0][1] * m[1][2] - m[1][1] * m[0][2],
+                 m[0][1] * m[1][3] - m[1][1] * m[0][3],
+                 m[0][2] * m[1][3] - m[1][2] * m[0][3]];
+        let c = [m[2][0] * m[3][1] - m[3][0] * m[2][1],
+                 m[2][0] * m[3][2] - m[3][0] * m[2][2],
+                 m[2][0] * m[3][3] - m[3][0] * m[2][3],
+                 m[2][1] * m[3][2] - m[3][1] * m[2][2],
+                 m[2][1] * m[3][3] - m[3][1] * m[2][3],
+                 m[2][2] * m[3][3] - m[3][2] * m[2][3]];
+        (s, c)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Traits
+////////////////////////////////////////////////////////////////////////////////
+
+/// Equivalence ´==´ implementation for Matrix2
+impl<N: Copy + PartialEq> PartialEq for Matrix2<N> {
+    fn eq(&self, other: &Matrix2<N>) -> bool {
+        self.m == other.m
+    }
+}
+
+/// Equivalence ´==´ implementation for Matrix3
+impl<N: Copy + PartialEq> PartialEq for Matrix3<N> {
+    fn eq(&self, other: &Matrix3<N>) -> bool {
+        self.m == other.m
+    }
+}
+
+/// Equivalence ´==´ implementation for Matrix4
+impl<N: Copy + PartialEq> PartialEq for Matrix4<N> {
+    fn eq(&self, other: &Matrix4<N>) -> bool {
+        self.m == other.m
+    }
+}
+
+/// Indexing ´m[(i, j)]´ implementation for Matrix2
+impl<N: Copy> Index<(usize, usize)> for Matrix2<N> {
+    type Output = N;
+
+    fn index(&self, (i, j): (usize, usize)) -> &N {
+        &self.m[i][j]
+    }
+}
+
+/// Mutable indexing ´m[(i, j)] = val´ implementation for Matrix2
+impl<N: Copy> IndexMut<(usize, usize)> for Matrix2<N> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut N {
+        &mut self.m[i][j]
+    }
+}
+
+/// Indexing ´m[(i, j)]´ implementation for Matrix3
+impl<N: Copy> Index<(usize, usize)> for Matrix3<N> {
+    type Output = N;
+
+    fn index(&self, (i, j): (usize, usize)) -> &N {
+        &self.m[i][j]
+    }
+}
+
+/// Mutable indexing ´m[(i, j)] = val´ implementation for Matrix3
+impl<N: Copy> IndexMut<(usize, usize)> for Matrix3<N> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut N {
+        &mut self.m[i][j]
+    }
+}
+
+/// Indexing ´m[(i, j)]´ implementation for Matrix4
+impl<N: Copy> Index<(usize, usize)> for Matrix4<N> {
+    type Output = N;
+
+    fn index(&self, (i, j): (usize, usize)) -> &N {
+        &self.m[i][j]
+    }
+}
+
+/// Mutable indexing ´m[(i, j)] = val´ implementation for Matrix4
+impl<N: Copy> IndexMut<(usize, usize)> for Matrix4<N> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut N {
+        &mut self.m[i][j]
+    }
+}
+
+/// Multiplication ´*´ implementation for Matrix2
+impl<N: Copy + Num> Mul for Matrix2<N> {
+    type Output = Matrix2<N>;
+
+    fn mul(self, other: Matrix2<N>) -> Matrix2<N> {
+        let mut res = [[N::zero(); 2]; 2];
+        for (i, row) in res.iter_mut().enumerate() {
+            for (j, el) in row.iter_mut().enumerate() {
+                *el = (0..2).fold(N::zero(), |acc, k| acc + self.m[i][k] * other.m[k][j]);
+            }
+        }
+        Matrix2 { m: res }
+    }
+}
+
+/// Multiplication ´*´ of every element of a Matrix2 by an scalar
+impl<N: Copy + Num> Mul<N> for Matrix2<N> {
+    type Output = Matrix2<N>;
+
+    fn mul(self, scalar: N) -> Matrix2<N> {
+        let mut res = self.m;
+        for el in res.iter_mut().flat_map(|row| row.iter_mut()) {
+            *el = *el * scalar;
+        }
+        Matrix2 { m: res }
+    }
+}
+
+/// Transform ´*´ of a Vector2D by a Matrix2
+impl<N: Copy + Num> Mul<Vector2D<N>> for Matrix2<N> {
+    type Output = Vector2D<N>;
+
+    fn mul(self, v: Vector2D<N>) -> Vector2D<N> {
+        let m = &self.m;
+        Vector2D::init(m[0][0] * v.x + m[0][1] * v.y,
+                       m[1][0] * v.x + m[1][1] * v.y)
+    }
+}
+
+/// Transform ´*´ of a Point2D by a Matrix2
+impl<N: Copy + Num> Mul<Point2D<N>> for Matrix2<N> {
+    type Output = Point2D<N>;
+
+    fn mul(self, p: Point2D<N>) -> Point2D<N> {
+        Point2D::from(self * Vector2D::from(p))
+    }
+}
+
+/// Multiplication ´*´ implementation for Matrix3
+impl<N: Copy + Num> Mul for Matrix3<N> {
+    type Output = Matrix3<N>;
+
+    fn mul(self, other: Matrix3<N>) -> Matrix3<N> {
+        let mut res = [[N::zero(); 3]; 3];
+        for (i, row) in res.iter_mut().enumerate() {
+            for (j, el) in row.iter_mut().enumerate() {
+                *el = (0..3).fold(N::zero(), |acc, k| acc + self.m[i][k] * other.m[k][j]);
+            }
+        }
+        Matrix3 { m: res }
+    }
+}
+
+/// Multiplication ´*´ of every element of a Matrix3 by an scalar
+impl<N: Copy + Num> Mul<N> for Matrix3<N> {
+    type Output = Matrix3<N>;
+
+    fn mul(self, scalar: N) -> Matrix3<N> {
+        let mut res = self.m;
+        for el in res.iter_mut().flat_map(|row| row.iter_mut()) {
+            *el = *el * scalar;
+        }
+        Matrix3 { m: res }
+    }
+}
+
+/// Transform ´*´ of a Vector3D by a Matrix3
+impl<N: Copy + Num> Mul<Vector3D<N>> for Matrix3<N> {
+    type Output = Vector3D<N>;
+
+    fn mul(self, v: Vector3D<N>) -> Vector3D<N> {
+        let m = &self.m;
+        Vector3D::init(m[0][0] * v.x + m[0][1] * v.y + m[0][2] * v.z,
+                       m[1][0] * v.x + m[1][1] * v.y + m[1][2] * v.z,
+                       m[2][0] * v.x + m[2][1] * v.y + m[2][2] * v.z)
+    }
+}
+
+/// Transform ´*´ of a Point3D by a Matrix3
+impl<N: Copy + Num> Mul<Point3D<N>> for Matrix3<N> {
+    type Output = Point3D<N>;
+
+    fn mul(self, p: Point3D<N>) -> Point3D<N> {
+        Point3D::from(self * Vector3D::from(p))
+    }
+}
+
+/// Multiplication ´*´ implementation for Matrix4
+impl<N: Copy + Num> Mul for Matrix4<N> {
+    type Output = Matrix4<N>;
+
+    fn mul(self, other: Matrix4<N>) -> Matrix4<N> {
+        let mut res = [[N::zero(); 4]; 4];
+        for (i, row) in res.iter_mut().enumerate() {
+            for (j, el) in row.iter_mut().enumerate() {
+                *el = (0..4).fold(N::zero(), |acc, k| acc + self.m[i][k] * other.m[k][j]);
+            }
+        }
+        Matrix4 { m: res }
+    }
+}
+
+/// Multiplication ´*´ of every element of a Matrix4 by an scalar
+impl<N: Copy + Num> Mul<N> for Matrix4<N> {
+    type Output = Matrix4<N>;
+
+    fn mul(self, scalar: N) -> Matrix4<N> {
+        let mut res = self.m;
+        for el in res.iter_mut().flat_map(|row| row.iter_mut()) {
+            *el = *el * scalar;
+        }
+        Matrix4 { m: res }
+    }
+}
+
+/// Transform ´*´ of some homogeneous coordinates by a Matrix4
+impl<N: Copy + Num> Mul<Vector4D<N>> for Matrix4<N> {
+    type Output = Vector4D<N>;
+
+    fn mul(self, v: Vector4D<N>) -> Vector4D<N> {
+        let m = &self.m;
+        let row = |i: usize| m[i][0] * v.x + m[i][1] * v.y + m[i][2] * v.z + m[i][3] * v.w;
+        Vector4D::init(row(0), row(1), row(2), row(3))
+    }
+}
+
+/// Transform ´*´ of a Vector3D by a Matrix4
+///
+/// # Remarks
+///
+/// * The vector is a direction (`w = 0`), so it's not translated
+impl<N: Copy + Num> Mul<Vector3D<N>> for Matrix4<N> {
+    type Output = Vector3D<N>;
+
+    fn mul(self, v: Vector3D<N>) -> Vector3D<N> {
+        (self * v.to_homogeneous()).xyz()
+    }
+}
+
+/// Transform ´*´ of a Point3D by a Matrix4
+///
+/// # Remarks
+///
+/// * The point is in homogeneous coordinates (`w = 1`); the result is
+///   divided by its `w` if it isn't one, as in the projective transforms
+/// * Panics if the resulting `w` is zero (see `try_transform_point`)
+impl<N: Copy + Num> Mul<Point3D<N>> for Matrix4<N> {
+    type Output = Point3D<N>;
+
+    fn mul(self, p: Point3D<N>) -> Point3D<N> {
+        match self.try_transform_point(&p) {
+            Some(p) => p,
+            None => panic!("point transformed to infinity: w = 0"),
+        }
+    }
+}
+
+/// Conversion of an array of rows into a Matrix2
+impl<N: Copy> From<[[N; 2]; 2]> for Matrix2<N> {
+    fn from(rows: [[N; 2]; 2]) -> Matrix2<N> {
+        Matrix2 { m: rows }
+    }
+}
+
+/// Conversion of an array of rows into a Matrix3
+impl<N: Copy> From<[[N; 3]; 3]> for Matrix3<N> {
+    fn from(rows: [[N; 3]; 3]) -> Matrix3<N> {
+        Matrix3 { m: rows }
+    }
+}
+
+/// Conversion of an array of rows into a Matrix4
+impl<N: Copy> From<[[N; 4]; 4]> for Matrix4<N> {
+    fn from(rows: [[N; 4]; 4]) -> Matrix4<N> {
+        Matrix4 { m: rows }
+    }
+}
+
+/// Conversion of a linear transform into its homogeneous Matrix4, without
+/// translation
+impl<N: Copy + Num> From<Matrix3<N>> for Matrix4<N> {
+    fn from(l: Matrix3<N>) -> Matrix4<N> {
+        let mut res = Matrix4::identity();
+        for i in 0..3 {
+            res.m[i][..3].copy_from_slice(&l.m[i]);
+        }
+        res
+    }
+}
+
+/// Display implementation for Matrix2
+impl<N: Copy + Num> fmt::Display for Matrix2<N> where N: fmt::Display {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.m.iter() {
+            writeln!(f, "( {}, {} )", row[0], row[1])?;
+        }
+        Ok(())
+    }
+}
+
+/// Display implementation for Matrix3
+impl<N: Copy + Num> fmt::Display for Matrix3<N> where N: fmt::Display {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.m.iter() {
+            writeln!(f, "( {}, {}, {} )", row[0], row[1], row[2])?;
+        }
+        Ok(())
+    }
+}
+
+/// Display implementation for Matrix4
+impl<N: Copy + Num> fmt::Display for Matrix4<N> where N: fmt::Display {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.m.iter() {
+            writeln!(f, "( {}, {}, {}, {} )", row[0], row[1], row[2], row[3])?;
+        }
+        Ok(())
+    }
+}
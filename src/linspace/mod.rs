@@ -1,3 +1,4 @@
 pub mod point;
 pub mod vector;
-pub mod quat;
\ No newline at end of file
+pub mod quat;
+pub mod matrix;
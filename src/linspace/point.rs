@@ -1,7 +1,9 @@
-use num::Num;
+use num::{Num, NumCast};
 use num::pow;
 use std::fmt;
 use std::fmt::Display;
+use std::ops::{Add, Sub, AddAssign, SubAssign};
+use linspace::vector::{Vector2D, Vector3D, Vector4D};
 
 /// Point of 3 dimensions with a defined coordinates
 ///
@@ -16,6 +18,18 @@ pub struct Point3D<N: Copy> {
     z: N,
 }
 
+/// Point of 2 dimensions with a defined coordinates
+///
+/// # Remarks
+///
+/// This struct is implemented to be used with numerical types, not tested
+/// for strings, bools, or other types.
+#[derive(Clone, Copy, Default)]
+pub struct Point2D<N: Copy> {
+    x: N,
+    y: N,
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Inherent methods
 ////////////////////////////////////////////////////////////////////////////////
@@ -115,6 +129,228 @@ impl<N: Copy + Num> Point3D<N> {
                         pow(a.z() - b.z(), 2)).into();
         val.sqrt()
     }
+
+    /// Returns the point in the middle of two points
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: second point
+    ///
+    /// # Remarks
+    ///
+    /// * The coordinates of integer points are truncated
+    #[inline]
+    pub fn midpoint(&self, other: &Point3D<N>) -> Point3D<N> {
+        let two = N::one() + N::one();
+        Point3D::init((self.x + other.x) / two, (self.y + other.y) / two, (self.z + other.z) / two)
+    }
+
+    /// Returns the linear interpolation between two points
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: second point
+    /// * `t`: interpolation factor, the point itself for `0` and `other`
+    ///   for `1`
+    ///
+    /// # Remarks
+    ///
+    /// * A factor out of `[0, 1]` extrapolates along the line of the points
+    #[inline]
+    pub fn lerp(&self, other: &Point3D<N>, t: N) -> Point3D<N> {
+        *self + (*other - *self) * t
+    }
+
+    /// Returns the centroid (the mean point) of a set of points
+    ///
+    /// # Arguments
+    ///
+    /// * `points`: points to average
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `None` if there are no points
+    #[inline]
+    pub fn centroid(points: &[Point3D<N>]) -> Option<Point3D<N>> where N: NumCast {
+        if points.is_empty() {
+            return None;
+        }
+        let count: N = NumCast::from(points.len())?;
+        let zero = Point3D::init(N::zero(), N::zero(), N::zero());
+        let sum = points.iter().fold(zero, |acc, p| acc + Vector3D::from(*p));
+        Some(Point3D::init(sum.x / count, sum.y / count, sum.z / count))
+    }
+
+    /// Returns the homogeneous coordinates of the point: `(x, y, z, 1)`
+    #[inline]
+    pub fn to_homogeneous(&self) -> Vector4D<N> {
+        Vector4D::init(self.x, self.y, self.z, N::one())
+    }
+
+    /// Returns the point of some homogeneous coordinates `(x, y, z, w)`:
+    /// `(x / w, y / w, z / w)`
+    ///
+    /// # Arguments
+    ///
+    /// * `v`: homogeneous coordinates
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `None` if `w` is zero (a direction, not a point)
+    #[inline]
+    pub fn from_homogeneous(v: &Vector4D<N>) -> Option<Point3D<N>> {
+        if v.w == N::zero() {
+            return None;
+        }
+        Some(Point3D::init(v.x / v.w, v.y / v.w, v.z / v.w))
+    }
+}
+
+impl<N: Copy + Num> Point2D<N> {
+
+    /// Returns the `x` coordinate
+    #[inline]
+    pub fn x(&self) -> N {
+        self.x
+    }
+
+    /// Returns the `y` coordinate
+    #[inline]
+    pub fn y(&self) -> N {
+        self.y
+    }
+
+    /// Initializes a Point2D with default coordinates' values
+    #[inline]
+    pub fn new() -> Point2D<N> where N: Default {
+        Point2D {x: N::default(), y: N::default()}
+    }
+
+    /// Initializes a Point2D with specified coordinates' values
+    ///
+    /// # Arguments
+    ///
+    /// * `x`: X coordinate
+    /// * `y`: Y coordinate
+    #[inline]
+    pub fn init(x: N, y: N) -> Point2D<N> {
+        Point2D {x, y}
+    }
+
+    /// Modifies the `X` coordinate
+    ///
+    /// # Arguments
+    ///
+    /// * `new_x`: new X value
+    #[inline]
+    pub fn set_x(&mut self, new_x: N) {
+        self.x = new_x;
+    }
+
+    /// Modifies the `Y` coordinate
+    ///
+    /// # Arguments
+    ///
+    /// * `new_y`: new Y value
+    #[inline]
+    pub fn set_y(&mut self, new_y: N) {
+        self.y = new_y;
+    }
+
+    /// Modifies all coordinates
+    ///
+    /// # Arguments
+    ///
+    /// * `new_x`: new X value
+    /// * `new_y`: new Y value
+    #[inline]
+    pub fn set(&mut self, new_x: N, new_y: N) {
+        self.x = new_x;
+        self.y = new_y;
+    }
+
+    /// Returns the Euclidean Distance between two points
+    ///
+    /// # Arguments
+    ///
+    /// * `a`: first point
+    /// * `b`: second point
+    #[inline]
+    pub fn eucl_distance(a: &Point2D<N>, b: &Point2D<N>) -> f64 where N: Into<f64> {
+        let val: f64 = (pow(a.x() - b.x(), 2) + pow(a.y() - b.y(), 2)).into();
+        val.sqrt()
+    }
+
+    /// Returns the point in the middle of two points
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: second point
+    ///
+    /// # Remarks
+    ///
+    /// * The coordinates of integer points are truncated
+    #[inline]
+    pub fn midpoint(&self, other: &Point2D<N>) -> Point2D<N> {
+        let two = N::one() + N::one();
+        Point2D::init((self.x + other.x) / two, (self.y + other.y) / two)
+    }
+
+    /// Returns the linear interpolation between two points
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: second point
+    /// * `t`: interpolation factor, the point itself for `0` and `other`
+    ///   for `1`
+    #[inline]
+    pub fn lerp(&self, other: &Point2D<N>, t: N) -> Point2D<N> {
+        *self + (*other - *self) * t
+    }
+
+    /// Returns the centroid (the mean point) of a set of points
+    ///
+    /// # Arguments
+    ///
+    /// * `points`: points to average
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `None` if there are no points
+    #[inline]
+    pub fn centroid(points: &[Point2D<N>]) -> Option<Point2D<N>> where N: NumCast {
+        if points.is_empty() {
+            return None;
+        }
+        let count: N = NumCast::from(points.len())?;
+        let zero = Point2D::init(N::zero(), N::zero());
+        let sum = points.iter().fold(zero, |acc, p| acc + Vector2D::from(*p));
+        Some(Point2D::init(sum.x / count, sum.y / count))
+    }
+
+    /// Returns the homogeneous coordinates of the point: `(x, y, 1)`
+    #[inline]
+    pub fn to_homogeneous(&self) -> Vector3D<N> {
+        Vector3D::init(self.x, self.y, N::one())
+    }
+
+    /// Returns the point of some homogeneous coordinates `(x, y, w)`:
+    /// `(x / w, y / w)`
+    ///
+    /// # Arguments
+    ///
+    /// * `v`: homogeneous coordinates
+    ///
+    /// # Remarks
+    ///
+    /// * Returns `None` if `w` is zero (a direction, not a point)
+    #[inline]
+    pub fn from_homogeneous(v: &Vector3D<N>) -> Option<Point2D<N>> {
+        if v.z == N::zero() {
+            return None;
+        }
+        Some(Point2D::init(v.x / v.z, v.y / v.z))
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -130,9 +366,189 @@ impl<N: Copy + PartialEq> PartialEq for Point3D<N> {
     }
 }
 
+/// Difference ´-´ of two points: the vector from `other` to `self`
+impl<N: Copy + Num> Sub for Point3D<N> {
+    type Output = Vector3D<N>;
+
+    fn sub(self, other: Point3D<N>) -> Vector3D<N> {
+        Vector3D::init(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+/// Translation ´+´ of a point by a vector
+impl<N: Copy + Num> Add<Vector3D<N>> for Point3D<N> {
+    type Output = Point3D<N>;
+
+    fn add(self, other: Vector3D<N>) -> Point3D<N> {
+        Point3D::init(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+/// Translation ´-´ of a point by the opposite of a vector
+impl<N: Copy + Num> Sub<Vector3D<N>> for Point3D<N> {
+    type Output = Point3D<N>;
+
+    fn sub(self, other: Vector3D<N>) -> Point3D<N> {
+        Point3D::init(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+/// Translation ´+=´ of a point by a vector
+impl<N: Copy + Num> AddAssign<Vector3D<N>> for Point3D<N> {
+    fn add_assign(&mut self, other: Vector3D<N>) {
+        *self = *self + other;
+    }
+}
+
+/// Translation ´-=´ of a point by the opposite of a vector
+impl<N: Copy + Num> SubAssign<Vector3D<N>> for Point3D<N> {
+    fn sub_assign(&mut self, other: Vector3D<N>) {
+        *self = *self - other;
+    }
+}
+
+/// Conversion of a position vector (from the origin) into a point
+impl<N: Copy> From<Vector3D<N>> for Point3D<N> {
+    fn from(v: Vector3D<N>) -> Point3D<N> {
+        Point3D { x: v.x, y: v.y, z: v.z }
+    }
+}
+
+/// Conversion of a point into its position vector (from the origin)
+impl<N: Copy> From<Point3D<N>> for Vector3D<N> {
+    fn from(p: Point3D<N>) -> Vector3D<N> {
+        Vector3D { x: p.x, y: p.y, z: p.z }
+    }
+}
+
+/// Conversion of an array `[x, y, z]` into a point
+impl<N: Copy> From<[N; 3]> for Point3D<N> {
+    fn from(c: [N; 3]) -> Point3D<N> {
+        Point3D { x: c[0], y: c[1], z: c[2] }
+    }
+}
+
+/// Conversion of a point into an array `[x, y, z]`
+impl<N: Copy> From<Point3D<N>> for [N; 3] {
+    fn from(p: Point3D<N>) -> [N; 3] {
+        [p.x, p.y, p.z]
+    }
+}
+
+/// Conversion of a tuple `(x, y, z)` into a point
+impl<N: Copy> From<(N, N, N)> for Point3D<N> {
+    fn from(c: (N, N, N)) -> Point3D<N> {
+        Point3D { x: c.0, y: c.1, z: c.2 }
+    }
+}
+
+/// Conversion of a point into a tuple `(x, y, z)`
+impl<N: Copy> From<Point3D<N>> for (N, N, N) {
+    fn from(p: Point3D<N>) -> (N, N, N) {
+        (p.x, p.y, p.z)
+    }
+}
+
 /// Display implementation for Point3D
 impl<N: Copy + Num> fmt::Display for Point3D<N> where N: Display {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "[ {x}, {y}, {z} ]", x = self.x, y = self.y, z = self.z)
     }
 }
+
+/// Equivalence ´==´ implementation for Point2D
+impl<N: Copy + PartialEq> PartialEq for Point2D<N> {
+    fn eq(&self, other: &Point2D<N>) -> bool {
+        self.x == other.x && self.y == other.y
+    }
+}
+
+/// Difference ´-´ of two points: the vector from `other` to `self`
+impl<N: Copy + Num> Sub for Point2D<N> {
+    type Output = Vector2D<N>;
+
+    fn sub(self, other: Point2D<N>) -> Vector2D<N> {
+        Vector2D::init(self.x - other.x, self.y - other.y)
+    }
+}
+
+/// Translation ´+´ of a point by a vector
+impl<N: Copy + Num> Add<Vector2D<N>> for Point2D<N> {
+    type Output = Point2D<N>;
+
+    fn add(self, other: Vector2D<N>) -> Point2D<N> {
+        Point2D::init(self.x + other.x, self.y + other.y)
+    }
+}
+
+/// Translation ´-´ of a point by the opposite of a vector
+impl<N: Copy + Num> Sub<Vector2D<N>> for Point2D<N> {
+    type Output = Point2D<N>;
+
+    fn sub(self, other: Vector2D<N>) -> Point2D<N> {
+        Point2D::init(self.x - other.x, self.y - other.y)
+    }
+}
+
+/// Translation ´+=´ of a point by a vector
+impl<N: Copy + Num> AddAssign<Vector2D<N>> for Point2D<N> {
+    fn add_assign(&mut self, other: Vector2D<N>) {
+        *self = *self + other;
+    }
+}
+
+/// Translation ´-=´ of a point by the opposite of a vector
+impl<N: Copy + Num> SubAssign<Vector2D<N>> for Point2D<N> {
+    fn sub_assign(&mut self, other: Vector2D<N>) {
+        *self = *self - other;
+    }
+}
+
+/// Conversion of a position vector (from the origin) into a point
+impl<N: Copy> From<Vector2D<N>> for Point2D<N> {
+    fn from(v: Vector2D<N>) -> Point2D<N> {
+        Point2D { x: v.x, y: v.y }
+    }
+}
+
+/// Conversion of a point into its position vector (from the origin)
+impl<N: Copy> From<Point2D<N>> for Vector2D<N> {
+    fn from(p: Point2D<N>) -> Vector2D<N> {
+        Vector2D { x: p.x, y: p.y }
+    }
+}
+
+/// Conversion of an array `[x, y]` into a point
+impl<N: Copy> From<[N; 2]> for Point2D<N> {
+    fn from(c: [N; 2]) -> Point2D<N> {
+        Point2D { x: c[0], y: c[1] }
+    }
+}
+
+/// Conversion of a point into an array `[x, y]`
+impl<N: Copy> From<Point2D<N>> for [N; 2] {
+    fn from(p: Point2D<N>) -> [N; 2] {
+        [p.x, p.y]
+    }
+}
+
+/// Conversion of a tuple `(x, y)` into a point
+impl<N: Copy> From<(N, N)> for Point2D<N> {
+    fn from(c: (N, N)) -> Point2D<N> {
+        Point2D { x: c.0, y: c.1 }
+    }
+}
+
+/// Conversion of a point into a tuple `(x, y)`
+impl<N: Copy> From<Point2D<N>> for (N, N) {
+    fn from(p: Point2D<N>) -> (N, N) {
+        (p.x, p.y)
+    }
+}
+
+/// Display implementation for Point2D
+impl<N: Copy + Num> fmt::Display for Point2D<N> where N: Display {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[ {x}, {y} ]", x = self.x, y = self.y)
+    }
+}
@@ -1,7 +1,10 @@
-use num::Num;
+use num::{Float, Num, NumCast};
 use num::pow;
 use std::fmt;
 use std::ops::{Add, Sub, Mul, Neg};
+use linspace::matrix::Matrix3;
+use linspace::point::Point3D;
+use linspace::vector::Vector3D;
 
 /// Quaternion represents a three dimensional component (x, y, z) with a definied
 /// amount of rotation (w).
@@ -17,6 +20,33 @@ pub struct Quat<N: Copy> {
     w: N
 }
 
+/// Order of the axes of the Euler angles
+///
+/// # Remarks
+///
+/// * The rotations are intrinsic: `XYZ` rotates about the X axis, then about
+///   the new Y axis and then about the newest Z axis, which is the same as
+///   rotating about the fixed axes in the reverse order (Z, Y and X).
+/// * The first six orders are the Tait-Bryan angles (three different axes)
+///   and the last six are the proper Euler angles (first and last axes are
+///   equal).
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EulerOrder {
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX,
+    XYX,
+    XZX,
+    YXY,
+    YZY,
+    ZXZ,
+    ZYZ,
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Inherent methods
 ////////////////////////////////////////////////////////////////////////////////
@@ -188,6 +218,248 @@ impl<N: Copy + Num> Quat<N> { // implementation of Quat<N>
             w: (angle * 0.5).cos()
         }
     }
+
+    /// Returns the unit quaternion of a rotation about an axis
+    ///
+    /// # Arguments
+    ///
+    /// * `axis`: direction of the axis of rotation, it's normalized
+    /// * `angle`: angle of the rotation, in radians
+    ///
+    /// # Remarks
+    ///
+    /// * The values of the rotation about the zero vector are `NaN`
+    #[inline]
+    pub fn from_axis_angle(axis: &Vector3D<N>, angle: N) -> Quat<N> where N: Float {
+        let u = axis.norm();
+        let (sin, cos) = (angle / (N::one() + N::one())).sin_cos();
+        Quat { x: u.x * sin, y: u.y * sin, z: u.z * sin, w: cos }
+    }
+
+    /// Returns the axis and the angle of the rotation of the quaternion
+    ///
+    /// # Remarks
+    ///
+    /// * The axis is a unit vector and the angle is in `[0, pi]`, in radians
+    /// * The axis of the identity rotation is undefined, `(1, 0, 0)` is
+    ///   returned with a zero angle
+    #[inline]
+    pub fn to_axis_angle(&self) -> (Vector3D<N>, N) where N: Float {
+        // q and -q are the same rotation, the one with w >= 0 has the
+        // smaller angle
+        let q = if self.w < N::zero() { -*self } else { *self };
+        let v = Vector3D::init(q.x, q.y, q.z);
+        let sin = v.dot(&v).sqrt();
+        if sin == N::zero() {
+            return (Vector3D::init(N::one(), N::zero(), N::zero()), N::zero());
+        }
+        (v / sin, (sin.atan2(q.w)) * (N::one() + N::one()))
+    }
+
+    /// Returns the rotation matrix of the quaternion (see `Matrix3::from_quat`)
+    #[inline]
+    pub fn to_rotation_matrix(&self) -> Matrix3<N> where N: Float {
+        Matrix3::from_quat(self)
+    }
+
+    /// Returns the unit quaternion of a rotation matrix
+    ///
+    /// # Arguments
+    ///
+    /// * `m`: orthogonal matrix with determinant one
+    ///
+    /// # Remarks
+    ///
+    /// * The result is not meaningful if the matrix is not a rotation
+    #[inline]
+    pub fn from_rotation_matrix(m: &Matrix3<N>) -> Quat<N> where N: Float {
+        let (one, two) = (N::one(), N::one() + N::one());
+        let trace = m[(0, 0)] + m[(1, 1)] + m[(2, 2)];
+
+        // divides by the biggest component to keep the precision
+        if trace > N::zero() {
+            let s = (trace + one).sqrt() * two;
+            Quat { x: (m[(2, 1)] - m[(1, 2)]) / s,
+                   y: (m[(0, 2)] - m[(2, 0)]) / s,
+                   z: (m[(1, 0)] - m[(0, 1)]) / s,
+                   w: s / (two + two) }
+        } else if m[(0, 0)] > m[(1, 1)] && m[(0, 0)] > m[(2, 2)] {
+            let s = (one + m[(0, 0)] - m[(1, 1)] - m[(2, 2)]).sqrt() * two;
+            Quat { x: s / (two + two),
+                   y: (m[(0, 1)] + m[(1, 0)]) / s,
+                   z: (m[(0, 2)] + m[(2, 0)]) / s,
+                   w: (m[(2, 1)] - m[(1, 2)]) / s }
+        } else if m[(1, 1)] > m[(2, 2)] {
+            let s = (one + m[(1, 1)] - m[(0, 0)] - m[(2, 2)]).sqrt() * two;
+            Quat { x: (m[(0, 1)] + m[(1, 0)]) / s,
+                   y: s / (two + two),
+                   z: (m[(1, 2)] + m[(2, 1)]) / s,
+                   w: (m[(0, 2)] - m[(2, 0)]) / s }
+        } else {
+            let s = (one + m[(2, 2)] - m[(0, 0)] - m[(1, 1)]).sqrt() * two;
+            Quat { x: (m[(0, 2)] + m[(2, 0)]) / s,
+                   y: (m[(1, 2)] + m[(2, 1)]) / s,
+                   z: s / (two + two),
+                   w: (m[(1, 0)] - m[(0, 1)]) / s }
+        }
+    }
+
+    /// Returns the unit quaternion of a rotation given by Euler angles
+    ///
+    /// # Arguments
+    ///
+    /// * `order`: axes of the rotations (see `EulerOrder`)
+    /// * `a`: angle about the first axis, in radians
+    /// * `b`: angle about the second axis, in radians
+    /// * `c`: angle about the third axis, in radians
+    #[inline]
+    pub fn from_euler(order: EulerOrder, a: N, b: N, c: N) -> Quat<N> where N: Float {
+        let (i, j, k) = order.axes();
+        Quat::elementary(i, a) * Quat::elementary(j, b) * Quat::elementary(k, c)
+    }
+
+    /// Returns the Euler angles `(a, b, c)` of the rotation of the quaternion
+    /// (see `from_euler`)
+    ///
+    /// # Arguments
+    ///
+    /// * `order`: axes of the rotations (see `EulerOrder`)
+    ///
+    /// # Remarks
+    ///
+    /// * `a` and `c` are in `[-pi, pi]`; `b` is in `[-pi/2, pi/2]` for the
+    ///   Tait-Bryan orders and in `[0, pi]` for the proper Euler orders
+    /// * In gimbal lock (when the first and third axes are aligned) just the
+    ///   combination of `a` and `c` is defined, so `c` is zero
+    /// * The quaternion doesn't need to be unitary
+    #[inline]
+    pub fn to_euler(&self, order: EulerOrder) -> (N, N, N) where N: Float {
+        // direct method of Bernardes and Viollet (2022), for the extrinsic
+        // rotations, which are the intrinsic ones in reverse order
+        let (k, j, i) = order.axes();
+        let symmetric = i == k;
+        let k = if symmetric { 3 - i - j } else { k };
+        let sign: N = NumCast::from((i as i32 - j as i32) * (j as i32 - k as i32) * (k as i32 - i as i32) / 2).unwrap();
+
+        let v = [self.x, self.y, self.z];
+        let (a, b, c, d) = if symmetric {
+            (self.w, v[i], v[j], v[k] * sign)
+        } else {
+            (self.w - v[j], v[i] + v[k] * sign, v[j] + self.w, v[k] * sign - v[i])
+        };
+
+        let two = N::one() + N::one();
+        let pi: N = NumCast::from(::std::f64::consts::PI).unwrap();
+        let tol: N = NumCast::from(1e-7).unwrap();
+
+        let mut second = two * c.hypot(d).atan2(a.hypot(b));
+        let half_sum = b.atan2(a);
+        let half_diff = d.atan2(c);
+        let (mut first, mut third) = if second.abs() <= tol {
+            (N::zero(), two * half_sum)
+        } else if (second - pi).abs() <= tol {
+            (N::zero(), two * half_diff)
+        } else {
+            (half_sum - half_diff, half_sum + half_diff)
+        };
+
+        if !symmetric {
+            third = third * sign;
+            second = second - pi / two;
+        }
+        first = wrap_angle(first, pi);
+        third = wrap_angle(third, pi);
+        (third, second, first)
+    }
+
+    /// Returns a vector rotated by the quaternion: `q * v * q^-1`
+    ///
+    /// # Arguments
+    ///
+    /// * `v`: vector to rotate
+    ///
+    /// # Remarks
+    ///
+    /// * The quaternion doesn't need to be unitary, but the unit ones skip
+    ///   the division by the squared magnitude
+    /// * The values of the rotation by the zero quaternion are `NaN`
+    #[inline]
+    pub fn rotate_vector(&self, v: &Vector3D<N>) -> Vector3D<N> where N: Float {
+        let two = N::one() + N::one();
+        let u = Vector3D::init(self.x, self.y, self.z);
+        let uv = cross(&u, v);
+        let n = u.dot(&u) + self.w * self.w;
+        let tol = N::epsilon() * two * two * two;
+
+        if (n - N::one()).abs() <= tol {
+            // v + 2w (u x v) + 2 u x (u x v)
+            return *v + (uv * self.w + cross(&u, &uv)) * two;
+        }
+        // ((w^2 - u.u) v + 2 (u.v) u + 2w (u x v)) / |q|^2
+        (*v * (self.w * self.w - u.dot(&u)) + u * (two * u.dot(v)) + uv * (two * self.w)) / n
+    }
+
+    /// Returns a point rotated by the quaternion about a pivot point
+    ///
+    /// # Arguments
+    ///
+    /// * `p`: point to rotate
+    /// * `pivot`: center of the rotation, the axis of the quaternion passes
+    ///   through it
+    ///
+    /// # Remarks
+    ///
+    /// * See `rotate_vector`
+    #[inline]
+    pub fn rotate_point(&self, p: &Point3D<N>, pivot: &Point3D<N>) -> Point3D<N> where N: Float {
+        *pivot + self.rotate_vector(&(*p - *pivot))
+    }
+
+    // internal use: rotation about the axis `0` (X), `1` (Y) or `2` (Z)
+    fn elementary(axis: usize, angle: N) -> Quat<N> where N: Float {
+        let (sin, cos) = (angle / (N::one() + N::one())).sin_cos();
+        let mut v = [N::zero(); 3];
+        v[axis] = sin;
+        Quat { x: v[0], y: v[1], z: v[2], w: cos }
+    }
+}
+
+impl EulerOrder {
+    // internal use: indices of the axes, `0` (X), `1` (Y) or `2` (Z)
+    fn axes(self) -> (usize, usize, usize) {
+        match self {
+            EulerOrder::XYZ => (0, 1, 2),
+            EulerOrder::XZY => (0, 2, 1),
+            EulerOrder::YXZ => (1, 0, 2),
+            EulerOrder::YZX => (1, 2, 0),
+            EulerOrder::ZXY => (2, 0, 1),
+            EulerOrder::ZYX => (2, 1, 0),
+            EulerOrder::XYX => (0, 1, 0),
+            EulerOrder::XZX => (0, 2, 0),
+            EulerOrder::YXY => (1, 0, 1),
+            EulerOrder::YZY => (1, 2, 1),
+            EulerOrder::ZXZ => (2, 0, 2),
+            EulerOrder::ZYZ => (2, 1, 2),
+        }
+    }
+}
+
+// internal use: cross product without the bounds of `Vector3D::cross`
+fn cross<N: Copy + Num>(a: &Vector3D<N>, b: &Vector3D<N>) -> Vector3D<N> {
+    Vector3D::init(a.y * b.z - a.z * b.y,
+                   a.z * b.x - a.x * b.z,
+                   a.x * b.y - a.y * b.x)
+}
+
+// internal use: moves an angle in `[-2 pi, 2 pi]` to `[-pi, pi]`
+fn wrap_angle<N: Float>(angle: N, pi: N) -> N {
+    if angle > pi {
+        angle - (pi + pi)
+    } else if angle < -pi {
+        angle + (pi + pi)
+    } else {
+        angle
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -242,6 +514,15 @@ impl<N: Copy + Num> Mul for Quat<N> {
     }
 }
 
+/// Rotation ´*´ of a Vector3D by a Quat (see `rotate_vector`)
+impl<N: Float> Mul<Vector3D<N>> for Quat<N> {
+    type Output = Vector3D<N>;
+
+    fn mul(self, v: Vector3D<N>) -> Vector3D<N> {
+        self.rotate_vector(&v)
+    }
+}
+
 /// Display implementation for Quat
 impl<N: Copy + Num> fmt::Display for Quat<N> where N: fmt::Display {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -17,6 +17,35 @@ pub struct Vector3D<N: Copy> {
     pub z: N,
 }
 
+/// Vector of 2 dimensions with a defined coordinates
+///
+/// # Remarks
+///
+/// * This struct is implemented to be used with numerical types, not tested
+///   for strings, bools, or other types.
+/// * The origen is [0, 0].
+#[derive(Clone, Copy, Default)]
+pub struct Vector2D<N: Copy> {
+    pub x: N,
+    pub y: N,
+}
+
+/// Vector of 4 dimensions with a defined coordinates, mostly used for the
+/// homogeneous coordinates of the 3D space
+///
+/// # Remarks
+///
+/// * This struct is implemented to be used with numerical types, not tested
+///   for strings, bools, or other types.
+/// * The origen is [0, 0, 0, 0].
+#[derive(Clone, Copy, Default)]
+pub struct Vector4D<N: Copy> {
+    pub x: N,
+    pub y: N,
+    pub z: N,
+    pub w: N,
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Inherent methods
 ////////////////////////////////////////////////////////////////////////////////
@@ -209,6 +238,296 @@ impl<N: Copy + Num> Vector3D<N> {
             z: func(self.z)
         }
     }
+
+    /// Returns the homogeneous coordinates of the vector: `(x, y, z, 0)`
+    #[inline]
+    pub fn to_homogeneous(&self) -> Vector4D<N> {
+        Vector4D::init(self.x, self.y, self.z, N::zero())
+    }
+}
+
+impl<N: Copy + Num> Vector2D<N> {
+
+    /// Returns the `x` coordinate
+    #[inline]
+    pub fn x(&self) -> N {
+        self.x
+    }
+
+    /// Returns the `y` coordinate
+    #[inline]
+    pub fn y(&self) -> N {
+        self.y
+    }
+
+    /// Modifies the `X` coordinate
+    ///
+    /// # Arguments
+    ///
+    /// * `new_x`: new X value
+    #[inline]
+    pub fn set_x(&mut self, new_x: N) {
+        self.x = new_x;
+    }
+
+    /// Modifies the `Y` coordinate
+    ///
+    /// # Arguments
+    ///
+    /// * `new_y`: new Y value
+    #[inline]
+    pub fn set_y(&mut self, new_y: N) {
+        self.y = new_y;
+    }
+
+    /// Modifies all coordinates
+    ///
+    /// # Arguments
+    ///
+    /// * `new_x`: new X value
+    /// * `new_y`: new Y value
+    #[inline]
+    pub fn set(&mut self, new_x: N, new_y: N) {
+        self.x = new_x;
+        self.y = new_y;
+    }
+
+    /// Initializes a Vector2D with default coordinates' values
+    #[inline]
+    pub fn new() -> Vector2D<N> where N: Default {
+        Vector2D { x: N::default(),
+                   y: N::default() }
+    }
+
+    /// Initializes a Vector2D with given coordinates
+    ///
+    /// # Arguments
+    ///
+    /// * `x`: X value
+    /// * `y`: Y value
+    #[inline]
+    pub fn init(x: N, y: N) -> Vector2D<N> {
+        Vector2D { x, y }
+    }
+
+    /// Scale a Vector with a given number
+    ///
+    /// # Arguments
+    ///
+    /// * `scalar`: scalar value
+    #[inline]
+    pub fn scale(self, scalar: N) -> Vector2D<N> {
+        Vector2D::<N>::init(self.x * scalar, self.y * scalar)
+    }
+
+    /// Scale a vector with a given vector
+    ///
+    /// # Arguments
+    ///
+    /// * `vec`: vector value
+    #[inline]
+    pub fn scale_vec(self, vec: &Vector2D<N>) -> Vector2D<N> {
+        Vector2D::<N>::init(self.x * vec.x(), self.y * vec.y())
+    }
+
+    /// Dot operation for two vectors
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: second vector for the dot
+    #[inline]
+    pub fn dot(&self, other: &Vector2D<N>) -> N {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// Perp-dot product (2D cross product) of two vectors: the `z` coordinate
+    /// of the cross product of both vectors in the plane `z = 0`
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: second vector
+    ///
+    /// # Remarks
+    ///
+    /// * It's positive if `other` is counterclockwise from the vector
+    #[inline]
+    pub fn perp_dot(&self, other: &Vector2D<N>) -> N {
+        self.x * other.y - self.y * other.x
+    }
+
+    /// Returns the vector rotated 90 degrees counterclockwise
+    #[inline]
+    pub fn perp(&self) -> Vector2D<N> where N: Neg<Output = N> {
+        Vector2D::init(-self.y, self.x)
+    }
+
+    /// Returns the signed angle from the vector to another one, in radians
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: second vector
+    ///
+    /// # Remarks
+    ///
+    /// * It's in `[-pi, pi]`, positive if `other` is counterclockwise
+    #[inline]
+    pub fn angle(&self, other: &Vector2D<N>) -> N where N: Float {
+        self.perp_dot(other).atan2(self.dot(other))
+    }
+
+    /// Returns the vector rotated counterclockwise
+    ///
+    /// # Arguments
+    ///
+    /// * `angle`: angle of the rotation, in radians
+    #[inline]
+    pub fn rotate(&self, angle: N) -> Vector2D<N> where N: Float {
+        let (sin, cos) = angle.sin_cos();
+        Vector2D::init(self.x * cos - self.y * sin, self.x * sin + self.y * cos)
+    }
+
+    /// Normalize the vector
+    ///
+    /// # Remarks
+    ///
+    /// * Since this operation requires an `sqrt`, it's just available for float vectors
+    #[inline]
+    pub fn norm(self) -> Vector2D<N> where N: Float {
+        let value = self.dot(&self);
+
+        self.scale(value.sqrt().recip())
+    }
+
+    /// Return the Eucledian distance from vector
+    #[inline]
+    pub fn dist(&self) -> f64 where N: Into<f64> {
+        (pow(self.x, 2) + pow(self.y, 2)).into().sqrt()
+    }
+
+    #[inline]
+    pub fn max(&self) -> N where N: PartialOrd {
+        if self.x >= self.y { self.x } else { self.y }
+    }
+
+    #[inline]
+    pub fn min(&self) -> N where N: PartialOrd {
+        if self.x <= self.y { self.x } else { self.y }
+    }
+
+    /// Returns the homogeneous coordinates of the vector: `(x, y, 0)`
+    #[inline]
+    pub fn to_homogeneous(&self) -> Vector3D<N> {
+        Vector3D::init(self.x, self.y, N::zero())
+    }
+}
+
+impl<N: Copy + Num> Vector4D<N> {
+
+    /// Returns the `x` coordinate
+    #[inline]
+    pub fn x(&self) -> N {
+        self.x
+    }
+
+    /// Returns the `y` coordinate
+    #[inline]
+    pub fn y(&self) -> N {
+        self.y
+    }
+
+    /// Returns the `z` coordinate
+    #[inline]
+    pub fn z(&self) -> N {
+        self.z
+    }
+
+    /// Returns the `w` coordinate
+    #[inline]
+    pub fn w(&self) -> N {
+        self.w
+    }
+
+    /// Modifies all coordinates
+    ///
+    /// # Arguments
+    ///
+    /// * `new_x`: new X value
+    /// * `new_y`: new Y value
+    /// * `new_z`: new Z value
+    /// * `new_w`: new W value
+    #[inline]
+    pub fn set(&mut self, new_x: N, new_y: N, new_z: N, new_w: N) {
+        self.x = new_x;
+        self.y = new_y;
+        self.z = new_z;
+        self.w = new_w;
+    }
+
+    /// Initializes a Vector4D with default coordinates' values
+    #[inline]
+    pub fn new() -> Vector4D<N> where N: Default {
+        Vector4D { x: N::default(),
+                   y: N::default(),
+                   z: N::default(),
+                   w: N::default() }
+    }
+
+    /// Initializes a Vector4D with given coordinates
+    ///
+    /// # Arguments
+    ///
+    /// * `x`: X value
+    /// * `y`: Y value
+    /// * `z`: Z value
+    /// * `w`: W value
+    #[inline]
+    pub fn init(x: N, y: N, z: N, w: N) -> Vector4D<N> {
+        Vector4D { x, y, z, w }
+    }
+
+    /// Scale a Vector with a given number
+    ///
+    /// # Arguments
+    ///
+    /// * `scalar`: scalar value
+    #[inline]
+    pub fn scale(self, scalar: N) -> Vector4D<N> {
+        Vector4D::<N>::init(self.x * scalar, self.y * scalar, self.z * scalar, self.w * scalar)
+    }
+
+    /// Dot operation for two vectors
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: second vector for the dot
+    #[inline]
+    pub fn dot(&self, other: &Vector4D<N>) -> N {
+        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+    }
+
+    /// Normalize the vector
+    ///
+    /// # Remarks
+    ///
+    /// * Since this operation requires an `sqrt`, it's just available for float vectors
+    #[inline]
+    pub fn norm(self) -> Vector4D<N> where N: Float {
+        let value = self.dot(&self);
+
+        self.scale(value.sqrt().recip())
+    }
+
+    /// Return the Eucledian distance from vector
+    #[inline]
+    pub fn dist(&self) -> f64 where N: Into<f64> {
+        (pow(self.x, 2) + pow(self.y, 2) + pow(self.z, 2) + pow(self.w, 2)).into().sqrt()
+    }
+
+    /// Returns the first three coordinates, dropping `w`
+    #[inline]
+    pub fn xyz(&self) -> Vector3D<N> {
+        Vector3D::init(self.x, self.y, self.z)
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -289,6 +608,33 @@ impl<N: Copy + Num + Neg<Output = N>> Neg for Vector3D<N> {
     }
 }
 
+/// Conversion of an array `[x, y, z]` into a vector
+impl<N: Copy> From<[N; 3]> for Vector3D<N> {
+    fn from(c: [N; 3]) -> Vector3D<N> {
+        Vector3D { x: c[0], y: c[1], z: c[2] }
+    }
+}
+
+/// Conversion of a vector into an array `[x, y, z]`
+impl<N: Copy> From<Vector3D<N>> for [N; 3] {
+    fn from(v: Vector3D<N>) -> [N; 3] {
+        [v.x, v.y, v.z]
+    }
+}
+
+/// Conversion of a tuple `(x, y, z)` into a vector
+impl<N: Copy> From<(N, N, N)> for Vector3D<N> {
+    fn from(c: (N, N, N)) -> Vector3D<N> {
+        Vector3D { x: c.0, y: c.1, z: c.2 }
+    }
+}
+
+/// Conversion of a vector into a tuple `(x, y, z)`
+impl<N: Copy> From<Vector3D<N>> for (N, N, N) {
+    fn from(v: Vector3D<N>) -> (N, N, N) {
+        (v.x, v.y, v.z)
+    }
+}
 
 /// Display implementation for Vector3D
 impl<N: Copy + Num> fmt::Display for Vector3D<N> where N: fmt::Display {
@@ -296,3 +642,189 @@ impl<N: Copy + Num> fmt::Display for Vector3D<N> where N: fmt::Display {
         write!(f, "( {x}, {y}, {z} )", x = self.x, y = self.y, z = self.z)
     }
 }
+
+/// Equivalence ´==´ implementation for Vector2D
+impl<N: Copy + PartialEq> PartialEq for Vector2D<N> {
+    fn eq(&self, other: &Vector2D<N>) -> bool {
+        self.x == other.x && self.y == other.y
+    }
+}
+
+/// Add implementation `+` for Vector2D
+impl<N: Copy + Num> Add for Vector2D<N> {
+    type Output = Vector2D<N>;
+
+    fn add(self, other: Vector2D<N>) -> Vector2D<N> {
+        Vector2D { x: self.x + other.x, y: self.y + other.y }
+    }
+}
+
+/// Sub implementation `-` for Vector2D
+impl<N: Copy + Num> Sub for Vector2D<N> {
+    type Output = Vector2D<N>;
+
+    fn sub(self, other: Vector2D<N>) -> Vector2D<N> {
+        Vector2D { x: self.x - other.x, y: self.y - other.y }
+    }
+}
+
+/// Mul implementation `*` for Vector2D
+impl<N: Copy + Num> Mul for Vector2D<N> {
+    type Output = Vector2D<N>;
+
+    fn mul(self, other: Vector2D<N>) -> Vector2D<N> {
+        Vector2D { x: self.x * other.x, y: self.y * other.y }
+    }
+}
+
+impl<N: Copy + Num> Mul<N> for Vector2D<N> {
+    type Output = Vector2D<N>;
+
+    fn mul(self, other: N) -> Vector2D<N> {
+        Vector2D { x: self.x * other, y: self.y * other }
+    }
+}
+
+impl<N: Copy + Num> Div<N> for Vector2D<N> {
+    type Output = Vector2D<N>;
+
+    fn div(self, other: N) -> Vector2D<N> {
+        Vector2D { x: self.x / other, y: self.y / other }
+    }
+}
+
+impl<N: Copy + Num + Neg<Output = N>> Neg for Vector2D<N> {
+    type Output = Vector2D<N>;
+
+    fn neg(self) -> Vector2D<N> {
+        Vector2D { x: -self.x, y: -self.y }
+    }
+}
+
+/// Conversion of an array `[x, y]` into a vector
+impl<N: Copy> From<[N; 2]> for Vector2D<N> {
+    fn from(c: [N; 2]) -> Vector2D<N> {
+        Vector2D { x: c[0], y: c[1] }
+    }
+}
+
+/// Conversion of a vector into an array `[x, y]`
+impl<N: Copy> From<Vector2D<N>> for [N; 2] {
+    fn from(v: Vector2D<N>) -> [N; 2] {
+        [v.x, v.y]
+    }
+}
+
+/// Conversion of a tuple `(x, y)` into a vector
+impl<N: Copy> From<(N, N)> for Vector2D<N> {
+    fn from(c: (N, N)) -> Vector2D<N> {
+        Vector2D { x: c.0, y: c.1 }
+    }
+}
+
+/// Conversion of a vector into a tuple `(x, y)`
+impl<N: Copy> From<Vector2D<N>> for (N, N) {
+    fn from(v: Vector2D<N>) -> (N, N) {
+        (v.x, v.y)
+    }
+}
+
+/// Display implementation for Vector2D
+impl<N: Copy + Num> fmt::Display for Vector2D<N> where N: fmt::Display {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "( {x}, {y} )", x = self.x, y = self.y)
+    }
+}
+
+/// Equivalence ´==´ implementation for Vector4D
+impl<N: Copy + PartialEq> PartialEq for Vector4D<N> {
+    fn eq(&self, other: &Vector4D<N>) -> bool {
+        self.x == other.x && self.y == other.y && self.z == other.z && self.w == other.w
+    }
+}
+
+/// Add implementation `+` for Vector4D
+impl<N: Copy + Num> Add for Vector4D<N> {
+    type Output = Vector4D<N>;
+
+    fn add(self, other: Vector4D<N>) -> Vector4D<N> {
+        Vector4D { x: self.x + other.x, y: self.y + other.y, z: self.z + other.z, w: self.w + other.w }
+    }
+}
+
+/// Sub implementation `-` for Vector4D
+impl<N: Copy + Num> Sub for Vector4D<N> {
+    type Output = Vector4D<N>;
+
+    fn sub(self, other: Vector4D<N>) -> Vector4D<N> {
+        Vector4D { x: self.x - other.x, y: self.y - other.y, z: self.z - other.z, w: self.w - other.w }
+    }
+}
+
+/// Mul implementation `*` for Vector4D
+impl<N: Copy + Num> Mul for Vector4D<N> {
+    type Output = Vector4D<N>;
+
+    fn mul(self, other: Vector4D<N>) -> Vector4D<N> {
+        Vector4D { x: self.x * other.x, y: self.y * other.y, z: self.z * other.z, w: self.w * other.w }
+    }
+}
+
+impl<N: Copy + Num> Mul<N> for Vector4D<N> {
+    type Output = Vector4D<N>;
+
+    fn mul(self, other: N) -> Vector4D<N> {
+        Vector4D { x: self.x * other, y: self.y * other, z: self.z * other, w: self.w * other }
+    }
+}
+
+impl<N: Copy + Num> Div<N> for Vector4D<N> {
+    type Output = Vector4D<N>;
+
+    fn div(self, other: N) -> Vector4D<N> {
+        Vector4D { x: self.x / other, y: self.y / other, z: self.z / other, w: self.w / other }
+    }
+}
+
+impl<N: Copy + Num + Neg<Output = N>> Neg for Vector4D<N> {
+    type Output = Vector4D<N>;
+
+    fn neg(self) -> Vector4D<N> {
+        Vector4D { x: -self.x, y: -self.y, z: -self.z, w: -self.w }
+    }
+}
+
+/// Conversion of an array `[x, y, z, w]` into a vector
+impl<N: Copy> From<[N; 4]> for Vector4D<N> {
+    fn from(c: [N; 4]) -> Vector4D<N> {
+        Vector4D { x: c[0], y: c[1], z: c[2], w: c[3] }
+    }
+}
+
+/// Conversion of a vector into an array `[x, y, z, w]`
+impl<N: Copy> From<Vector4D<N>> for [N; 4] {
+    fn from(v: Vector4D<N>) -> [N; 4] {
+        [v.x, v.y, v.z, v.w]
+    }
+}
+
+/// Conversion of a tuple `(x, y, z, w)` into a vector
+impl<N: Copy> From<(N, N, N, N)> for Vector4D<N> {
+    fn from(c: (N, N, N, N)) -> Vector4D<N> {
+        Vector4D { x: c.0, y: c.1, z: c.2, w: c.3 }
+    }
+}
+
+/// Conversion of a vector into a tuple `(x, y, z, w)`
+impl<N: Copy> From<Vector4D<N>> for (N, N, N, N) {
+    fn from(v: Vector4D<N>) -> (N, N, N, N) {
+        (v.x, v.y, v.z, v.w)
+    }
+}
+
+/// Display implementation for Vector4D
+impl<N: Copy + Num> fmt::Display for Vector4D<N> where N: fmt::Display {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "( {x}, {y}, {z}, {w} )", x = self.x, y = self.y, z = self.z, w = self.w)
+    }
+}
@@ -1,9 +1,39 @@
 extern crate rsmath as r;
+extern crate num;
 
 #[cfg(test)]
 mod tests {
     use r::algebra::matrix::*;
     use r::algebra::vector::*;
+    use r::algebra::stats::*;
+    use r::algebra::order::*;
+    use r::Error;
+    use num::Complex;
+
+    fn approx_eq(a: &Matrix<f64>, b: &Matrix<f64>, tol: f64) -> bool {
+        if a.nrows() != b.nrows() || a.ncols() != b.ncols() {
+            return false;
+        }
+        for i in 0..a.nrows() {
+            for j in 0..a.ncols() {
+                if (a.get_element(i, j) - b.get_element(i, j)).abs() > tol {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    fn naive_mul(a: &Matrix<f64>, b: &Matrix<f64>) -> Matrix<f64> {
+        let mut m = Matrix::<f64>::zeros(a.nrows(), b.ncols());
+        for i in 0..a.nrows() {
+            for j in 0..b.ncols() {
+                let val = (0..a.ncols()).fold(0.0, |sum, k| sum + a.get_element(i, k) * b.get_element(k, j));
+                m.set_element(i, j, &val);
+            }
+        }
+        m
+    }
 
     // --------------- Matrix TEST ----------------------------------------
 
@@ -61,6 +91,18 @@ mod tests {
         assert_eq!(m.row(0).unwrap().clone(), v);
     }
     #[test]
+    fn matrix_strides_test() {
+        let m = Matrix::<i32>::init(&vec![vec![1, 2, 3], vec![4, 5, 6]]);
+
+        assert_eq!(m.strides(), (3, 1));
+        assert_eq!(m.row(1).unwrap(), &[4, 5, 6]);
+        assert_eq!(m.row_slice(1).unwrap(), &[4, 5, 6]);
+        assert_eq!(m.row_slice(2), None);
+        assert_eq!(m.col(2).unwrap(), vec![3, 6]);
+        assert_eq!(m.row(2), None);
+        assert_eq!(m.col(3), None);
+    }
+    #[test]
     fn matrix_get_push_row_test() {
         let val: Vec<u32> = vec![1, 2, 3, 4, 5];
         let mut m = Matrix::<u32>::init_with_capacity(5, 2);
@@ -420,6 +462,7 @@ mod tests {
         assert_eq!(d.get_element(2, 1), 0);
     }
     #[test]
+    #[allow(deprecated)]
     fn matrix_submatrix_test() {
         let values: Vec<Vec<f32>> = vec![vec![1.0, 4.0], vec![2.0, 5.0], vec![3.0, 6.0]];
         let m = Matrix::<f32>::init(&values);
@@ -494,6 +537,1194 @@ mod tests {
         assert_eq!(i.next().unwrap(), m.get_element(1, 0));
         assert_eq!(i.next().unwrap(), m.get_element(1, 1));
     }
+    #[test]
+    fn matrix_el_iter_end_test() {
+        let m = Matrix::<i32>::init(&vec![vec![1, 3], vec![2, 5], vec![3, 6]]);
+
+        assert_eq!(m.el_iter().collect::<Vec<i32>>(), vec![1, 3, 2, 5, 3, 6]);
+        assert_eq!(m.el_iter_at(4).count(), 2);
+        assert_eq!(m.el_iter_at(10).next(), None);
+        assert_eq!(Matrix::<i32>::new().el_iter().next(), None);
+    }
+    #[test]
+    fn matrix_iter_double_ended_test() {
+        let m = Matrix::<i32>::init(&vec![vec![1, 3], vec![2, 5], vec![3, 6]]);
+
+        assert_eq!(m.row_iter().len(), 3);
+        assert_eq!(m.col_iter().len(), 2);
+        assert_eq!(m.el_iter_at(1).len(), 5);
+        assert_eq!(m.as_view().iter().len(), 6);
+
+        assert_eq!(m.el_iter().rev().collect::<Vec<i32>>(), vec![6, 3, 5, 2, 3, 1]);
+        assert_eq!(m.row_iter().rev().nth(1).unwrap(), &vec![2, 5]);
+        assert_eq!(m.col_iter().next_back().unwrap(), vec![3, 5, 6]);
+        assert_eq!(m.transpose_view().iter().rev().collect::<Vec<i32>>(), vec![6, 5, 3, 3, 2, 1]);
+
+        let mut rows = m.row_iter();
+        assert_eq!(rows.next().unwrap(), &vec![1, 3]);
+        assert_eq!(rows.next_back().unwrap(), &vec![3, 6]);
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows.next().unwrap(), &vec![2, 5]);
+        assert_eq!(rows.next_back(), None);
+    }
+    #[test]
+    fn matrix_iter_test() {
+        let mut m = Matrix::<i32>::init(&vec![vec![1, 3], vec![2, 5]]);
+
+        assert_eq!(m.iter().sum::<i32>(), 11);
+        assert_eq!(m.as_slice(), &[1, 3, 2, 5]);
+        for el in m.iter_mut() {
+            *el *= 2;
+        }
+        for el in &mut m {
+            *el += 1;
+        }
+        assert_eq!((&m).into_iter().cloned().collect::<Vec<i32>>(), vec![3, 7, 5, 11]);
+        m.as_mut_slice()[0] = 0;
+        assert_eq!(m.into_iter().collect::<Vec<i32>>(), vec![0, 7, 5, 11]);
+    }
+    #[test]
+    fn matrix_from_shape_iter_test() {
+        let m = Matrix::<i32>::from_shape_iter(2, 3, 1..7);
+        assert!(m == Matrix::<i32>::init(&vec![vec![1, 2, 3], vec![4, 5, 6]]));
+
+        let squares = Matrix::<i32>::from_shape_iter(2, 2, m.iter().take(4).map(|x| x * x));
+        assert!(squares == Matrix::<i32>::init(&vec![vec![1, 4], vec![9, 16]]));
+
+        assert_eq!(Matrix::<i32>::try_from_shape_iter(2, 3, 1..6).err(),
+                   Some(Error::DimensionMismatch { expected: (6, 1), found: (5, 1) }));
+    }
+    #[test]
+    #[should_panic]
+    fn matrix_from_shape_iter_panic_test() {
+        Matrix::<i32>::from_shape_iter(2, 2, 1..6);
+    }
+    #[test]
+    fn matrix_try_random_test() {
+        assert!(Matrix::<i32>::try_random(2, 2, &[0, 5]).is_ok());
+        assert_eq!(Matrix::<i32>::try_random(2, 2, &[5, 0]).err(), Some(Error::InvalidRange));
+    }
+    #[test]
+    fn matrix_try_set_row_test() {
+        let mut m = Matrix::<i32>::init(&vec![vec![1, 3], vec![2, 5]]);
+
+        assert_eq!(m.try_set_row(2, &[1, 1]),
+                   Err(Error::IndexOutOfBounds { index: 2, size: 2 }));
+        assert_eq!(m.try_set_row(0, &[1, 1, 1]),
+                   Err(Error::DimensionMismatch { expected: (1, 2), found: (1, 3) }));
+        assert_eq!(m.try_set_row(0, &[7, 8]), Ok(()));
+        assert_eq!(m.row(0).unwrap(), &[7, 8]);
+    }
+    #[test]
+    fn matrix_try_set_col_test() {
+        let mut m = Matrix::<i32>::init(&vec![vec![1, 3], vec![2, 5]]);
+
+        assert_eq!(m.try_set_col(3, &[1, 1]),
+                   Err(Error::IndexOutOfBounds { index: 3, size: 2 }));
+        assert_eq!(m.try_set_col(0, &[1]),
+                   Err(Error::DimensionMismatch { expected: (2, 1), found: (1, 1) }));
+    }
+    #[test]
+    fn matrix_try_push_pop_test() {
+        let mut m = Matrix::<i32>::init(&vec![vec![1, 3], vec![2, 5]]);
+
+        assert!(m.try_push_row(vec![1, 2, 3]).is_err());
+        assert!(m.try_push_col(vec![1, 2, 3]).is_err());
+        assert!(m.try_pop_row(2).is_err());
+        assert!(m.try_pop_col(2).is_err());
+        assert!(m.try_swap_row(0, 2).is_err());
+        assert!(m.try_swap_col(2, 0).is_err());
+        assert!(m == Matrix::<i32>::init(&vec![vec![1, 3], vec![2, 5]]));
+    }
+    #[test]
+    fn matrix_try_get_diagonal_test() {
+        let m = Matrix::<i32>::init(&vec![vec![1, 3], vec![2, 5], vec![3, 6]]);
+
+        assert_eq!(m.try_get_diagonal().err(), Some(Error::NotSquare { nrows: 3, ncols: 2 }));
+    }
+    #[test]
+    #[allow(deprecated)]
+    fn matrix_try_submatrix_test() {
+        let m = Matrix::<i32>::init(&vec![vec![1, 3], vec![2, 5], vec![3, 6]]);
+
+        assert_eq!(m.try_submatrix(&[2, 1], &[0, 1]).err(), Some(Error::InvalidRange));
+        assert_eq!(m.try_submatrix(&[0, 3], &[0, 1]).err(),
+                   Some(Error::IndexOutOfBounds { index: 3, size: 3 }));
+        assert_eq!(m.try_submatrix(&[0, 1], &[0, 2]).err(),
+                   Some(Error::IndexOutOfBounds { index: 2, size: 2 }));
+        assert!(m.try_submatrix(&[1, 2], &[1, 1]).unwrap() ==
+                Matrix::<i32>::init(&vec![vec![5], vec![6]]));
+    }
+    #[test]
+    fn matrix_try_add_sub_test() {
+        let m = Matrix::<i32>::init(&vec![vec![1, 3], vec![2, 5]]);
+        let m2 = Matrix::<i32>::init(&vec![vec![1, 3, 2]]);
+
+        let err = Some(Error::DimensionMismatch { expected: (2, 2), found: (1, 3) });
+        assert_eq!(m.try_add(&m2).err(), err);
+        assert_eq!(m.try_sub(&m2).err(), err);
+        assert!(m.try_add(&m).unwrap() == &m + &m);
+    }
+    #[test]
+    fn matrix_try_mul_test() {
+        let m = Matrix::<i32>::init(&vec![vec![1, 3], vec![2, 5]]);
+        let m2 = Matrix::<i32>::init(&vec![vec![1, 3, 2]]);
+
+        assert_eq!(m.try_mul(&m2).err(), Some(Error::DimensionMismatch { expected: (2, 3), found: (1, 3) }));
+        assert!(m.try_mul(&m).unwrap() == Matrix::<i32>::init(&vec![vec![7, 18], vec![12, 31]]));
+    }
+    #[test]
+    fn matrix_mul_rectangular_test() {
+        let a = Matrix::<i32>::init(&vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        let b = Matrix::<i32>::init(&vec![vec![1, 0, 2, 1], vec![0, 1, 1, 0], vec![1, 1, 0, 2]]);
+
+        let m = &a * &b;
+        assert_eq!((m.nrows(), m.ncols()), (2, 4));
+        assert!(m == Matrix::<i32>::init(&vec![vec![4, 5, 4, 7], vec![10, 11, 13, 16]]));
+
+        let col = Matrix::<i32>::init(&vec![vec![1], vec![2], vec![3]]);
+        let row = Matrix::<i32>::init(&vec![vec![1, 2, 3]]);
+        assert!(&row * &col == Matrix::<i32>::init(&vec![vec![14]]));
+        assert_eq!((&col * &row).nrows(), 3);
+        assert_eq!((&col * &row).get_element(2, 1), 6);
+
+        // a 3x2 product with a 3x2 matrix passes no valid shape
+        let mut at = a.clone();
+        at.transpose();
+        assert!(at.try_mul(&at).is_err());
+        assert!(a.try_mul(&a).is_err());
+    }
+    #[test]
+    #[should_panic]
+    fn matrix_mul_mismatch_panic_test() {
+        let a = Matrix::<i32>::ones(2, 3);
+        let _ = &a * &a;
+    }
+    #[test]
+    fn matrix_mul_vector_test() {
+        let a = Matrix::<i32>::init(&vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        let v = Vector::<i32>::init(&vec![1, 0, -1]);
+
+        let res = &a * &v;
+        assert_eq!(res.size(), 2);
+        assert_eq!((res.el(0), res.el(1)), (-2, -2));
+
+        let res = a.clone() * Vector::<i32>::init(&vec![1, 0, -1]);
+        assert_eq!((res.el(0), res.el(1)), (-2, -2));
+        assert_eq!(a.try_mul_vector(&Vector::<i32>::ones(2)).err(),
+                   Some(Error::DimensionMismatch { expected: (3, 1), found: (2, 1) }));
+    }
+    #[test]
+    fn vector_mul_matrix_test() {
+        let a = Matrix::<i32>::init(&vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        let v = Vector::<i32>::init(&vec![1, -1]);
+
+        let res = &v * &a;
+        assert_eq!(res.size(), 3);
+        assert_eq!((res.el(0), res.el(1), res.el(2)), (-3, -3, -3));
+
+        let res = Vector::<i32>::init(&vec![1, -1]) * a.clone();
+        assert_eq!(res.el(2), -3);
+        assert_eq!(Vector::<i32>::ones(3).try_mul_matrix(&a).err(),
+                   Some(Error::DimensionMismatch { expected: (3, 3), found: (2, 3) }));
+    }
+    #[test]
+    fn matrix_gemm_test() {
+        let a = Matrix::<i32>::init(&vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        let b = Matrix::<i32>::init(&vec![vec![1, 0], vec![0, 1], vec![1, 1]]);
+        let mut c = Matrix::<i32>::init(&vec![vec![1, 1], vec![1, 1]]);
+
+        Matrix::gemm(2, &a, &b, 3, &mut c);
+        assert!(c == Matrix::<i32>::init(&vec![vec![11, 13], vec![23, 25]]));
+
+        let mut wrong = Matrix::<i32>::zeros(3, 3);
+        assert_eq!(Matrix::try_gemm(1, &a, &a, 0, &mut c).err(),
+                   Some(Error::DimensionMismatch { expected: (3, 3), found: (2, 3) }));
+        assert_eq!(Matrix::try_gemm(1, &a, &b, 0, &mut wrong).err(),
+                   Some(Error::DimensionMismatch { expected: (2, 2), found: (3, 3) }));
+    }
+    #[test]
+    fn matrix_gemm_blocked_test() {
+        // bigger than the blocks of the kernel in every dimension
+        let a = Matrix::<f64>::random(150, 300, &[-1.0, 1.0]);
+        let b = Matrix::<f64>::random(300, 600, &[-1.0, 1.0]);
+        let mut c = Matrix::<f64>::ones(150, 600);
+        let mut expected = naive_mul(&a, &b);
+        for i in 0..150 {
+            for j in 0..600 {
+                let val = expected.get_element(i, j) - 1.0;
+                expected.set_element(i, j, &val);
+            }
+        }
+
+        Matrix::gemm(1.0, &a, &b, -1.0, &mut c);
+        assert!(approx_eq(&c, &expected, 1e-10));
+
+        // non-finite values of `c` are ignored if beta is zero
+        let mut nan = Matrix::<f64>::zeros(150, 600);
+        nan.set_element(0, 0, &f64::NAN);
+        Matrix::gemm(1.0, &a, &b, 0.0, &mut nan);
+        assert!(approx_eq(&nan, &naive_mul(&a, &b), 1e-10));
+    }
+    #[test]
+    fn matrix_parallel_element_test() {
+        fn is_parallel_element<T: ParallelElement>(_: &T) -> bool {
+            true
+        }
+
+        assert!(is_parallel_element(&1.0));
+        // the products just need `Send + Sync` elements with the parallel feature
+        #[cfg(not(feature = "parallel"))]
+        assert!(is_parallel_element(&std::rc::Rc::new(1.0)));
+    }
+    #[test]
+    #[should_panic]
+    fn matrix_add_mismatch_panic_test() {
+        let m = Matrix::<i32>::init(&vec![vec![1, 3], vec![2, 5]]);
+        let m2 = Matrix::<i32>::init(&vec![vec![1, 3, 2]]);
+
+        let _ = &m + &m2;
+    }
+
+    // --------------- View TEST ----------------------------------------
+
+    fn counting_matrix(nrows: usize, ncols: usize) -> Matrix<i32> {
+        let mut m = Matrix::<i32>::zeros(nrows, ncols);
+        for i in 0..nrows {
+            for j in 0..ncols {
+                m.set_element(i, j, &((i * ncols + j) as i32));
+            }
+        }
+        m
+    }
+
+    #[test]
+    fn view_test() {
+        let m = counting_matrix(4, 4);
+        let v = m.view(1..3, 1..4);
+
+        assert_eq!((v.nrows(), v.ncols()), (2, 3));
+        assert_eq!(v.strides(), (4, 1));
+        assert_eq!(v.get_element(0, 0), 5);
+        assert_eq!(v.get(1, 3), None);
+        assert!(v == m.slice(1..=2, 1..));
+        assert_eq!(v.iter().collect::<Vec<i32>>(), vec![5, 6, 7, 9, 10, 11]);
+
+        let sub = v.view(1..2, 1..3);
+        assert_eq!(sub.iter().collect::<Vec<i32>>(), vec![10, 11]);
+        assert!(m.as_view() == (&m).into());
+    }
+    #[test]
+    fn view_row_col_test() {
+        let m = counting_matrix(3, 4);
+
+        let row = m.row_view(1).unwrap();
+        assert_eq!((row.nrows(), row.ncols()), (1, 4));
+        assert_eq!(row.iter().collect::<Vec<i32>>(), vec![4, 5, 6, 7]);
+
+        let col = m.col_view(2).unwrap();
+        assert_eq!((col.nrows(), col.ncols()), (3, 1));
+        assert_eq!(col.iter().collect::<Vec<i32>>(), vec![2, 6, 10]);
+
+        assert!(m.row_view(3).is_none());
+        assert!(m.col_view(4).is_none());
+    }
+    #[test]
+    fn view_transpose_test() {
+        let m = counting_matrix(2, 3);
+        let t = m.transpose_view();
+        let mut expected = m.clone();
+        expected.transpose();
+
+        assert_eq!((t.nrows(), t.ncols()), (3, 2));
+        assert_eq!(t.strides(), (1, 3));
+        assert!(t.to_matrix() == expected);
+        assert_eq!(t.col_view(1).unwrap().iter().collect::<Vec<i32>>(), vec![3, 4, 5]);
+        assert!(t.transpose() == m.as_view());
+    }
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn view_errors_test() {
+        let m = counting_matrix(4, 4);
+
+        assert_eq!(m.try_view(3..1, 0..4).err(), Some(Error::InvalidRange));
+        assert_eq!(m.try_view(0..5, 0..4).err(), Some(Error::IndexOutOfBounds { index: 4, size: 4 }));
+        assert_eq!(m.view(1..3, 0..4).try_view(0..2, 2..5).err(), Some(Error::IndexOutOfBounds { index: 4, size: 4 }));
+
+        let empty = m.view(2..2, 0..4);
+        assert_eq!((empty.nrows(), empty.ncols()), (0, 4));
+        assert_eq!(empty.iter().count(), 0);
+        assert_eq!(m.view(4..4, 4..4).iter().count(), 0);
+    }
+    #[test]
+    fn view_mut_test() {
+        let mut m = counting_matrix(3, 3);
+        {
+            let mut v = m.view_mut(0..2, 1..3);
+            v.set_element(0, 0, &-1);
+            assert_eq!(v.get_element(1, 1), 5);
+        }
+        assert_eq!(m.get_element(0, 1), -1);
+
+        m.col_view_mut(0).unwrap().fill(0);
+        m.row_view_mut(2).unwrap().fill(9);
+        assert!(m == Matrix::<i32>::init(&vec![vec![0, -1, 2], vec![0, 4, 5], vec![9, 9, 9]]));
+
+        let source = Matrix::<i32>::init(&vec![vec![1, 2], vec![3, 4]]);
+        m.view_mut(0..2, 0..2).transpose().copy_from(&source.as_view());
+        assert!(m == Matrix::<i32>::init(&vec![vec![1, 3, 2], vec![2, 4, 5], vec![9, 9, 9]]));
+        assert_eq!(m.view_mut(0..1, 0..3).try_copy_from(&source.as_view()).err(),
+                   Some(Error::DimensionMismatch { expected: (1, 3), found: (2, 2) }));
+    }
+    #[test]
+    fn matrix_index_test() {
+        let mut m = counting_matrix(2, 3);
+
+        assert_eq!(m[(1, 2)], 5);
+        m[(0, 1)] = -1;
+        m[(1, 0)] += 10;
+        assert_eq!(m.get_element(0, 1), -1);
+        assert!(m == Matrix::<i32>::init(&vec![vec![0, -1, 2], vec![13, 4, 5]]));
+    }
+    #[test]
+    #[should_panic]
+    fn matrix_index_panic_test() {
+        let m = counting_matrix(2, 3);
+        let _ = m[(2, 0)];
+    }
+    #[test]
+    fn view_index_test() {
+        let mut m = counting_matrix(3, 3);
+        assert_eq!(m.transpose_view()[(2, 1)], 5);
+        {
+            let mut v = m.slice_mut(1.., ..2);
+            assert_eq!(v[(1, 1)], 7);
+            v[(0, 1)] = 0;
+        }
+        assert_eq!(m[(1, 1)], 0);
+    }
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn slice_test() {
+        let m = counting_matrix(4, 5);
+
+        assert!(m.slice(.., ..) == m.as_view());
+        assert!(m.slice(1..3, ..) == m.view(1..3, 0..5));
+        assert!(m.slice(..2, 3..) == m.view(0..2, 3..5));
+        assert!(m.slice(1..=3, ..=0) == m.view(1..4, 0..1));
+        assert!(m.slice(1..3, 1..).slice(1.., ..=1) == m.view(2..3, 1..3));
+        assert_eq!(m.slice(2..2, ..).nrows(), 0);
+
+        assert_eq!(m.try_slice(..5, ..).err(), Some(Error::IndexOutOfBounds { index: 4, size: 4 }));
+        assert_eq!(m.try_slice(.., 1..=5).err(), Some(Error::IndexOutOfBounds { index: 5, size: 5 }));
+        assert_eq!(m.try_slice(3..=1, ..).err(), Some(Error::InvalidRange));
+        assert_eq!(m.clone().try_slice_mut(.., 6..).err(), Some(Error::InvalidRange));
+    }
+    #[test]
+    fn view_ops_test() {
+        let m = counting_matrix(3, 3);
+        let a = m.view(0..2, 0..2);
+        let b = m.view(1..3, 1..3);
+
+        assert!(a + b == Matrix::<i32>::init(&vec![vec![4, 6], vec![10, 12]]));
+        assert!(b - a == Matrix::<i32>::init(&vec![vec![4, 4], vec![4, 4]]));
+        assert!(&m + m.transpose_view() == Matrix::<i32>::init(&vec![vec![0, 4, 8], vec![4, 8, 12], vec![8, 12, 16]]));
+        assert!(a - &a.to_matrix() == Matrix::<i32>::zeros(2, 2));
+
+        // 2x3 view times 3x1 column view
+        let rows = m.view(0..2, 0..3);
+        let col = m.col_view(1).unwrap();
+        assert!(rows * col == Matrix::<i32>::init(&vec![vec![18], vec![54]]));
+        assert!(&m * m.col_view(0).unwrap() == &m * &m.col_view(0).unwrap().to_matrix());
+        assert_eq!(rows.try_mul(&rows).err(), Some(Error::DimensionMismatch { expected: (3, 3), found: (2, 3) }));
+    }
+    #[test]
+    #[should_panic]
+    fn view_add_mismatch_panic_test() {
+        let m = counting_matrix(3, 3);
+        let _ = m.view(0..2, 0..2) + m.row_view(0).unwrap();
+    }
+    #[test]
+    fn view_decomposition_display_test() {
+        let m = Matrix::<f64>::init(&vec![vec![4.0, 7.0, 1.0], vec![2.0, 6.0, 1.0], vec![0.0, 0.0, 1.0]]);
+        let v = m.view(0..2, 0..2);
+
+        assert!((v.determinant().unwrap() - 10.0).abs() < 1e-12);
+        assert!((m.transpose_view().determinant().unwrap() - 10.0).abs() < 1e-12);
+        assert!(approx_eq(&v.inverse().unwrap(), &Matrix::<f64>::init(&vec![vec![0.6, -0.7], vec![-0.2, 0.4]]), 1e-12));
+        assert_eq!(format!("{}", v), format!("{}", v.to_matrix()));
+        assert_eq!(format!("{}", v), "{\n[ 4 7 ]\n[ 2 6 ]\n}\nsize: 2 x 2");
+    }
+
+    // --------------- Element-wise TEST ----------------------------------------
+
+    #[test]
+    fn matrix_hadamard_test() {
+        let a = Matrix::<i32>::init(&vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        let b = Matrix::<i32>::init(&vec![vec![2, 2, 3], vec![1, 5, 2]]);
+
+        assert!(a.hadamard(&b) == Matrix::<i32>::init(&vec![vec![2, 4, 9], vec![4, 25, 12]]));
+        assert!(a.hadamard_div(&b) == Matrix::<i32>::init(&vec![vec![0, 1, 1], vec![4, 1, 3]]));
+        assert_eq!(a.try_hadamard(&Matrix::<i32>::zeros(3, 2)).err(),
+                   Some(Error::DimensionMismatch { expected: (2, 3), found: (3, 2) }));
+        assert_eq!(a.try_hadamard_div(&Matrix::<i32>::zeros(2, 2)).err(),
+                   Some(Error::DimensionMismatch { expected: (2, 3), found: (2, 2) }));
+    }
+    #[test]
+    fn matrix_scalar_ops_test() {
+        let m = Matrix::<f64>::init(&vec![vec![1.0, -2.0], vec![3.0, 4.0]]);
+
+        assert!(&m + 1.0 == Matrix::<f64>::init(&vec![vec![2.0, -1.0], vec![4.0, 5.0]]));
+        assert!(&m - 1.0 == Matrix::<f64>::init(&vec![vec![0.0, -3.0], vec![2.0, 3.0]]));
+        assert!(&m * 2.0 == m.scalar_mul(2.0));
+        assert!(&m / 2.0 == Matrix::<f64>::init(&vec![vec![0.5, -1.0], vec![1.5, 2.0]]));
+        assert!(-&m == Matrix::<f64>::init(&vec![vec![-1.0, 2.0], vec![-3.0, -4.0]]));
+        assert!(-(m.clone() * 3.0 - 1.0) / 2.0 + 0.5 == Matrix::<f64>::init(&vec![vec![-0.5, 4.0], vec![-3.5, -5.0]]));
+        // the matrix product is still available next to the scalar product
+        assert!(&m * &Matrix::<f64>::create_identity(2) == m);
+    }
+    #[test]
+    fn matrix_assign_ops_test() {
+        let mut m = Matrix::<i32>::init(&vec![vec![1, 2], vec![3, 4]]);
+        let other = Matrix::<i32>::ones(2, 2);
+
+        m += &other;
+        assert!(m == Matrix::<i32>::init(&vec![vec![2, 3], vec![4, 5]]));
+        m -= &other;
+        m -= 1;
+        assert!(m == Matrix::<i32>::init(&vec![vec![0, 1], vec![2, 3]]));
+        m += 1;
+        m *= 3;
+        m /= 2;
+        assert!(m == Matrix::<i32>::init(&vec![vec![1, 3], vec![4, 6]]));
+
+        m *= &Matrix::<i32>::init(&vec![vec![0, 1], vec![1, 0]]);
+        assert!(m == Matrix::<i32>::init(&vec![vec![3, 1], vec![6, 4]]));
+    }
+    #[test]
+    #[should_panic]
+    fn matrix_add_assign_panic_test() {
+        let mut m = Matrix::<i32>::ones(2, 2);
+        m += &Matrix::<i32>::ones(2, 3);
+    }
+    #[test]
+    fn matrix_broadcast_test() {
+        let m = Matrix::<i32>::init(&vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        let row = Vector::<i32>::init(&vec![10, 20, 30]);
+        let col = Vector::<i32>::init(&vec![1, 2]);
+
+        assert!(m.broadcast_add(&row, Axis::Row) == Matrix::<i32>::init(&vec![vec![11, 22, 33], vec![14, 25, 36]]));
+        assert!(m.broadcast_sub(&col, Axis::Col) == Matrix::<i32>::init(&vec![vec![0, 1, 2], vec![2, 3, 4]]));
+        assert!(m.broadcast_mul(&col, Axis::Col) == Matrix::<i32>::init(&vec![vec![1, 2, 3], vec![8, 10, 12]]));
+        assert!(m.broadcast_div(&Vector::<i32>::init(&vec![1, 2, 3]), Axis::Row) ==
+                Matrix::<i32>::init(&vec![vec![1, 1, 1], vec![4, 2, 2]]));
+
+        assert_eq!(m.try_broadcast_add(&col, Axis::Row).err(),
+                   Some(Error::DimensionMismatch { expected: (1, 3), found: (1, 2) }));
+        assert_eq!(m.try_broadcast_mul(&row, Axis::Col).err(),
+                   Some(Error::DimensionMismatch { expected: (2, 1), found: (3, 1) }));
+    }
+    #[test]
+    fn matrix_broadcast_normalization_test() {
+        let data = Matrix::<f64>::init(&vec![vec![1.0, 10.0], vec![3.0, 30.0], vec![5.0, 20.0]]);
+        let means = Vector::<f64>::init(&vec![3.0, 20.0]);
+        let ranges = Vector::<f64>::init(&vec![4.0, 20.0]);
+
+        let normalized = data.broadcast_sub(&means, Axis::Row).broadcast_div(&ranges, Axis::Row);
+        assert!(normalized == Matrix::<f64>::init(&vec![vec![-0.5, -0.5], vec![0.0, 0.5], vec![0.5, 0.0]]));
+    }
+
+    // --------------- Combinators TEST ----------------------------------------
+
+    #[test]
+    fn matrix_map_test() {
+        let mut m = Matrix::<i32>::init(&vec![vec![1, -2], vec![3, -4]]);
+
+        assert!(m.map(|el| el.abs()) == Matrix::<i32>::init(&vec![vec![1, 2], vec![3, 4]]));
+        assert!(m.map(|el| el as f64 / 2.0) == Matrix::<f64>::init(&vec![vec![0.5, -1.0], vec![1.5, -2.0]]));
+        m.map_inplace(|el| el * el);
+        assert!(m == Matrix::<i32>::init(&vec![vec![1, 4], vec![9, 16]]));
+    }
+    #[test]
+    fn matrix_zip_map_test() {
+        let a = Matrix::<i32>::init(&vec![vec![1, 2], vec![3, 4]]);
+        let b = Matrix::<f64>::init(&vec![vec![0.5, 0.5], vec![2.0, 0.0]]);
+
+        assert!(a.zip_map(&b, |x, y| x as f64 * y) == Matrix::<f64>::init(&vec![vec![0.5, 1.0], vec![6.0, 0.0]]));
+        assert_eq!(a.try_zip_map(&Matrix::<i32>::zeros(1, 2), |x, y| x + y).err(),
+                   Some(Error::DimensionMismatch { expected: (2, 2), found: (1, 2) }));
+    }
+    #[test]
+    fn matrix_fold_test() {
+        let m = Matrix::<i32>::init(&vec![vec![1, 2, 3], vec![4, 5, 6]]);
+
+        assert_eq!(m.sum(), 21);
+        assert_eq!(m.product(), 720);
+        assert_eq!(m.fold(String::new(), |acc, el| acc + &el.to_string()), "123456");
+        assert!(m.any(|el| el > 5));
+        assert!(!m.all(|el| el > 1));
+        assert_eq!(Matrix::<i32>::new().sum(), 0);
+        assert_eq!(Matrix::<i32>::new().product(), 1);
+        assert!(Matrix::<i32>::new().all(|el| el > 1));
+    }
+    #[test]
+    fn matrix_min_max_test() {
+        let m = Matrix::<i32>::init(&vec![vec![3, 1, 7], vec![7, 1, 2]]);
+
+        assert_eq!(m.min(), Some((1, (0, 1))));
+        assert_eq!(m.max(), Some((7, (0, 2))));
+        assert_eq!(m.argmin(), Some((0, 1)));
+        assert_eq!(m.argmax(), Some((0, 2)));
+        assert_eq!(Matrix::<i32>::new().max(), None);
+
+        let nan = Matrix::<f64>::init(&vec![vec![f64::NAN, 2.0], vec![-1.0, f64::NAN]]);
+        assert_eq!(nan.min(), Some((-1.0, (1, 0))));
+        assert_eq!(nan.max(), Some((2.0, (0, 1))));
+        assert!(Matrix::<f64>::init(&vec![vec![f64::NAN]]).max().unwrap().0.is_nan());
+    }
+    fn values<N: Copy>(v: &Vector<N>) -> Vec<N> {
+        (0..v.size()).map(|i| v.el(i)).collect()
+    }
+    #[test]
+    fn matrix_axis_reduction_test() {
+        let m = Matrix::<i32>::init(&vec![vec![1, 5, 3], vec![4, 2, 6]]);
+
+        assert_eq!(values(&m.sum_axis(Axis::Row)), vec![5, 7, 9]);
+        assert_eq!(values(&m.sum_axis(Axis::Col)), vec![9, 12]);
+        assert_eq!(values(&m.product_axis(Axis::Row)), vec![4, 10, 18]);
+        assert_eq!(values(&m.fold_axis(Axis::Col, 0, |acc, el| acc.max(el))), vec![5, 6]);
+        assert_eq!(values(&m.min_axis(Axis::Row)), vec![Some((1, 0)), Some((2, 1)), Some((3, 0))]);
+        assert_eq!(values(&m.max_axis(Axis::Col)), vec![Some((5, 1)), Some((6, 2))]);
+        assert_eq!(values(&m.any_axis(Axis::Row, |el| el > 4)), vec![false, true, true]);
+        assert_eq!(values(&m.all_axis(Axis::Col, |el| el > 1)), vec![false, true]);
+        assert_eq!(Matrix::<i32>::new().sum_axis(Axis::Row).size(), 0);
+    }
+    #[test]
+    fn matrix_axis_extreme_empty_test() {
+        // a 0 x 3 matrix still has three (empty) columns
+        let m = Matrix::<i32>::from_shape_iter(0, 3, Vec::new());
+
+        assert_eq!(values(&m.min_axis(Axis::Row)), vec![None, None, None]);
+        assert_eq!(values(&m.max_axis(Axis::Row)), vec![None, None, None]);
+        assert_eq!(m.min_axis(Axis::Col).size(), 0);
+
+        let m = Matrix::<i32>::from_shape_iter(2, 0, Vec::new());
+        assert_eq!(values(&m.max_axis(Axis::Col)), vec![None, None]);
+        assert_eq!(m.sum_axis(Axis::Col).size(), 2);
+    }
+    #[test]
+    fn matrix_comparison_test() {
+        let m = Matrix::<i32>::init(&vec![vec![1, 2], vec![3, 4]]);
+        let other = Matrix::<i32>::init(&vec![vec![2, 2], vec![2, 2]]);
+
+        assert!(m.not_equal(&2) == Matrix::<bool>::init(&vec![vec![true, false], vec![true, true]]));
+        assert!(m.less_than(&3) == Matrix::<bool>::init(&vec![vec![true, true], vec![false, false]]));
+        assert!(m.less_equal(&3) == Matrix::<bool>::init(&vec![vec![true, true], vec![true, false]]));
+        assert!(m.bigger_equal(&2) == Matrix::<bool>::init(&vec![vec![false, true], vec![true, true]]));
+        assert!(m.not_equal_matrix(&other) == m.equal_to_matrix(&other).map(|el| !el));
+        assert!(m.less_than_matrix(&other) == Matrix::<bool>::init(&vec![vec![true, false], vec![false, false]]));
+        assert!(m.less_equal_matrix(&other) == Matrix::<bool>::init(&vec![vec![true, true], vec![false, false]]));
+        assert!(m.bigger_equal_matrix(&other) == Matrix::<bool>::init(&vec![vec![false, true], vec![true, true]]));
+        assert_eq!(m.try_less_than_matrix(&Matrix::<i32>::zeros(2, 1)).err(),
+                   Some(Error::DimensionMismatch { expected: (2, 2), found: (2, 1) }));
+    }
+
+    // --------------- Mask TEST ----------------------------------------
+
+    #[test]
+    fn matrix_select_test() {
+        let m = Matrix::<i32>::init(&vec![vec![1, 8, 3], vec![9, 2, 7]]);
+        let mask = m.bigger_than(&5);
+
+        assert_eq!(values(&m.select(&mask)), vec![8, 9, 7]);
+        assert_eq!(m.select(&m.bigger_than(&10)).size(), 0);
+        assert_eq!(m.try_select(&Matrix::<bool>::init(&vec![vec![true]])).err(),
+                   Some(Error::DimensionMismatch { expected: (2, 3), found: (1, 1) }));
+    }
+    #[test]
+    fn matrix_set_where_test() {
+        let mut m = Matrix::<f64>::init(&vec![vec![-4.0, 0.5], vec![2.0, 12.0]]);
+
+        // clipping of the outliers to [-1, 1]
+        let high = m.bigger_than(&1.0);
+        let low = m.less_than(&-1.0);
+        m.set_where(&high, 1.0);
+        m.set_where(&low, -1.0);
+        assert!(m == Matrix::<f64>::init(&vec![vec![-1.0, 0.5], vec![1.0, 1.0]]));
+        assert_eq!(m.try_set_where(&Matrix::<bool>::init(&vec![vec![true, false]]), 0.0).err(),
+                   Some(Error::DimensionMismatch { expected: (2, 2), found: (1, 2) }));
+    }
+    #[test]
+    fn matrix_where_test() {
+        let m = Matrix::<i32>::init(&vec![vec![1, -2], vec![-3, 4]]);
+        let zeros = Matrix::<i32>::zeros(2, 2);
+
+        assert!(m.where_(&m.bigger_than(&0), &zeros) == Matrix::<i32>::init(&vec![vec![1, 0], vec![0, 4]]));
+        assert!(m.where_(&m.less_than(&0), &-&m) == Matrix::<i32>::init(&vec![vec![-1, -2], vec![-3, -4]]));
+        assert_eq!(m.try_where_(&m.bigger_than(&0), &Matrix::<i32>::zeros(2, 1)).err(),
+                   Some(Error::DimensionMismatch { expected: (2, 2), found: (2, 1) }));
+    }
+    #[test]
+    fn matrix_bool_ops_test() {
+        let m = Matrix::<i32>::init(&vec![vec![1, 5, 3], vec![9, 2, 7]]);
+        let a = m.bigger_than(&2);
+        let b = m.less_than(&8);
+
+        assert!(&a & &b == Matrix::<bool>::init(&vec![vec![false, true, true], vec![false, false, true]]));
+        assert!(&a | &b == Matrix::<bool>::init(&vec![vec![true, true, true], vec![true, true, true]]));
+        assert!(!&a == m.less_equal(&2));
+        assert_eq!((a.clone() & b.clone()).count_true(), 3);
+        assert_eq!((!(a | b)).count_true(), 0);
+    }
+    #[test]
+    #[should_panic]
+    fn matrix_bool_and_panic_test() {
+        let _ = Matrix::<bool>::init(&vec![vec![true, false]]) & Matrix::<bool>::init(&vec![vec![true]]);
+    }
+
+    // --------------- Statistics TEST ----------------------------------------
+
+    fn assert_close(v: &Vector<f64>, expected: &[f64]) {
+        assert_eq!(v.size(), expected.len());
+        for (i, e) in expected.iter().enumerate() {
+            assert!((v.el(i) - e).abs() < 1e-12, "{} != {}", v.el(i), e);
+        }
+    }
+    fn stats_matrix() -> Matrix<i32> {
+        Matrix::<i32>::init(&vec![vec![1, 10], vec![2, 20], vec![3, 60], vec![4, 30]])
+    }
+    #[test]
+    fn matrix_mean_variance_test() {
+        let m = stats_matrix();
+
+        assert_close(&m.mean_axis(Axis::Row), &[2.5, 30.0]);
+        assert_close(&m.mean_axis(Axis::Col), &[5.5, 11.0, 31.5, 17.0]);
+        assert_close(&m.variance_axis(Axis::Row, 0), &[1.25, 350.0]);
+        assert_close(&m.variance_axis(Axis::Row, 1), &[5.0 / 3.0, 1400.0 / 3.0]);
+        assert_close(&m.std_dev_axis(Axis::Row, 0), &[1.25f64.sqrt(), 350f64.sqrt()]);
+        assert_close(&m.variance_axis(Axis::Col, 1), &[40.5, 162.0, 1624.5, 338.0]);
+
+        assert_eq!(m.try_variance_axis(Axis::Row, 4).err(), Some(Error::InsufficientData { required: 5, found: 4 }));
+        assert_eq!(m.try_std_dev_axis(Axis::Col, 2).err(), Some(Error::InsufficientData { required: 3, found: 2 }));
+    }
+    #[test]
+    fn matrix_median_percentile_test() {
+        let m = stats_matrix();
+
+        assert_close(&m.median_axis(Axis::Row), &[2.5, 25.0]);
+        assert_close(&m.median_axis(Axis::Col), &[5.5, 11.0, 31.5, 17.0]);
+        assert_close(&m.percentile_axis(Axis::Row, 25.0), &[1.75, 17.5]);
+        assert_close(&m.percentile_axis(Axis::Row, 0.0), &[1.0, 10.0]);
+        assert_close(&m.percentile_axis(Axis::Row, 100.0), &[4.0, 60.0]);
+        assert_eq!(m.try_percentile_axis(Axis::Row, 100.5).err(), Some(Error::InvalidArgument { name: "p" }));
+        assert_eq!(m.try_percentile_axis(Axis::Col, f64::NAN).err(), Some(Error::InvalidArgument { name: "p" }));
+
+        let nan = Matrix::<f64>::init(&vec![vec![1.0, f64::NAN], vec![3.0, 2.0], vec![2.0, 5.0]]);
+        let medians = nan.median_axis(Axis::Row);
+        assert_eq!(medians.el(0), 2.0);
+        assert!(medians.el(1).is_nan());
+    }
+    #[test]
+    fn matrix_covariance_test() {
+        let m = stats_matrix();
+        let cov = m.covariance();
+        let expected = Matrix::<f64>::init(&vec![vec![5.0 / 3.0, 50.0 / 3.0], vec![50.0 / 3.0, 1400.0 / 3.0]]);
+
+        assert!(approx_eq(&cov, &expected, 1e-12));
+        assert!(cov.is_symmetric(0.0));
+
+        let corr = m.correlation();
+        let r = 50.0 / 7000f64.sqrt();
+        assert!(approx_eq(&corr, &Matrix::<f64>::init(&vec![vec![1.0, r], vec![r, 1.0]]), 1e-12));
+
+        // perfectly (anti)correlated columns
+        let lines = Matrix::<f64>::init(&vec![vec![1.0, 2.0, -1.0], vec![2.0, 4.0, -2.0], vec![4.0, 8.0, -4.0]]);
+        let ones = Matrix::<f64>::init(&vec![vec![1.0, 1.0, -1.0], vec![1.0, 1.0, -1.0], vec![-1.0, -1.0, 1.0]]);
+        assert!(approx_eq(&lines.correlation(), &ones, 1e-12));
+
+        assert_eq!(Matrix::<i32>::init(&vec![vec![1, 2]]).try_covariance().err(),
+                   Some(Error::InsufficientData { required: 2, found: 1 }));
+        assert_eq!(Matrix::<i32>::new().try_correlation().err(),
+                   Some(Error::InsufficientData { required: 2, found: 0 }));
+    }
+
+    // element without f64 representation when it's negative
+    #[derive(Clone, Copy)]
+    struct Unsigned(i64);
+    impl num::ToPrimitive for Unsigned {
+        fn to_i64(&self) -> Option<i64> {
+            None
+        }
+        fn to_u64(&self) -> Option<u64> {
+            if self.0 < 0 { None } else { Some(self.0 as u64) }
+        }
+    }
+    #[test]
+    fn matrix_sum_min_max_f64_test() {
+        let m = stats_matrix();
+
+        assert_close(&m.sum_axis_f64(Axis::Row), &[10.0, 120.0]);
+        assert_close(&m.sum_axis_f64(Axis::Col), &[11.0, 22.0, 63.0, 34.0]);
+        assert_close(&m.min_axis_f64(Axis::Row), &[1.0, 10.0]);
+        assert_close(&m.max_axis_f64(Axis::Col), &[10.0, 20.0, 60.0, 30.0]);
+
+        let big = Matrix::<u8>::init(&vec![vec![200, 100], vec![100, 200]]);
+        assert_close(&big.sum_axis_f64(Axis::Row), &[300.0, 300.0]);
+
+        let nan = Matrix::<f64>::init(&vec![vec![f64::NAN, 2.0], vec![3.0, 1.0]]);
+        assert_close(&nan.min_axis_f64(Axis::Col), &[2.0, 1.0]);
+        assert_close(&nan.max_axis_f64(Axis::Row), &[3.0, 2.0]);
+        let empty = Matrix::<f64>::from_shape_iter(0, 2, vec![]);
+        assert!(empty.max_axis_f64(Axis::Row).as_slice().iter().all(|el| el.is_nan()));
+        assert_close(&empty.sum_axis_f64(Axis::Row), &[0.0, 0.0]);
+    }
+    #[test]
+    fn matrix_axis_f64_empty_test() {
+        // a 2 x 0 matrix still has two (empty) rows
+        let m = Matrix::<i32>::from_shape_iter(2, 0, Vec::new());
+
+        assert_close(&m.sum_axis_f64(Axis::Col), &[0.0, 0.0]);
+        assert_eq!(m.sum_axis_f64(Axis::Row).size(), 0);
+        let means = m.try_mean_axis(Axis::Col).unwrap();
+        assert_eq!(means.size(), 2);
+        assert!(means.as_slice().iter().all(|el| el.is_nan()));
+        assert!(m.max_axis_f64(Axis::Col).as_slice().iter().all(|el| el.is_nan()));
+        assert_eq!(m.median_axis(Axis::Col).size(), 2);
+    }
+    #[test]
+    fn stats_not_representable_test() {
+        let m = Matrix::<Unsigned>::from_shape_iter(2, 2, vec![Unsigned(1), Unsigned(2), Unsigned(-3), Unsigned(4)]);
+        let err = Some(Error::NotRepresentable { index: 2 });
+
+        assert_eq!(m.try_mean_axis(Axis::Row).err(), err);
+        assert_eq!(m.try_sum_axis_f64(Axis::Col).err(), err);
+        assert_eq!(m.try_min_axis_f64(Axis::Row).err(), err);
+        assert_eq!(m.try_max_axis_f64(Axis::Col).err(), err);
+        assert_eq!(m.try_variance_axis(Axis::Row, 0).err(), err);
+        assert_eq!(m.try_percentile_axis(Axis::Col, 50.0).err(), err);
+        assert_eq!(m.try_covariance().err(), err);
+        assert_eq!(m.try_correlation().err(), err);
+
+        let v: Vector<Unsigned> = vec![Unsigned(1), Unsigned(-1)].into_iter().collect();
+        assert_eq!(v.try_quantile(0.5, Interpolation::Linear).err(), Some(Error::NotRepresentable { index: 1 }));
+        let w: Vector<Unsigned> = vec![Unsigned(2), Unsigned(3)].into_iter().collect();
+        assert_eq!(v.try_distance(&w).err(), Some(Error::NotRepresentable { index: 1 }));
+        assert_eq!(w.try_cosine_similarity(&v).err(), Some(Error::NotRepresentable { index: 1 }));
+        assert_eq!(w.distance(&w), 0.0);
+
+        let mut stats = RunningStats::new();
+        assert_eq!(stats.try_push(Unsigned(5)), Ok(()));
+        assert_eq!(stats.try_push(Unsigned(-5)), Err(Error::NotRepresentable { index: 1 }));
+        assert_eq!((stats.count(), stats.mean()), (1, 5.0));
+    }
+    #[test]
+    #[should_panic]
+    fn stats_not_representable_panic_test() {
+        let v: Vector<Unsigned> = vec![Unsigned(-1)].into_iter().collect();
+        v.mean();
+    }
+    #[test]
+    #[should_panic]
+    fn norm_not_representable_panic_test() {
+        let v: Vector<Unsigned> = vec![Unsigned(1), Unsigned(-1)].into_iter().collect();
+        v.norm_l2();
+    }
+
+    // --------------- LU TEST ----------------------------------------
+
+    #[test]
+    fn lu_factors_test() {
+        let m = Matrix::<f64>::init(&vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0], vec![7.0, 8.0, 10.0]]);
+        let lu = m.lu().unwrap();
+
+        assert_eq!(lu.permutation(), &[2, 0, 1]);
+        assert!(approx_eq(&(&lu.p() * &m), &(&lu.l() * &lu.u()), 1e-12));
+        assert_eq!(lu.l().get_element(0, 1), 0.0);
+        assert_eq!(lu.u().get_element(1, 0), 0.0);
+    }
+    #[test]
+    fn lu_not_square_test() {
+        let m = Matrix::<f64>::init(&vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]);
+
+        assert_eq!(m.lu().err(), Some(Error::NotSquare { nrows: 2, ncols: 3 }));
+    }
+    #[test]
+    fn lu_determinant_test() {
+        let m = Matrix::<f64>::init(&vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0], vec![7.0, 8.0, 10.0]]);
+        let singular = Matrix::<f64>::init(&vec![vec![1.0, 2.0], vec![2.0, 4.0]]);
+
+        assert!((m.determinant().unwrap() + 3.0).abs() < 1e-12);
+        assert_eq!(singular.determinant().unwrap(), 0.0);
+    }
+    #[test]
+    fn lu_inverse_test() {
+        let m = Matrix::<f64>::init(&vec![vec![4.0, 7.0], vec![2.0, 6.0]]);
+        let inv = m.inverse().unwrap();
+
+        assert!(approx_eq(&inv, &Matrix::<f64>::init(&vec![vec![0.6, -0.7], vec![-0.2, 0.4]]), 1e-12));
+        assert!(approx_eq(&(&m * &inv), &Matrix::<f64>::create_identity(2), 1e-12));
+    }
+    #[test]
+    fn lu_singular_test() {
+        let m = Matrix::<f64>::init(&vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0], vec![7.0, 8.0, 9.0]]);
+        let lu = m.lu().unwrap();
+
+        assert!(lu.is_singular());
+        assert_eq!(lu.inverse().err(), Some(Error::Singular));
+        assert_eq!(lu.solve_vector(&Vector::<f64>::ones(3)).err(), Some(Error::Singular));
+    }
+    #[test]
+    fn lu_scaled_test() {
+        let diag = Matrix::<f64>::init(&vec![vec![1e-20, 0.0], vec![0.0, 1.0]]);
+        let lu = diag.lu().unwrap();
+
+        assert!(!lu.is_singular());
+        assert!(approx_eq(&lu.inverse().unwrap(), &Matrix::<f64>::init(&vec![vec![1e20, 0.0], vec![0.0, 1.0]]), 1e-12));
+        let x = lu.solve_vector(&Vector::<f64>::init(&vec![2e-20, 3.0])).unwrap();
+        assert!((x.el(0) - 2.0).abs() < 1e-12 && (x.el(1) - 3.0).abs() < 1e-12);
+
+        let cols = Matrix::<f64>::init(&vec![vec![1e-20, 1.0], vec![0.0, 1.0]]);
+        assert!(!cols.lu().unwrap().is_singular());
+
+        // a scaled row doesn't hide the rank deficiency
+        let singular = Matrix::<f64>::init(&vec![vec![1e-20, 2e-20, 3e-20], vec![4.0, 5.0, 6.0], vec![7.0, 8.0, 9.0]]);
+        assert!(singular.lu().unwrap().is_singular());
+    }
+    #[test]
+    fn lu_solve_test() {
+        let m = Matrix::<f64>::init(&vec![vec![2.0, 1.0, -1.0], vec![-3.0, -1.0, 2.0], vec![-2.0, 1.0, 2.0]]);
+        let lu = m.lu().unwrap();
+
+        let x = lu.solve_vector(&Vector::<f64>::init(&vec![8.0, -11.0, -3.0])).unwrap();
+        assert!((x.el(0) - 2.0).abs() < 1e-12);
+        assert!((x.el(1) - 3.0).abs() < 1e-12);
+        assert!((x.el(2) + 1.0).abs() < 1e-12);
+
+        let b = Matrix::<f64>::init(&vec![vec![8.0, 1.0, 0.0], vec![-11.0, 0.0, 2.0], vec![-3.0, 0.0, 1.0]]);
+        let xs = lu.solve(&b).unwrap();
+        assert_eq!(xs.ncols(), 3);
+        assert!(approx_eq(&(&m * &xs), &b, 1e-12));
+
+        assert!(lu.solve(&Matrix::<f64>::ones(2, 1)).is_err());
+    }
+
+    // --------------- QR TEST ----------------------------------------
+
+    #[test]
+    fn qr_tall_test() {
+        let m = Matrix::<f64>::init(&vec![vec![12.0, -51.0, 4.0], vec![6.0, 167.0, -68.0],
+                                          vec![-4.0, 24.0, -41.0], vec![1.0, 2.0, 3.0]]);
+        let qr = m.qr();
+        let (q, r) = (qr.q(), qr.r());
+
+        assert_eq!((q.nrows(), q.ncols()), (4, 3));
+        assert_eq!((r.nrows(), r.ncols()), (3, 3));
+        assert!(approx_eq(&naive_mul(&q, &r), &m, 1e-10));
+
+        let mut qt = q.clone();
+        qt.transpose();
+        assert!(approx_eq(&naive_mul(&qt, &q), &Matrix::<f64>::create_identity(3), 1e-12));
+        assert_eq!(r.get_element(1, 0), 0.0);
+        assert_eq!(r.get_element(2, 1), 0.0);
+        assert!(qr.is_full_rank());
+    }
+    #[test]
+    fn qr_wide_test() {
+        let m = Matrix::<f64>::init(&vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]);
+        let qr = m.qr();
+        let (q, r) = (qr.q(), qr.r());
+
+        assert_eq!((q.nrows(), q.ncols()), (2, 2));
+        assert_eq!((r.nrows(), r.ncols()), (2, 3));
+        assert!(approx_eq(&naive_mul(&q, &r), &m, 1e-12));
+        assert!(!qr.is_full_rank());
+    }
+    #[test]
+    fn least_squares_test() {
+        // best line through (0, 6), (1, 0) and (2, 0) is 5 - 3x
+        let a = Matrix::<f64>::init(&vec![vec![1.0, 0.0], vec![1.0, 1.0], vec![1.0, 2.0]]);
+        let b = Vector::<f64>::init(&vec![6.0, 0.0, 0.0]);
+
+        let x = a.least_squares(&b).unwrap();
+        assert_eq!(x.size(), 2);
+        assert!((x.el(0) - 5.0).abs() < 1e-12);
+        assert!((x.el(1) + 3.0).abs() < 1e-12);
+    }
+    #[test]
+    fn least_squares_square_test() {
+        let a = Matrix::<f64>::init(&vec![vec![2.0, 1.0, -1.0], vec![-3.0, -1.0, 2.0], vec![-2.0, 1.0, 2.0]]);
+        let b = Vector::<f64>::init(&vec![8.0, -11.0, -3.0]);
+
+        let x = a.least_squares(&b).unwrap();
+        assert!((x.el(0) - 2.0).abs() < 1e-12);
+        assert!((x.el(1) - 3.0).abs() < 1e-12);
+        assert!((x.el(2) + 1.0).abs() < 1e-12);
+    }
+    #[test]
+    fn least_squares_scaled_test() {
+        let diag = Matrix::<f64>::init(&vec![vec![1e-20, 0.0], vec![0.0, 1.0]]);
+        assert!(diag.qr().is_full_rank());
+        let x = diag.least_squares(&Vector::<f64>::init(&vec![2e-20, 3.0])).unwrap();
+        assert!((x.el(0) - 2.0).abs() < 1e-12 && (x.el(1) - 3.0).abs() < 1e-12);
+
+        // best line through (0, 6), (1, 0) and (2, 0) with x measured in other units
+        let a = Matrix::<f64>::init(&vec![vec![1.0, 0.0], vec![1.0, 1e-20], vec![1.0, 2e-20]]);
+        let x = a.least_squares(&Vector::<f64>::init(&vec![6.0, 0.0, 0.0])).unwrap();
+        assert!((x.el(0) - 5.0).abs() < 1e-12);
+        assert!((x.el(1) / 1e20 + 3.0).abs() < 1e-12);
+
+        // a scaled column doesn't hide the rank deficiency
+        let deficient = Matrix::<f64>::init(&vec![vec![1e-20, 2.0], vec![2e-20, 4.0], vec![3e-20, 6.0]]);
+        assert_eq!(deficient.least_squares(&Vector::<f64>::ones(3)).err(), Some(Error::Singular));
+    }
+    #[test]
+    fn least_squares_errors_test() {
+        let wide = Matrix::<f64>::init(&vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]);
+        let deficient = Matrix::<f64>::init(&vec![vec![1.0, 2.0], vec![2.0, 4.0], vec![3.0, 6.0]]);
+
+        assert_eq!(wide.least_squares(&Vector::<f64>::ones(2)).err(),
+                   Some(Error::Underdetermined { nrows: 2, ncols: 3 }));
+        assert_eq!(deficient.least_squares(&Vector::<f64>::ones(3)).err(), Some(Error::Singular));
+        assert_eq!(deficient.least_squares(&Vector::<f64>::ones(2)).err(),
+                   Some(Error::DimensionMismatch { expected: (3, 1), found: (2, 1) }));
+    }
+
+    // --------------- Cholesky TEST ----------------------------------------
+
+    #[test]
+    fn matrix_is_symmetric_test() {
+        let m = Matrix::<f64>::init(&vec![vec![4.0, 1.0], vec![1.0 + 1e-10, 3.0]]);
+        let rect = Matrix::<f64>::ones(2, 3);
+
+        assert!(m.is_symmetric(1e-8));
+        assert!(!m.is_symmetric(0.0));
+        assert!(!rect.is_symmetric(1.0));
+        assert!(Matrix::<i32>::create_identity(3).is_symmetric(0));
+    }
+    #[test]
+    fn cholesky_factor_test() {
+        let m = Matrix::<f64>::init(&vec![vec![4.0, 12.0, -16.0], vec![12.0, 37.0, -43.0], vec![-16.0, -43.0, 98.0]]);
+        let l = m.cholesky().unwrap().l();
+
+        assert!(approx_eq(&l, &Matrix::<f64>::init(&vec![vec![2.0, 0.0, 0.0], vec![6.0, 1.0, 0.0],
+                                                        vec![-8.0, 5.0, 3.0]]), 1e-12));
+        let mut lt = l.clone();
+        lt.transpose();
+        assert!(approx_eq(&(&l * &lt), &m, 1e-12));
+    }
+    #[test]
+    fn cholesky_errors_test() {
+        let rect = Matrix::<f64>::ones(2, 3);
+        let not_symmetric = Matrix::<f64>::init(&vec![vec![4.0, 1.0], vec![2.0, 3.0]]);
+        let indefinite = Matrix::<f64>::init(&vec![vec![1.0, 2.0], vec![2.0, 1.0]]);
+
+        assert_eq!(rect.cholesky().err(), Some(Error::NotSquare { nrows: 2, ncols: 3 }));
+        assert_eq!(not_symmetric.cholesky().err(), Some(Error::NotSymmetric));
+        assert_eq!(indefinite.cholesky().err(), Some(Error::NotPositiveDefinite));
+    }
+    #[test]
+    fn cholesky_solve_test() {
+        let m = Matrix::<f64>::init(&vec![vec![4.0, 12.0, -16.0], vec![12.0, 37.0, -43.0], vec![-16.0, -43.0, 98.0]]);
+        let chol = m.cholesky().unwrap();
+
+        assert!((chol.determinant() - 36.0).abs() < 1e-10);
+        assert!(approx_eq(&(&m * &chol.inverse()), &Matrix::<f64>::create_identity(3), 1e-10));
+
+        let x = chol.solve_vector(&Vector::<f64>::init(&vec![0.0, 6.0, 39.0])).unwrap();
+        assert!((x.el(0) - 1.0).abs() < 1e-10);
+        assert!((x.el(1) - 1.0).abs() < 1e-10);
+        assert!((x.el(2) - 1.0).abs() < 1e-10);
+        assert_eq!(chol.solve(&Matrix::<f64>::ones(2, 1)).err(),
+                   Some(Error::DimensionMismatch { expected: (3, 1), found: (2, 1) }));
+    }
+
+    // --------------- Eigen TEST ----------------------------------------
+
+    #[test]
+    fn symmetric_eigen_test() {
+        let m = Matrix::<f64>::init(&vec![vec![2.0, -1.0, 0.0], vec![-1.0, 2.0, -1.0], vec![0.0, -1.0, 2.0]]);
+        let eigen = m.symmetric_eigen().unwrap();
+        let values = eigen.eigenvalues();
+        let vectors = eigen.eigenvectors();
+
+        assert!((values.el(0) - (2.0 - 2f64.sqrt())).abs() < 1e-12);
+        assert!((values.el(1) - 2.0).abs() < 1e-12);
+        assert!((values.el(2) - (2.0 + 2f64.sqrt())).abs() < 1e-12);
+
+        let mut d = Matrix::<f64>::zeros(3, 3);
+        for i in 0..3 {
+            d.set_element(i, i, &values.el(i));
+        }
+        assert!(approx_eq(&(&m * &vectors), &(&vectors * &d), 1e-12));
+
+        let mut vt = vectors.clone();
+        vt.transpose();
+        assert!(approx_eq(&(&vt * &vectors), &Matrix::<f64>::create_identity(3), 1e-12));
+    }
+    #[test]
+    fn symmetric_eigen_random_test() {
+        let r = Matrix::<f64>::random(6, 6, &[-1.0, 1.0]);
+        let mut rt = r.clone();
+        rt.transpose();
+        let m = &r + &rt;
+
+        let eigen = m.symmetric_eigen().unwrap();
+        let values = eigen.eigenvalues();
+        let vectors = eigen.eigenvectors();
+        let mut d = Matrix::<f64>::zeros(6, 6);
+        for i in 0..6 {
+            d.set_element(i, i, &values.el(i));
+            if i > 0 {
+                assert!(values.el(i - 1) <= values.el(i));
+            }
+        }
+        assert!(approx_eq(&(&m * &vectors), &(&vectors * &d), 1e-10));
+    }
+    #[test]
+    fn symmetric_eigen_errors_test() {
+        let rect = Matrix::<f64>::ones(2, 3);
+        let not_symmetric = Matrix::<f64>::init(&vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+        let m = Matrix::<f64>::init(&vec![vec![2.0, 1.0], vec![1.0, 2.0]]);
+
+        assert_eq!(rect.symmetric_eigen().err(), Some(Error::NotSquare { nrows: 2, ncols: 3 }));
+        assert_eq!(not_symmetric.symmetric_eigen().err(), Some(Error::NotSymmetric));
+        assert_eq!(m.symmetric_eigen_with(1e-12, 0).err(), Some(Error::NoConvergence { iterations: 0 }));
+        assert!(Matrix::<f64>::create_identity(2).symmetric_eigen_with(1e-12, 0).is_ok());
+    }
+
+    #[test]
+    fn eigen_real_test() {
+        let m = Matrix::<f64>::init(&vec![vec![2.0, 0.0, 0.0], vec![1.0, 3.0, 0.0], vec![4.0, 5.0, 6.0]]);
+        let mut values: Vec<f64> = Vec::new();
+        let eigenvalues = m.eigenvalues().unwrap();
+        for i in 0..eigenvalues.size() {
+            assert_eq!(eigenvalues.el(i).im, 0.0);
+            values.push(eigenvalues.el(i).re);
+        }
+        values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        assert!((values[0] - 2.0).abs() < 1e-12);
+        assert!((values[1] - 3.0).abs() < 1e-12);
+        assert!((values[2] - 6.0).abs() < 1e-12);
+    }
+    #[test]
+    fn eigen_complex_test() {
+        // rotation by 90 degrees has the eigenvalues i and -i
+        let rot = Matrix::<f64>::init(&vec![vec![0.0, -1.0], vec![1.0, 0.0]]);
+        let values = rot.eigenvalues().unwrap();
+
+        assert!((values.el(0) - Complex::new(0.0, 1.0)).norm() < 1e-12);
+        assert!((values.el(1) - Complex::new(0.0, -1.0)).norm() < 1e-12);
+        assert!(rot.eigen().unwrap().eigenvectors().is_some());
+        assert_eq!(Matrix::<f64>::ones(2, 3).eigen().err(), Some(Error::NotSquare { nrows: 2, ncols: 3 }));
+    }
+    #[test]
+    fn eigen_vectors_test() {
+        let m = Matrix::<f64>::init(&vec![vec![1.0, 2.0, 0.0, -1.0], vec![-3.0, 0.5, 4.0, 2.0],
+                                          vec![0.0, 1.0, -2.0, 1.0], vec![5.0, -1.0, 0.0, 3.0]]);
+        let eigen = m.eigen().unwrap();
+        let values = eigen.eigenvalues();
+        let vectors = eigen.eigenvectors().unwrap();
+
+        let mut a = Matrix::<Complex<f64>>::zeros(4, 4);
+        let mut d = Matrix::<Complex<f64>>::zeros(4, 4);
+        for i in 0..4 {
+            for j in 0..4 {
+                a.set_element(i, j, &Complex::new(m.get_element(i, j), 0.0));
+            }
+            d.set_element(i, i, &values.el(i));
+        }
+        let (av, vd) = (&a * &vectors, &vectors * &d);
+        for i in 0..4 {
+            for j in 0..4 {
+                assert!((av.get_element(i, j) - vd.get_element(i, j)).norm() < 1e-10);
+            }
+            let norm: f64 = (0..4).map(|k| vectors.get_element(k, i).norm_sqr()).sum();
+            assert!((norm - 1.0).abs() < 1e-12);
+        }
+        assert!(m.eigen().unwrap().eigenvectors().is_some());
+    }
+
+    // --------------- SVD TEST ----------------------------------------
+
+    #[test]
+    fn svd_tall_test() {
+        let m = Matrix::<f64>::init(&vec![vec![2.0, 0.0, 1.0], vec![-1.0, 3.0, 0.5],
+                                          vec![4.0, 1.0, -2.0], vec![0.0, 5.0, 1.0]]);
+        let svd = m.svd().unwrap();
+        let (u, vt) = (svd.u(), svd.vt());
+        let s = svd.singular_values();
+
+        assert_eq!((u.nrows(), u.ncols()), (4, 3));
+        assert_eq!((vt.nrows(), vt.ncols()), (3, 3));
+        let mut d = Matrix::<f64>::zeros(3, 3);
+        for i in 0..3 {
+            d.set_element(i, i, &s.el(i));
+            if i > 0 {
+                assert!(s.el(i - 1) >= s.el(i));
+            }
+        }
+        assert!(approx_eq(&naive_mul(&naive_mul(&u, &d), &vt), &m, 1e-12));
+
+        let mut ut = u.clone();
+        ut.transpose();
+        assert!(approx_eq(&naive_mul(&ut, &u), &Matrix::<f64>::create_identity(3), 1e-12));
+    }
+    #[test]
+    fn svd_wide_test() {
+        let m = Matrix::<f64>::init(&vec![vec![3.0, 2.0, 2.0], vec![2.0, 3.0, -2.0]]);
+        let svd = m.svd().unwrap();
+        let (u, vt) = (svd.u(), svd.vt());
+        let s = svd.singular_values();
+
+        assert_eq!((u.nrows(), u.ncols()), (2, 2));
+        assert_eq!((vt.nrows(), vt.ncols()), (2, 3));
+        assert!((s.el(0) - 5.0).abs() < 1e-12);
+        assert!((s.el(1) - 3.0).abs() < 1e-12);
+
+        let d = Matrix::<f64>::init(&vec![vec![5.0, 0.0], vec![0.0, 3.0]]);
+        assert!(approx_eq(&naive_mul(&naive_mul(&u, &d), &vt), &m, 1e-12));
+        let mut v = vt.clone();
+        v.transpose();
+        assert!(approx_eq(&naive_mul(&vt, &v), &Matrix::<f64>::create_identity(2), 1e-12));
+
+        let null = m.nullspace().unwrap();
+        assert_eq!((null.nrows(), null.ncols()), (3, 1));
+        assert!(approx_eq(&naive_mul(&m, &null), &Matrix::<f64>::zeros(2, 1), 1e-12));
+    }
+    #[test]
+    fn svd_rank_deficient_test() {
+        let m = Matrix::<f64>::init(&vec![vec![1.0, 2.0], vec![2.0, 4.0], vec![3.0, 6.0]]);
+
+        assert_eq!(m.rank(1e-10).unwrap(), 1);
+        assert_eq!(m.condition_number().unwrap(), f64::INFINITY);
+
+        let pinv = m.pinv().unwrap();
+        assert_eq!((pinv.nrows(), pinv.ncols()), (2, 3));
+        assert!(approx_eq(&naive_mul(&naive_mul(&m, &pinv), &m), &m, 1e-12));
+
+        let null = m.nullspace().unwrap();
+        assert_eq!((null.nrows(), null.ncols()), (2, 1));
+        assert!((null.get_element(0, 0).abs() - 2.0 / 5f64.sqrt()).abs() < 1e-12);
+        assert!(approx_eq(&naive_mul(&m, &null), &Matrix::<f64>::zeros(3, 1), 1e-12));
+        assert_eq!(Matrix::<f64>::create_identity(3).nullspace().unwrap().ncols(), 0);
+    }
+    #[test]
+    fn svd_pinv_solve_test() {
+        let m = Matrix::<f64>::init(&vec![vec![4.0, 7.0], vec![2.0, 6.0]]);
+        let diag = Matrix::<f64>::init(&vec![vec![1.0, 0.0], vec![0.0, 10.0]]);
+
+        assert!(approx_eq(&m.pinv().unwrap(), &m.inverse().unwrap(), 1e-12));
+        assert!((diag.condition_number().unwrap() - 10.0).abs() < 1e-12);
+
+        // minimum norm solution of x + y = 2
+        let wide = Matrix::<f64>::init(&vec![vec![1.0, 1.0]]);
+        let x = wide.svd().unwrap().solve_vector(&Vector::<f64>::init(&vec![2.0])).unwrap();
+        assert!((x.el(0) - 1.0).abs() < 1e-12);
+        assert!((x.el(1) - 1.0).abs() < 1e-12);
+        assert_eq!(wide.svd().unwrap().solve(&Matrix::<f64>::ones(2, 1)).err(),
+                   Some(Error::DimensionMismatch { expected: (1, 1), found: (2, 1) }));
+    }
 
     // --------------- Vector TEST ----------------------------------------
     #[test]
@@ -506,6 +1737,16 @@ mod tests {
         assert_eq!(v.el(2), 5.3f64);
     }
     #[test]
+    fn vector_index_test() {
+        let mut v = Vector::<i32>::init(&vec![1, 2, 3]);
+
+        assert_eq!(v[2], 3);
+        v[0] = 7;
+        v[1] *= 5;
+        assert_eq!(v.el(0), 7);
+        assert_eq!(v.el(1), 10);
+    }
+    #[test]
     fn vector_zeros_test() {
         let v = Vector::<i32>::zeros(2);
 
@@ -533,6 +1774,11 @@ mod tests {
         assert_eq!(v.el(4) >= -1i32 && v.el(4) <= 2i32, true);
     }
     #[test]
+    fn vector_try_random_test() {
+        assert!(Vector::<i32>::try_random(5, &[-1i32, 2i32]).is_ok());
+        assert_eq!(Vector::<i32>::try_random(5, &[2i32, -1i32]).err(), Some(Error::InvalidRange));
+    }
+    #[test]
     fn vector_push_test() {
         let mut v = Vector::<f32>::new();
         v.push(1f32);
@@ -632,4 +1878,261 @@ mod tests {
 
         assert_eq!(median, 3f64);
     }
+    #[test]
+    fn vector_median_odd_test() {
+        let v = Vector::<i64>::init(&vec![9, 1, 2]);
+
+        assert_eq!(v.median(), 2f64);
+        assert!(Vector::<f64>::init(&vec![1.0, f64::NAN]).median().is_nan());
+        assert!(Vector::<i64>::new().median().is_nan());
+    }
+    #[test]
+    fn vector_mean_variance_test() {
+        let v = Vector::<i32>::init(&vec![2, 4, 4, 4, 5, 5, 7, 9]);
+
+        assert_eq!(v.mean(), 5.0);
+        assert_eq!(v.variance(), 4.0);
+        assert_eq!(v.std_dev(), 2.0);
+        assert!((v.sample_variance() - 32.0 / 7.0).abs() < 1e-12);
+        assert!((v.sample_std_dev() - (32.0f64 / 7.0).sqrt()).abs() < 1e-12);
+        assert!(Vector::<i32>::init(&vec![3]).sample_variance().is_nan());
+        assert!(Vector::<i32>::new().mean().is_nan());
+    }
+    #[test]
+    fn vector_mode_test() {
+        assert_eq!(Vector::<i32>::init(&vec![2, 4, 4, 4, 5, 5, 7, 9]).mode(), Some(4));
+        assert_eq!(Vector::<i32>::init(&vec![3, 1, 3, 1]).mode(), Some(1));
+        assert_eq!(Vector::<f64>::init(&vec![f64::NAN, 0.5, f64::NAN]).mode(), Some(0.5));
+        assert_eq!(Vector::<i32>::new().mode(), None);
+    }
+    #[test]
+    fn vector_quantile_test() {
+        let v = Vector::<i32>::init(&vec![4, 1, 3, 2]);
+
+        assert!((v.quantile(0.4, Interpolation::Linear) - 2.2).abs() < 1e-12);
+        assert_eq!(v.quantile(0.4, Interpolation::Lower), 2.0);
+        assert_eq!(v.quantile(0.4, Interpolation::Higher), 3.0);
+        assert_eq!(v.quantile(0.4, Interpolation::Nearest), 2.0);
+        assert_eq!(v.quantile(0.5, Interpolation::Nearest), 3.0);
+        assert_eq!(v.quantile(0.4, Interpolation::Midpoint), 2.5);
+        assert_eq!(v.quantile(0.0, Interpolation::Linear), 1.0);
+        assert_eq!(v.quantile(1.0, Interpolation::Linear), 4.0);
+
+        assert_eq!(v.try_quantile(1.5, Interpolation::Linear).err(), Some(Error::InvalidArgument { name: "q" }));
+        assert_eq!(Vector::<i32>::new().try_quantile(0.5, Interpolation::Linear).err(),
+                   Some(Error::InsufficientData { required: 1, found: 0 }));
+    }
+    #[test]
+    fn vector_skewness_kurtosis_test() {
+        let v = Vector::<i32>::init(&vec![1, 1, 1, 1, 10]);
+
+        assert!((v.skewness() - 1.5).abs() < 1e-12);
+        assert!((v.kurtosis() - 0.25).abs() < 1e-12);
+        assert!(Vector::<i32>::init(&vec![1, 2, 3]).skewness().abs() < 1e-12);
+        assert!(Vector::<i32>::init(&vec![2, 2]).skewness().is_nan());
+    }
+    #[test]
+    fn vector_partial_min_max_test() {
+        let v = Vector::<f64>::init(&vec![1.5, f64::NAN, -2.0, 3.0, -2.0]);
+
+        assert_eq!(v.partial_max(NanPolicy::Ignore), Ok(Some((3.0, 3))));
+        assert_eq!(v.partial_min(NanPolicy::Ignore), Ok(Some((-2.0, 2))));
+        assert_eq!(v.partial_min(NanPolicy::Error), Err(Error::NotANumber { index: 1 }));
+        let (nan, idx) = v.partial_max(NanPolicy::Propagate).unwrap().unwrap();
+        assert!(nan.is_nan());
+        assert_eq!(idx, 1);
+
+        assert_eq!(Vector::<f64>::new().partial_max(NanPolicy::Error), Ok(None));
+        assert_eq!(Vector::<f64>::init(&vec![f64::NAN]).partial_min(NanPolicy::Ignore), Ok(None));
+    }
+    #[test]
+    fn vector_sort_partial_test() {
+        let mut v = Vector::<f64>::init(&vec![2.5, f64::NAN, -1.0, 7.0]);
+        v.sort_min_partial(NanPolicy::Propagate).unwrap();
+        assert_eq!(values(&v)[..3], [-1.0, 2.5, 7.0]);
+        assert!(v.el(3).is_nan());
+
+        v.sort_max_partial(NanPolicy::Ignore).unwrap();
+        assert_eq!(values(&v), vec![7.0, 2.5, -1.0]);
+
+        let mut with_nan = Vector::<f32>::init(&vec![1.0, f32::NAN]);
+        assert_eq!(with_nan.sort_min_partial(NanPolicy::Error), Err(Error::NotANumber { index: 1 }));
+        assert_eq!(with_nan.size(), 2);
+        assert_eq!(with_nan.el(0), 1.0);
+    }
+    #[test]
+    fn vector_argsort_test() {
+        let v = Vector::<f64>::init(&vec![0.5, f64::NAN, -3.0, 0.5, 2.0]);
+
+        assert_eq!(v.argsort(NanPolicy::Propagate), Ok(vec![2, 0, 3, 4, 1]));
+        assert_eq!(v.argsort(NanPolicy::Ignore), Ok(vec![2, 0, 3, 4]));
+        assert_eq!(v.argsort(NanPolicy::Error), Err(Error::NotANumber { index: 1 }));
+        assert_eq!(Vector::<i32>::init(&vec![3, 1, 2]).argsort(NanPolicy::Error), Ok(vec![1, 2, 0]));
+    }
+    #[test]
+    fn vector_sort_by_test() {
+        let mut v = Vector::<i32>::init(&vec![-3, 2, -1, 3, 1]);
+        v.sort_by_key(|el| el.abs());
+        // stable: the equal keys keep their order
+        assert_eq!(values(&v), vec![-1, 1, 2, -3, 3]);
+
+        v.sort_by(|a, b| b.cmp(a));
+        assert_eq!(values(&v), vec![3, 2, 1, -1, -3]);
+
+        let mut floats = Vector::<f64>::init(&vec![1.0, f64::NAN, -0.0, f64::NEG_INFINITY, 0.0]);
+        floats.sort_total();
+        assert_eq!(floats.el(0), f64::NEG_INFINITY);
+        assert!(floats.el(1).is_sign_negative() && floats.el(1) == 0.0);
+        assert!(floats.el(2).is_sign_positive() && floats.el(2) == 0.0);
+        assert_eq!(floats.el(3), 1.0);
+        assert!(floats.el(4).is_nan());
+    }
+    #[test]
+    fn vector_partial_sort_top_k_test() {
+        let mut v = Vector::<f64>::init(&vec![5.0, 1.0, f64::NAN, 4.0, 2.0, 3.0]);
+
+        let top = v.top_k(3, NanPolicy::Ignore).unwrap();
+        assert_eq!(values(&top), vec![5.0, 4.0, 3.0]);
+        assert_eq!(v.top_k(10, NanPolicy::Ignore).unwrap().size(), 5);
+        assert_eq!(v.top_k(6, NanPolicy::Propagate).unwrap().size(), 6);
+        assert_eq!(v.top_k(0, NanPolicy::Ignore).unwrap().size(), 0);
+        assert_eq!(v.top_k(2, NanPolicy::Error).err(), Some(Error::NotANumber { index: 2 }));
+
+        v.partial_sort(2, NanPolicy::Propagate).unwrap();
+        assert_eq!(v.size(), 6);
+        assert_eq!(values(&v)[..2], [1.0, 2.0]);
+        assert!(v.el(5).is_nan());
+
+        let mut ints = Vector::<i32>::init(&vec![9, 8, 7, 6, 5, 4, 3, 2, 1]);
+        ints.partial_sort(4, NanPolicy::Error).unwrap();
+        assert_eq!(values(&ints)[..4], [1, 2, 3, 4]);
+    }
+    #[test]
+    fn vector_arithmetic_test() {
+        let a = Vector::<i32>::init(&vec![1, 2, 3]);
+        let b = Vector::<i32>::init(&vec![4, -5, 6]);
+
+        assert_eq!(values(&(&a + &b)), vec![5, -3, 9]);
+        assert_eq!(values(&(&a - &b)), vec![-3, 7, -3]);
+        assert_eq!(values(&(&a * 2)), vec![2, 4, 6]);
+        assert_eq!(values(&(&b / 2)), vec![2, -2, 3]);
+        assert_eq!(values(&(-&a)), vec![-1, -2, -3]);
+        assert_eq!(values(&(Vector::<i32>::ones(3) + Vector::<i32>::ones(3) * 2 - (-Vector::<i32>::ones(3)))), vec![4, 4, 4]);
+
+        let c = Vector::<i32>::init(&vec![1, 2]);
+        assert_eq!(a.try_add(&c).err(), Some(Error::DimensionMismatch { expected: (3, 1), found: (2, 1) }));
+        assert!(a.try_sub(&c).is_err());
+    }
+    #[test]
+    #[should_panic]
+    fn vector_add_panic_test() {
+        let _ = Vector::<i32>::init(&vec![1, 2, 3]) + Vector::<i32>::init(&vec![1, 2]);
+    }
+    #[test]
+    fn vector_dot_norm_test() {
+        let a = Vector::<i32>::init(&vec![3, -4, 0]);
+        let b = Vector::<i32>::init(&vec![1, 2, 5]);
+
+        assert_eq!(a.dot(&b), -5);
+        assert!(a.try_dot(&Vector::<i32>::new()).is_err());
+        assert_eq!(a.norm_l1(), 7.0);
+        assert_eq!(a.norm_l2(), 5.0);
+        assert_eq!(a.norm_inf(), 4.0);
+        assert_eq!(Vector::<f64>::new().norm_inf(), 0.0);
+
+        let unit = Vector::<f64>::init(&vec![3.0, 0.0, -4.0]).normalize();
+        assert_close(&unit, &[0.6, 0.0, -0.8]);
+        assert!(Vector::<f64>::zeros(2).normalize().el(0).is_nan());
+
+        assert!((a.distance(&b) - 65f64.sqrt()).abs() < 1e-12);
+        assert!((a.cosine_similarity(&a) - 1.0).abs() < 1e-12);
+        assert!((a.cosine_similarity(&(-&a)) + 1.0).abs() < 1e-12);
+        let x = Vector::<f64>::init(&vec![1.0, 0.0]);
+        let y = Vector::<f64>::init(&vec![0.0, 2.0]);
+        assert!(x.cosine_similarity(&y).abs() < 1e-12);
+        assert!(x.cosine_similarity(&Vector::<f64>::zeros(2)).is_nan());
+        assert!(x.try_distance(&Vector::<f64>::zeros(3)).is_err());
+    }
+    #[test]
+    fn vector_outer_cumulative_test() {
+        let a = Vector::<i32>::init(&vec![1, 2, 3]);
+        let b = Vector::<i32>::init(&vec![4, 5]);
+
+        let outer = a.outer(&b);
+        assert!(outer == Matrix::<i32>::init(&vec![vec![4, 5], vec![8, 10], vec![12, 15]]));
+
+        assert_eq!(values(&a.cumsum()), vec![1, 3, 6]);
+        assert_eq!(values(&a.cumprod()), vec![1, 2, 6]);
+        assert_eq!(Vector::<i32>::new().cumsum().size(), 0);
+    }
+    #[test]
+    fn vector_iter_test() {
+        let mut v = Vector::<i32>::init(&vec![1, 2, 3]);
+
+        assert_eq!(v.iter().sum::<i32>(), 6);
+        assert_eq!(v.as_slice(), &[1, 2, 3]);
+        for el in v.iter_mut() {
+            *el *= 10;
+        }
+        for el in &mut v {
+            *el += 1;
+        }
+        v.as_mut_slice()[0] = 0;
+        assert_eq!((&v).into_iter().rev().cloned().collect::<Vec<i32>>(), vec![31, 21, 0]);
+        assert_eq!(v.into_iter().collect::<Vec<i32>>(), vec![0, 21, 31]);
+    }
+    #[test]
+    fn vector_collect_extend_test() {
+        let mut v: Vector<i32> = (1..4).collect();
+        assert_eq!(values(&v), vec![1, 2, 3]);
+
+        v.extend(vec![4, 5]);
+        v.extend(&[6]);
+        assert_eq!(values(&v), vec![1, 2, 3, 4, 5, 6]);
+
+        let even: Vector<i32> = v.iter().filter(|x| *x % 2 == 0).cloned().collect();
+        assert_eq!(values(&even), vec![2, 4, 6]);
+        assert_eq!(std::iter::empty::<i32>().collect::<Vector<i32>>().size(), 0);
+    }
+    #[test]
+    fn running_stats_test() {
+        let values = [2, 4, 4, 4, 5, 5, 7, 9];
+        let mut stats = RunningStats::new();
+        for el in values.iter() {
+            stats.push(*el);
+        }
+
+        assert_eq!(stats.count(), 8);
+        assert_eq!(stats.mean(), 5.0);
+        assert_eq!(stats.variance(), 4.0);
+        assert_eq!(stats.std_dev(), 2.0);
+        assert!((stats.sample_variance() - 32.0 / 7.0).abs() < 1e-12);
+        assert_eq!((stats.min(), stats.max()), (2.0, 9.0));
+
+        let (mut first, mut second) = (RunningStats::default(), RunningStats::new());
+        for el in values[..3].iter() {
+            first.push(*el);
+        }
+        for el in values[3..].iter() {
+            second.push(*el);
+        }
+        first.merge(&second);
+        assert_eq!(first.count(), 8);
+        assert!((first.mean() - 5.0).abs() < 1e-12);
+        assert!((first.variance() - 4.0).abs() < 1e-12);
+        assert_eq!((first.min(), first.max()), (2.0, 9.0));
+
+        let empty = RunningStats::new();
+        assert!(empty.mean().is_nan() && empty.variance().is_nan() && empty.max().is_nan());
+    }
+    #[test]
+    fn running_stats_big_offset_test() {
+        let mut stats = RunningStats::new();
+        for el in [4.0, 7.0, 13.0, 16.0].iter() {
+            stats.push(1e9 + el);
+        }
+
+        assert!((stats.variance() - 22.5).abs() < 1e-6);
+        assert!((stats.sample_variance() - 30.0).abs() < 1e-6);
+    }
 }
@@ -5,6 +5,9 @@ mod tests {
     use r::linspace::vector::*;
     use r::linspace::point::*;
     use r::linspace::quat::*;
+    use r::linspace::matrix::*;
+    use r::Error;
+    use std::f64::consts::PI;
 
     // --------------- Point3D TEST ----------------------------------------
 
@@ -71,6 +74,67 @@ mod tests {
         assert_eq!(d, 5.916079783099616); // using online euclidean distance calculator
     }
 
+    #[test]
+    fn point_sub_add_test() {
+        let a = Point3D::<i32>::init(1, 2, 3);
+        let b = Point3D::<i32>::init(4, 0, -1);
+
+        let v = b - a;
+        assert!(v == Vector3D::<i32>::init(3, -2, -4));
+        assert!(a + v == b);
+        assert!(b - v == a);
+
+        let mut c = a;
+        c += v;
+        assert!(c == b);
+        c -= v;
+        assert!(c == a);
+    }
+    #[test]
+    fn point_midpoint_lerp_test() {
+        let a = Point3D::<f64>::init(0.0, 2.0, -4.0);
+        let b = Point3D::<f64>::init(2.0, 4.0, 4.0);
+
+        assert!(a.midpoint(&b) == Point3D::<f64>::init(1.0, 3.0, 0.0));
+        assert!(a.lerp(&b, 0.0) == a);
+        assert!(a.lerp(&b, 1.0) == b);
+        assert!(a.lerp(&b, 0.25) == Point3D::<f64>::init(0.5, 2.5, -2.0));
+        assert!(a.lerp(&b, 2.0) == Point3D::<f64>::init(4.0, 6.0, 12.0));
+        assert!(Point3D::<i32>::init(0, 0, 0).midpoint(&Point3D::<i32>::init(3, 4, -4))
+                == Point3D::<i32>::init(1, 2, -2));
+    }
+    #[test]
+    fn point_centroid_test() {
+        let points = [Point3D::<f64>::init(0.0, 0.0, 0.0),
+                      Point3D::<f64>::init(3.0, 0.0, 3.0),
+                      Point3D::<f64>::init(0.0, 6.0, 3.0)];
+
+        assert!(Point3D::centroid(&points).unwrap() == Point3D::<f64>::init(1.0, 2.0, 2.0));
+        assert!(Point3D::centroid(&points[..1]).unwrap() == points[0]);
+        assert!(Point3D::<f64>::centroid(&[]).is_none());
+    }
+    #[test]
+    fn point_from_test() {
+        let p = Point3D::<i32>::from([1, 2, 3]);
+        assert!(p == Point3D::<i32>::init(1, 2, 3));
+        assert!(Point3D::<i32>::from((1, 2, 3)) == p);
+        assert!(Point3D::from(Vector3D::<i32>::init(1, 2, 3)) == p);
+
+        let v: Vector3D<i32> = p.into();
+        assert!(v == Vector3D::<i32>::init(1, 2, 3));
+        assert!(Vector3D::<i32>::from([1, 2, 3]) == v);
+        assert!(Vector3D::<i32>::from((1, 2, 3)) == v);
+
+        let arr: [i32; 3] = p.into();
+        let tuple: (i32, i32, i32) = p.into();
+        assert_eq!(arr, [1, 2, 3]);
+        assert_eq!(tuple, (1, 2, 3));
+        let arr: [i32; 3] = v.into();
+        let tuple: (i32, i32, i32) = v.into();
+        assert_eq!(arr, [1, 2, 3]);
+        assert_eq!(tuple, (1, 2, 3));
+    }
+
     // --------------- Vector3D TEST ----------------------------------------
 
     #[test]
@@ -256,6 +320,156 @@ mod tests {
         assert_eq!(min_b, -5);
     }
 
+    #[test]
+    fn vector_homogeneous_test() {
+        let v = Vector3D::<i32>::init(1, 2, 3);
+        assert!(v.to_homogeneous() == Vector4D::<i32>::init(1, 2, 3, 0));
+        assert!(v.to_homogeneous().xyz() == v);
+    }
+
+    // --------------- Point2D TEST ----------------------------------------
+
+    #[test]
+    fn point2d_init_test() {
+        let mut p = Point2D::<f64>::new();
+        assert_eq!(p.x(), 0.0);
+        assert_eq!(p.y(), 0.0);
+
+        p.set(1.5, -2.0);
+        assert!(p == Point2D::<f64>::init(1.5, -2.0));
+        p.set_x(3.0);
+        p.set_y(4.0);
+        assert_eq!(Point2D::eucl_distance(&p, &Point2D::<f64>::new()), 5.0);
+        assert_eq!(format!("{}", p), "[ 3, 4 ]");
+    }
+    #[test]
+    fn point2d_affine_test() {
+        let a = Point2D::<i32>::init(1, 2);
+        let b = Point2D::<i32>::init(5, -2);
+
+        let v = b - a;
+        assert!(v == Vector2D::<i32>::init(4, -4));
+        assert!(a + v == b);
+        assert!(b - v == a);
+        let mut c = a;
+        c += v;
+        c -= v;
+        assert!(c == a);
+
+        assert!(a.midpoint(&b) == Point2D::<i32>::init(3, 0));
+        assert!(a.lerp(&b, 2) == Point2D::<i32>::init(9, -6));
+        assert!(Point2D::centroid(&[a, b, Point2D::<i32>::init(0, 6)]).unwrap() == Point2D::<i32>::init(2, 2));
+        assert!(Point2D::<i32>::centroid(&[]).is_none());
+    }
+    #[test]
+    fn point2d_from_test() {
+        let p = Point2D::<i32>::from([1, 2]);
+        assert!(Point2D::<i32>::from((1, 2)) == p);
+        assert!(Point2D::from(Vector2D::<i32>::init(1, 2)) == p);
+        assert!(Vector2D::from(p) == Vector2D::<i32>::init(1, 2));
+
+        let arr: [i32; 2] = p.into();
+        let tuple: (i32, i32) = p.into();
+        assert_eq!(arr, [1, 2]);
+        assert_eq!(tuple, (1, 2));
+    }
+    #[test]
+    fn point_homogeneous_test() {
+        let p = Point2D::<f64>::init(1.0, -2.0);
+        let h = p.to_homogeneous();
+        assert!(h == Vector3D::<f64>::init(1.0, -2.0, 1.0));
+        assert!(Point2D::from_homogeneous(&(h * 4.0)).unwrap() == p);
+        assert!(Point2D::from_homogeneous(&Vector2D::<f64>::init(1.0, 1.0).to_homogeneous()).is_none());
+
+        let q = Point3D::<f64>::init(1.0, -2.0, 3.0);
+        let h = q.to_homogeneous();
+        assert!(h == Vector4D::<f64>::init(1.0, -2.0, 3.0, 1.0));
+        assert!(Point3D::from_homogeneous(&(h * 0.5)).unwrap() == q);
+        assert!(Point3D::from_homogeneous(&Vector3D::<f64>::init(1.0, 1.0, 1.0).to_homogeneous()).is_none());
+    }
+
+    // --------------- Vector2D TEST ----------------------------------------
+
+    #[test]
+    fn vector2d_init_test() {
+        let mut v = Vector2D::<i32>::new();
+        assert_eq!(v.x(), 0);
+        assert_eq!(v.y(), 0);
+
+        v.set(3, -4);
+        assert!(v == Vector2D::<i32>::init(3, -4));
+        v.set_x(1);
+        v.set_y(2);
+        assert!(v == Vector2D::<i32>::from([1, 2]));
+        assert_eq!(v.max(), 2);
+        assert_eq!(v.min(), 1);
+        assert_eq!(format!("{}", v), "( 1, 2 )");
+    }
+    #[test]
+    fn vector2d_ops_test() {
+        let a = Vector2D::<i32>::init(1, 2);
+        let b = Vector2D::<i32>::init(3, -1);
+
+        assert!(a + b == Vector2D::<i32>::init(4, 1));
+        assert!(a - b == Vector2D::<i32>::init(-2, 3));
+        assert!(a * b == Vector2D::<i32>::init(3, -2));
+        assert!(a * 3 == a.scale(3));
+        assert!(a.scale_vec(&b) == a * b);
+        assert!(b * 2 / 2 == b);
+        assert!(-a == Vector2D::<i32>::init(-1, -2));
+        assert_eq!(a.dot(&b), 1);
+        assert_eq!(a.perp_dot(&b), -7);
+        assert_eq!(b.perp_dot(&a), 7);
+        assert!(a.perp() == Vector2D::<i32>::init(-2, 1));
+        assert_eq!(a.perp().dot(&a), 0);
+        assert_eq!(Vector2D::<i32>::init(3, 4).dist(), 5.0);
+    }
+    #[test]
+    fn vector2d_angle_rotate_test() {
+        use std::f64::consts::PI;
+
+        let x = Vector2D::<f64>::init(1.0, 0.0);
+        let y = Vector2D::<f64>::init(0.0, 2.0);
+
+        assert!((x.angle(&y) - PI / 2.0).abs() < 1e-12);
+        assert!((y.angle(&x) + PI / 2.0).abs() < 1e-12);
+        assert!((x.angle(&-x) - PI).abs() < 1e-12);
+
+        let r = x.rotate(PI / 2.0);
+        assert!(r.x.abs() < 1e-12 && (r.y - 1.0).abs() < 1e-12);
+        let r = y.rotate(-PI / 4.0);
+        assert!((r.x - 2f64.sqrt()).abs() < 1e-12 && (r.y - 2f64.sqrt()).abs() < 1e-12);
+
+        let n = Vector2D::<f64>::init(3.0, -4.0).norm();
+        assert!((n.x - 0.6).abs() < 1e-12 && (n.y + 0.8).abs() < 1e-12);
+    }
+
+    // --------------- Vector4D TEST ----------------------------------------
+
+    #[test]
+    fn vector4d_test() {
+        let mut a = Vector4D::<i32>::new();
+        a.set(1, 2, 3, 4);
+        let b = Vector4D::<i32>::init(4, 3, 2, 1);
+
+        assert_eq!((a.x(), a.y(), a.z(), a.w()), (1, 2, 3, 4));
+        assert!(a + b == Vector4D::<i32>::from([5, 5, 5, 5]));
+        assert!(a - b == Vector4D::<i32>::from((-3, -1, 1, 3)));
+        assert!(a * b == Vector4D::<i32>::init(4, 6, 6, 4));
+        assert!(a * 2 == a.scale(2));
+        assert!(a * 2 / 2 == a);
+        assert!(-a == Vector4D::<i32>::init(-1, -2, -3, -4));
+        assert_eq!(a.dot(&b), 20);
+        assert_eq!(Vector4D::<i32>::init(1, 1, 1, 1).dist(), 2.0);
+        assert!(a.xyz() == Vector3D::<i32>::init(1, 2, 3));
+        assert_eq!(format!("{}", a), "( 1, 2, 3, 4 )");
+
+        let arr: [i32; 4] = a.into();
+        assert_eq!(arr, [1, 2, 3, 4]);
+        let n = Vector4D::<f64>::init(2.0, 0.0, 0.0, 0.0).norm();
+        assert!(n == Vector4D::<f64>::init(1.0, 0.0, 0.0, 0.0));
+    }
+
      // --------------- QUAT TEST ----------------------------------------
     #[test]
     fn quat_coord_test() {
@@ -408,4 +622,296 @@ mod tests {
         assert_eq!(r.z().round(), 0f64);
         assert_eq!(r.w().round(), -1f64);
     }
+
+    const ORDERS: [EulerOrder; 12] = [EulerOrder::XYZ, EulerOrder::XZY, EulerOrder::YXZ,
+                                      EulerOrder::YZX, EulerOrder::ZXY, EulerOrder::ZYX,
+                                      EulerOrder::XYX, EulerOrder::XZX, EulerOrder::YXY,
+                                      EulerOrder::YZY, EulerOrder::ZXZ, EulerOrder::ZYZ];
+
+    // q and -q are the same rotation
+    fn same_rotation(a: &Quat<f64>, b: &Quat<f64>) -> bool {
+        let dot = a.x() * b.x() + a.y() * b.y() + a.z() * b.z() + a.w() * b.w();
+        (dot.abs() - a.magnitude() * b.magnitude()).abs() < 1e-9
+    }
+    fn is_tait_bryan(order: EulerOrder) -> bool {
+        ORDERS[..6].contains(&order)
+    }
+
+    #[test]
+    fn quat_rotation_matrix_roundtrip_test() {
+        let axes = [(1.0, 0.0, 0.0), (0.0, 1.0, 0.0), (0.0, 0.0, 1.0), (1.0, -2.0, 0.5), (-3.0, 1.0, 1.0)];
+        let angles = [0.0, 0.3, -1.2, 2.5, PI - 1e-3, PI];
+
+        for &(x, y, z) in axes.iter() {
+            for &angle in angles.iter() {
+                let q = Quat::from_axis_angle(&Vector3D::<f64>::init(x, y, z), angle);
+                let m = q.to_rotation_matrix();
+                let back = Quat::from_rotation_matrix(&m);
+                assert!(same_rotation(&q, &back), "axis ({}, {}, {}), angle {}", x, y, z, angle);
+                assert!((back.magnitude() - 1.0).abs() < 1e-12);
+            }
+        }
+    }
+    #[test]
+    fn quat_euler_matrix_test() {
+        let (a, b, c) = (0.4, -0.9, 2.1);
+        let x = Vector3D::<f64>::init(1.0, 0.0, 0.0);
+        let y = Vector3D::<f64>::init(0.0, 1.0, 0.0);
+        let z = Vector3D::<f64>::init(0.0, 0.0, 1.0);
+
+        // intrinsic rotations: first about the X axis, then Y, then Z
+        let q = Quat::from_euler(EulerOrder::XYZ, a, b, c);
+        let m = Matrix3::rotation(&x, a) * Matrix3::rotation(&y, b) * Matrix3::rotation(&z, c);
+        assert!(close3(&q.to_rotation_matrix(), &m));
+
+        let q = Quat::from_euler(EulerOrder::ZXZ, a, b, c);
+        let m = Matrix3::rotation(&z, a) * Matrix3::rotation(&x, b) * Matrix3::rotation(&z, c);
+        assert!(close3(&q.to_rotation_matrix(), &m));
+    }
+    #[test]
+    fn quat_euler_roundtrip_test() {
+        let values = [-3.0, -1.4, -0.2, 0.0, 0.7, 1.5, 2.9];
+
+        for &order in ORDERS.iter() {
+            for &a in values.iter() {
+                for &b in values.iter() {
+                    for &c in values.iter() {
+                        let q = Quat::from_euler(order, a, b, c);
+                        let (ea, eb, ec) = q.to_euler(order);
+                        let back = Quat::from_euler(order, ea, eb, ec);
+                        assert!(same_rotation(&q, &back), "{:?} ({}, {}, {})", order, a, b, c);
+
+                        // the angles are recovered within their ranges
+                        let in_range = if is_tait_bryan(order) { b.abs() < PI / 2.0 } else { b > 0.0 };
+                        if in_range {
+                            assert!((ea - a).abs() < 1e-9 && (eb - b).abs() < 1e-9 && (ec - c).abs() < 1e-9,
+                                    "{:?} ({}, {}, {}) != ({}, {}, {})", order, a, b, c, ea, eb, ec);
+                        }
+                        assert!(ea.abs() <= PI && ec.abs() <= PI);
+                        if is_tait_bryan(order) {
+                            assert!(eb.abs() <= PI / 2.0 + 1e-12);
+                        } else {
+                            assert!((0.0..=PI + 1e-12).contains(&eb));
+                        }
+                    }
+                }
+            }
+        }
+    }
+    #[test]
+    fn quat_euler_gimbal_lock_test() {
+        for &order in ORDERS.iter() {
+            let locks = if is_tait_bryan(order) { [PI / 2.0, -PI / 2.0] } else { [0.0, PI] };
+            for &b in locks.iter() {
+                let q = Quat::from_euler(order, 0.5, b, -0.3);
+                let (ea, eb, ec) = q.to_euler(order);
+
+                assert!((eb - b).abs() < 1e-9, "{:?}: {} != {}", order, eb, b);
+                assert_eq!(ec, 0.0);
+                assert!(same_rotation(&q, &Quat::from_euler(order, ea, eb, ec)), "{:?} ({})", order, b);
+            }
+        }
+    }
+    #[test]
+    fn quat_axis_angle_test() {
+        let axis = Vector3D::<f64>::init(2.0, -1.0, 2.0);
+        let q = Quat::from_axis_angle(&axis, 1.2);
+        assert!((q.magnitude() - 1.0).abs() < 1e-12);
+
+        let (u, angle) = q.to_axis_angle();
+        assert!(close_v3(&u, &axis.norm()));
+        assert!((angle - 1.2).abs() < 1e-12);
+
+        // the negated quaternion is the same rotation, and the angle is kept in [0, pi]
+        let (u, angle) = (-q).to_axis_angle();
+        assert!(close_v3(&u, &axis.norm()));
+        assert!((angle - 1.2).abs() < 1e-12);
+        let (u, angle) = Quat::from_axis_angle(&axis, -1.2).to_axis_angle();
+        assert!(close_v3(&u, &-axis.norm()));
+        assert!((angle - 1.2).abs() < 1e-12);
+
+        // a non-unit quaternion has the same axis and angle
+        let mut big = q;
+        big.scale(3.0);
+        let (_, angle) = big.to_axis_angle();
+        assert!((angle - 1.2).abs() < 1e-12);
+
+        let (u, angle) = Quat::<f64>::init(0.0, 0.0, 0.0, 1.0).to_axis_angle();
+        assert!(u == Vector3D::<f64>::init(1.0, 0.0, 0.0) && angle == 0.0);
+
+        // same rotation as the matrix
+        assert!(close3(&q.to_rotation_matrix(), &Matrix3::rotation(&axis, 1.2)));
+        let r = Quat::from_euler(EulerOrder::YZX, 0.1, 0.2, 0.3);
+        let (u, angle) = r.to_axis_angle();
+        assert!(same_rotation(&Quat::from_axis_angle(&u, angle), &r));
+    }
+
+    #[test]
+    fn quat_rotate_vector_test() {
+        let q = Quat::from_axis_angle(&Vector3D::<f64>::init(0.0, 0.0, 1.0), PI / 2.0);
+        let v = Vector3D::<f64>::init(1.0, 0.0, 2.0);
+
+        assert!(close_v3(&q.rotate_vector(&v), &Vector3D::init(0.0, 1.0, 2.0)));
+        assert!(close_v3(&(q * v), &Vector3D::init(0.0, 1.0, 2.0)));
+
+        // same result as q * v * q^-1 and as the rotation matrix
+        let q = Quat::from_euler(EulerOrder::ZYX, 0.3, -1.1, 2.0);
+        let v = Vector3D::<f64>::init(-1.5, 0.25, 3.0);
+        let p = q * Quat::init(v.x, v.y, v.z, 0.0) * q.conjugate();
+        assert!(close_v3(&q.rotate_vector(&v), &Vector3D::init(p.x(), p.y(), p.z())));
+        assert!(close_v3(&(q * v), &(q.to_rotation_matrix() * v)));
+        assert!(((q * v).dist() - v.dist()).abs() < 1e-12);
+    }
+    #[test]
+    fn quat_rotate_non_unit_test() {
+        let unit = Quat::from_axis_angle(&Vector3D::<f64>::init(1.0, 1.0, 0.0), 0.8);
+        let mut q = unit;
+        q.scale(2.5);
+        let v = Vector3D::<f64>::init(0.5, -2.0, 1.0);
+
+        assert!(close_v3(&q.rotate_vector(&v), &unit.rotate_vector(&v)));
+        assert!(close_v3(&(-q * v), &(unit * v)));
+
+        let zero = Quat::<f64>::init(0.0, 0.0, 0.0, 0.0).rotate_vector(&v);
+        assert!(zero.x.is_nan());
+    }
+    #[test]
+    fn quat_rotate_point_test() {
+        let q = Quat::from_axis_angle(&Vector3D::<f64>::init(0.0, 0.0, 1.0), PI);
+        let p = Point3D::<f64>::init(2.0, 1.0, 5.0);
+        let pivot = Point3D::<f64>::init(1.0, 1.0, 0.0);
+
+        let r = q.rotate_point(&p, &pivot);
+        assert!(close_v3(&Vector3D::from(r), &Vector3D::init(0.0, 1.0, 5.0)));
+        assert!(close_v3(&Vector3D::from(q.rotate_point(&pivot, &pivot)), &Vector3D::from(pivot)));
+        let origin = Point3D::<f64>::init(0.0, 0.0, 0.0);
+        assert!(close_v3(&Vector3D::from(q.rotate_point(&p, &origin)), &(q * Vector3D::from(p))));
+
+        // same as a translated rotation matrix
+        let m = Matrix4::translation(1.0, 1.0, 0.0) * Matrix4::from_quat(&q) * Matrix4::translation(-1.0, -1.0, 0.0);
+        assert!(close_v3(&Vector3D::from(m * p), &Vector3D::from(r)));
+    }
+
+    // --------------- Matrix TEST ----------------------------------------
+
+    fn close3(a: &Matrix3<f64>, b: &Matrix3<f64>) -> bool {
+        let (a, b) = (a.to_array(), b.to_array());
+        (0..3).all(|i| (0..3).all(|j| (a[i][j] - b[i][j]).abs() < 1e-12))
+    }
+    fn close4(a: &Matrix4<f64>, b: &Matrix4<f64>) -> bool {
+        let (a, b) = (a.to_array(), b.to_array());
+        (0..4).all(|i| (0..4).all(|j| (a[i][j] - b[i][j]).abs() < 1e-12))
+    }
+    fn close_v3(a: &Vector3D<f64>, b: &Vector3D<f64>) -> bool {
+        (a.x - b.x).abs() < 1e-12 && (a.y - b.y).abs() < 1e-12 && (a.z - b.z).abs() < 1e-12
+    }
+
+    #[test]
+    fn matrix2_test() {
+        let mut m = Matrix2::<f64>::init([[4.0, 7.0], [1.0, 2.0]]);
+
+        assert_eq!(m.determinant(), 1.0);
+        assert!(m.transpose() == Matrix2::<f64>::from([[4.0, 1.0], [7.0, 2.0]]));
+        assert!(m * m.inverse() == Matrix2::<f64>::identity());
+        assert!(m * Matrix2::<f64>::identity() == m);
+        assert!(Matrix2::<f64>::scaling(2.0, 3.0) * Vector2D::<f64>::init(1.0, 1.0) == Vector2D::<f64>::init(2.0, 3.0));
+        assert!(Matrix2::<f64>::scaling(2.0, 3.0) * Point2D::<f64>::init(1.0, 1.0) == Point2D::<f64>::init(2.0, 3.0));
+
+        let r = Matrix2::<f64>::rotation(PI / 2.0) * Vector2D::<f64>::init(1.0, 0.0);
+        assert!(r.x.abs() < 1e-12 && (r.y - 1.0).abs() < 1e-12);
+
+        m[(0, 1)] = 8.0;
+        assert_eq!(m[(0, 1)], 8.0);
+        assert!(m.try_inverse().err() == Some(Error::Singular));
+        assert_eq!(format!("{}", m), "( 4, 8 )\n( 1, 2 )\n");
+    }
+    #[test]
+    fn matrix3_test() {
+        let m = Matrix3::<f64>::init([[2.0, 0.0, 1.0], [1.0, 3.0, 2.0], [1.0, 1.0, 2.0]]);
+
+        assert_eq!(m.determinant(), 6.0);
+        assert!(close3(&(m * m.inverse()), &Matrix3::identity()));
+        assert!(close3(&(m.inverse() * m), &Matrix3::identity()));
+        assert!(m.transpose().transpose() == m);
+        assert_eq!(m.transpose()[(0, 1)], 1.0);
+        assert_eq!(Matrix3::<i32>::init([[1, 2, 3], [4, 5, 6], [7, 8, 9]]).determinant(), 0);
+        assert!(Matrix3::<f64>::new().try_inverse().is_err());
+
+        let v = Vector3D::<f64>::init(1.0, -1.0, 2.0);
+        assert!(m * v == Vector3D::<f64>::init(4.0, 2.0, 4.0));
+        assert!(m * Point3D::<f64>::init(1.0, -1.0, 2.0) == Point3D::<f64>::init(4.0, 2.0, 4.0));
+        assert!(Matrix3::<i32>::scaling(1, 2, 3) * Vector3D::<i32>::init(1, 1, 1) == Vector3D::<i32>::init(1, 2, 3));
+    }
+    #[test]
+    fn matrix3_rotation_test() {
+        let z = Vector3D::<f64>::init(0.0, 0.0, 3.0);
+        let r = Matrix3::rotation(&z, PI / 2.0);
+        assert!(close_v3(&(r * Vector3D::<f64>::init(1.0, 0.0, 0.0)), &Vector3D::init(0.0, 1.0, 0.0)));
+
+        // the rotations are orthogonal with determinant one
+        let axis = Vector3D::<f64>::init(1.0, 2.0, -2.0);
+        let r = Matrix3::rotation(&axis, 0.7);
+        assert!(close3(&(r * r.transpose()), &Matrix3::identity()));
+        assert!((r.determinant() - 1.0).abs() < 1e-12);
+        assert!(close_v3(&(r * axis), &axis));
+
+        // same rotation as the quaternion, even if it isn't unitary
+        let q = Quat::rotation(1.0 / 3.0, 2.0 / 3.0, -2.0 / 3.0, 0.7);
+        assert!(close3(&Matrix3::from_quat(&q), &r));
+        let mut big = q;
+        big.scale(5.0);
+        assert!(close3(&Matrix3::from_quat(&big), &r));
+    }
+    #[test]
+    fn matrix4_test() {
+        let m = Matrix4::<f64>::init([[1.0, 2.0, 0.0, 1.0],
+                                      [0.0, 1.0, 3.0, 0.0],
+                                      [2.0, 0.0, 1.0, 1.0],
+                                      [1.0, 1.0, 0.0, 2.0]]);
+
+        let det = m.determinant();
+        assert!((det - 16.0).abs() < 1e-12);
+        assert!(close4(&(m * m.inverse()), &Matrix4::identity()));
+        assert!(close4(&(m.inverse() * m), &Matrix4::identity()));
+        assert!((m.transpose().determinant() - det).abs() < 1e-12);
+        assert_eq!(m.transpose()[(3, 0)], 1.0);
+        assert!(Matrix4::<f64>::scaling(1.0, 0.0, 1.0).try_inverse().err() == Some(Error::Singular));
+        assert!(m * Vector4D::<f64>::init(1.0, 0.0, 0.0, 0.0) == Vector4D::<f64>::init(1.0, 0.0, 2.0, 1.0));
+        assert_eq!(Matrix4::<i32>::identity().determinant(), 1);
+    }
+    #[test]
+    fn matrix4_transform_test() {
+        let t = Matrix4::<f64>::translation(1.0, 2.0, 3.0);
+        let s = Matrix4::<f64>::scaling(2.0, 2.0, 2.0);
+        let p = Point3D::<f64>::init(1.0, 1.0, 1.0);
+        let v = Vector3D::<f64>::init(1.0, 1.0, 1.0);
+
+        assert!(t * p == Point3D::<f64>::init(2.0, 3.0, 4.0));
+        assert!(t * v == v);
+        assert!(t * s * p == Point3D::<f64>::init(3.0, 4.0, 5.0));
+        assert!(s * t * p == Point3D::<f64>::init(4.0, 6.0, 8.0));
+        assert!(t.inverse() * (t * p) == p);
+
+        let axis = Vector3D::<f64>::init(0.0, 1.0, 0.0);
+        let r = Matrix4::rotation(&axis, PI / 2.0);
+        assert!(close_v3(&(r * Vector3D::<f64>::init(0.0, 0.0, 1.0)), &Vector3D::init(1.0, 0.0, 0.0)));
+        assert!(close4(&r, &Matrix4::from(Matrix3::rotation(&axis, PI / 2.0))));
+        assert!(close4(&Matrix4::from_quat(&Quat::rotation(0.0, 1.0, 0.0, PI / 2.0)), &r));
+
+        // projective division by w
+        let mut proj = Matrix4::<f64>::identity();
+        proj[(3, 2)] = 1.0;
+        proj[(3, 3)] = 0.0;
+        assert!(proj * Point3D::<f64>::init(2.0, 4.0, 2.0) == Point3D::<f64>::init(1.0, 2.0, 1.0));
+        assert!(proj.try_transform_point(&Point3D::<f64>::init(2.0, 4.0, 2.0)) == Some(Point3D::init(1.0, 2.0, 1.0)));
+        assert!(proj.try_transform_point(&Point3D::<f64>::init(2.0, 4.0, 0.0)).is_none());
+        assert!(Matrix4::<i32>::translation(1, 0, 0).try_transform_point(&Point3D::init(1, 2, 3)) == Some(Point3D::init(2, 2, 3)));
+    }
+    #[test]
+    #[should_panic]
+    fn matrix4_transform_infinity_test() {
+        let mut proj = Matrix4::<i32>::identity();
+        proj[(3, 3)] = 0;
+        let _ = proj * Point3D::<i32>::init(1, 2, 3);
+    }
 }